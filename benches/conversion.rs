@@ -0,0 +1,84 @@
+//! Benchmarks of conversion between `Date` and year, month, and day.
+//!
+//! This compares the crate's conversions (which use the Neri–Schneider algorithms) against Howard
+//! Hinnant's algorithms, which the crate previously used. Run with `cargo bench`.
+
+use std::hint::black_box;
+use std::time::Duration;
+use std::time::Instant;
+
+use date::Date;
+
+const ROUNDS: u32 = 20;
+
+fn main() {
+  let dates: Vec<Date> = (-200_000..200_000).map(Date::from_unix_days).collect();
+  let ymds = Date::ymd_batch(&dates);
+
+  compare(
+    "days -> ymd",
+    || dates.iter().map(|d| hinnant::civil_from_days(d.unix_days())).collect::<Vec<_>>(),
+    || Date::ymd_batch(&dates),
+  );
+  compare(
+    "ymd -> days",
+    || ymds.iter().map(|&(y, m, d)| hinnant::days_from_civil(y, m, d)).collect::<Vec<_>>(),
+    || ymds.iter().map(|&(y, m, d)| Date::new(y, m, d)).collect::<Vec<_>>(),
+  );
+  compare(
+    "round trip",
+    || {
+      let ymds: Vec<_> = dates.iter().map(|d| hinnant::civil_from_days(d.unix_days())).collect();
+      ymds.iter().map(|&(y, m, d)| hinnant::days_from_civil(y, m, d)).collect::<Vec<_>>()
+    },
+    || Date::from_ymd_batch(&Date::ymd_batch(&dates)),
+  );
+}
+
+/// Time the two implementations, and print the results.
+fn compare<A, B>(name: &str, reference: impl Fn() -> A, current: impl Fn() -> B) {
+  let reference = time(reference);
+  let current = time(current);
+  println!(
+    "{name:<12} hinnant: {reference:>10.2?}  neri-schneider: {current:>10.2?}  ({:.2}x)",
+    reference.as_secs_f64() / current.as_secs_f64(),
+  );
+}
+
+/// The fastest of several runs of the given function.
+fn time<T>(f: impl Fn() -> T) -> Duration {
+  (0..ROUNDS)
+    .map(|_| {
+      let start = Instant::now();
+      black_box(f());
+      start.elapsed()
+    })
+    .min()
+    .unwrap_or_default()
+}
+
+/// The algorithms from https://howardhinnant.github.io/date_algorithms.html.
+mod hinnant {
+  pub fn civil_from_days(days: i32) -> (i16, u8, u8) {
+    let shifted = days + 719468;
+    let era = if shifted >= 0 { shifted } else { shifted - 146_096 } / 146_097;
+    let doe = shifted - era * 146_097;
+    let year_of_era = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let year = year_of_era + era * 400;
+    let day_of_year = doe - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    ((year + if month <= 2 { 1 } else { 0 }) as i16, month as u8, day as u8)
+  }
+
+  pub fn days_from_civil(year: i16, month: u8, day: u8) -> i32 {
+    let year = year as i32 - if month <= 2 { 1 } else { 0 };
+    let (month, day) = (month as i32, day as i32);
+    let era = if year >= 0 { year } else { year - 399 } / 400;
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+  }
+}
@@ -0,0 +1,247 @@
+use crate::utils;
+use crate::Date;
+
+/// A policy for how to treat February 29 in years that are not leap years.
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub enum Feb29Policy {
+  /// Observe February 29 on February 28 in non-leap years.
+  #[default]
+  Feb28,
+  /// Observe February 29 on March 1 in non-leap years.
+  Mar1,
+  /// Only observe February 29 in leap years.
+  LeapYearsOnly,
+}
+
+impl Feb29Policy {
+  /// The date on which the given month and day falls in the given year, according to this
+  /// policy. Returns `None` only for February 29 in a non-leap year under
+  /// [`Feb29Policy::LeapYearsOnly`].
+  pub(crate) const fn resolve(&self, year: i16, month: u8, day: u8) -> Option<Date> {
+    if month != 2 || day != 29 || utils::is_leap_year(year) {
+      return Some(Date::new(year, month, day));
+    }
+    match self {
+      Self::Feb28 => Some(Date::new(year, 2, 28)),
+      Self::Mar1 => Some(Date::new(year, 3, 1)),
+      Self::LeapYearsOnly => None,
+    }
+  }
+}
+
+impl Date {
+  /// The next anniversary of this date after the given date.
+  ///
+  /// Anniversaries fall on the same month and day as this date, in subsequent years. If this date
+  /// is February 29, the provided policy determines the anniversary in non-leap years. This date
+  /// is itself considered to be the first anniversary, so if `after` precedes this date, this
+  /// date is returned.
+  ///
+  /// Returns `None` if there is no such anniversary before [`Date::MAX`].
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::date;
+  /// use date::Feb29Policy;
+  ///
+  /// let signup = date! { 2023-03-15 };
+  /// let renewal = signup.next_anniversary(date! { 2024-04-01 }, Feb29Policy::Feb28);
+  /// assert_eq!(renewal, Some(date! { 2025-03-15 }));
+  ///
+  /// let leapling = date! { 2024-02-29 };
+  /// let today = date! { 2024-06-01 };
+  /// assert_eq!(leapling.next_anniversary(today, Feb29Policy::Feb28), Some(date! { 2025-02-28 }));
+  /// assert_eq!(leapling.next_anniversary(today, Feb29Policy::Mar1), Some(date! { 2025-03-01 }));
+  /// assert_eq!(
+  ///   leapling.next_anniversary(today, Feb29Policy::LeapYearsOnly),
+  ///   Some(date! { 2028-02-29 }),
+  /// );
+  /// ```
+  pub fn next_anniversary(&self, after: Date, policy: Feb29Policy) -> Option<Date> {
+    if after < *self {
+      return Some(*self);
+    }
+    let (_, month, day) = self.ymd();
+    (after.year()..=i16::MAX).filter_map(|y| policy.resolve(y, month, day)).find(|d| *d > after)
+  }
+
+  /// The number of completed years from `other` to this date, such as a person's age on this
+  /// date if `other` is their birthday.
+  ///
+  /// A year is completed on the anniversary of `other`. If `other` is February 29, the provided
+  /// policy determines the anniversary in non-leap years; [`Feb29Policy::LeapYearsOnly`] is
+  /// treated like [`Feb29Policy::Mar1`], since a year is not complete until February 28 has
+  /// passed.
+  ///
+  /// Returns `None` if `other` is after this date.
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::date;
+  /// use date::Feb29Policy;
+  ///
+  /// let birthday = date! { 1990-06-15 };
+  /// assert_eq!(date! { 2024-06-14 }.years_since(birthday, Feb29Policy::Feb28), Some(33));
+  /// assert_eq!(date! { 2024-06-15 }.years_since(birthday, Feb29Policy::Feb28), Some(34));
+  ///
+  /// let leapling = date! { 2004-02-29 };
+  /// let today = date! { 2022-02-28 };
+  /// assert_eq!(today.years_since(leapling, Feb29Policy::Feb28), Some(18));
+  /// assert_eq!(today.years_since(leapling, Feb29Policy::Mar1), Some(17));
+  /// ```
+  pub const fn years_since(&self, other: Date, policy: Feb29Policy) -> Option<u16> {
+    if self.0 < other.0 {
+      return None;
+    }
+    let (year, ..) = self.ymd();
+    let (other_year, month, day) = other.ymd();
+    let anniversary = match policy.resolve(year, month, day) {
+      Some(anniversary) => anniversary,
+      None => Date::new(year, 3, 1),
+    };
+    let years = year as i32 - other_year as i32;
+    match self.0 < anniversary.0 {
+      true => Some((years - 1) as u16),
+      false => Some(years as u16),
+    }
+  }
+
+  /// The next February 29 on or after this date.
+  ///
+  /// Returns `None` if there is no such date before [`Date::MAX`].
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::date;
+  ///
+  /// assert_eq!(date! { 2023-06-01 }.next_leap_day(), Some(date! { 2024-02-29 }));
+  /// assert_eq!(date! { 2024-02-29 }.next_leap_day(), Some(date! { 2024-02-29 }));
+  /// assert_eq!(date! { 2096-03-01 }.next_leap_day(), Some(date! { 2104-02-29 }));
+  /// ```
+  pub const fn next_leap_day(&self) -> Option<Date> {
+    let (mut year, month, _) = self.ymd();
+    if month > 2 {
+      year = match year.checked_add(1) {
+        Some(year) => year,
+        None => return None,
+      };
+    }
+    while !utils::is_leap_year(year) {
+      year = match year.checked_add(1) {
+        Some(year) => year,
+        None => return None,
+      };
+    }
+    Some(Date::new(year, 2, 29))
+  }
+
+  /// The number of leap years from `start` (inclusive) to `end` (exclusive).
+  ///
+  /// Returns `0` if `end` is not after `start`.
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::Date;
+  ///
+  /// assert_eq!(Date::leap_years_between(2000, 2024), 6);
+  /// assert_eq!(Date::leap_years_between(1800, 2000), 48);
+  /// ```
+  pub const fn leap_years_between(start: i16, end: i16) -> u16 {
+    /// The number of leap years between an arbitrary epoch and the given year, inclusive.
+    const fn leap_years_through(year: i32) -> i32 {
+      year.div_euclid(4) - year.div_euclid(100) + year.div_euclid(400)
+    }
+    match end > start {
+      true => (leap_years_through(end as i32 - 1) - leap_years_through(start as i32 - 1)) as u16,
+      false => 0,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use assert2::check;
+
+  use super::*;
+
+  #[test]
+  fn test_next_anniversary() {
+    let date = date! { 2020-06-15 };
+    check!(date.next_anniversary(date! { 2019-01-01 }, Feb29Policy::Feb28) == Some(date));
+    check!(date.next_anniversary(date, Feb29Policy::Feb28) == Some(date! { 2021-06-15 }));
+    let anniversary = |from| date.next_anniversary(from, Feb29Policy::Feb28);
+    check!(anniversary(date! { 2024-06-14 }) == Some(date! { 2024-06-15 }));
+    check!(anniversary(date! { 2024-06-15 }) == Some(date! { 2025-06-15 }));
+    check!(date! { 2020-12-31 }.next_anniversary(Date::MAX, Feb29Policy::Feb28) == None);
+  }
+
+  #[test]
+  fn test_next_anniversary_leap_day() {
+    let date = date! { 2096-02-29 };
+    let after = date! { 2097-01-01 };
+    check!(date.next_anniversary(after, Feb29Policy::Feb28) == Some(date! { 2097-02-28 }));
+    check!(date.next_anniversary(after, Feb29Policy::Mar1) == Some(date! { 2097-03-01 }));
+    check!(date.next_anniversary(after, Feb29Policy::LeapYearsOnly) == Some(date! { 2104-02-29 }));
+    let after = date! { 2097-02-28 };
+    check!(date.next_anniversary(after, Feb29Policy::Feb28) == Some(date! { 2098-02-28 }));
+    check!(date.next_anniversary(after, Feb29Policy::Mar1) == Some(date! { 2097-03-01 }));
+  }
+
+  #[test]
+  fn test_next_leap_day() {
+    for date in date! { 1896-01-01 }.iter_through(date! { 2108-12-31 }).step_by(5) {
+      let leap_day = date.next_leap_day().unwrap();
+      check!((leap_day.month(), leap_day.day()) == (2, 29));
+      check!(leap_day >= date);
+      check!(date.iter_through(leap_day).filter(|d| (d.month(), d.day()) == (2, 29)).count() == 1);
+    }
+    check!(Date::MIN.next_leap_day() == Some(date! { -32768-02-29 }));
+    check!(date! { 32764-02-29 }.next_leap_day() == Some(date! { 32764-02-29 }));
+    check!(date! { 32764-03-01 }.next_leap_day() == None);
+    check!(Date::MAX.next_leap_day() == None);
+  }
+
+  #[test]
+  fn test_years_since() {
+    let birthday = date! { 1990-06-15 };
+    let policy = Feb29Policy::Feb28;
+    check!(birthday.years_since(birthday, policy) == Some(0));
+    check!(date! { 1991-06-14 }.years_since(birthday, policy) == Some(0));
+    check!(date! { 1991-06-15 }.years_since(birthday, policy) == Some(1));
+    check!(date! { 2024-12-31 }.years_since(birthday, policy) == Some(34));
+    check!(date! { 1990-06-14 }.years_since(birthday, policy) == None);
+    check!(Date::MAX.years_since(Date::MIN, policy) == Some(65_535));
+    check!(date! { 32767-12-30 }.years_since(date! { -32768-12-31 }, policy) == Some(65_534));
+  }
+
+  #[test]
+  fn test_years_since_leap_day() {
+    let leapling = date! { 2004-02-29 };
+    for (date, feb28, mar1) in [
+      (date! { 2005-02-27 }, 0, 0),
+      (date! { 2005-02-28 }, 1, 0),
+      (date! { 2005-03-01 }, 1, 1),
+      (date! { 2008-02-28 }, 3, 3),
+      (date! { 2008-02-29 }, 4, 4),
+    ] {
+      check!(date.years_since(leapling, Feb29Policy::Feb28) == Some(feb28));
+      check!(date.years_since(leapling, Feb29Policy::Mar1) == Some(mar1));
+      check!(date.years_since(leapling, Feb29Policy::LeapYearsOnly) == Some(mar1));
+    }
+  }
+
+  #[test]
+  fn test_leap_years_between() {
+    for start in -500..500 {
+      for end in [start - 1, start, start + 1, start + 4, start + 400, 2500] {
+        let expected = (start..end).filter(|&y| utils::is_leap_year(y)).count();
+        check!(Date::leap_years_between(start, end) as usize == expected);
+      }
+    }
+    check!(Date::leap_years_between(i16::MIN, i16::MAX) == 15_892);
+  }
+}
@@ -0,0 +1,314 @@
+//! Approximate astronomical events, using the algorithms in Jean Meeus' _Astronomical
+//! Algorithms_ (2nd edition, 1998).
+
+use core::fmt;
+
+use crate::Date;
+
+/// The Julian Day of the Unix epoch.
+const UNIX_EPOCH_JD: f64 = 2_440_587.5;
+
+/// The Julian Day of J2000.0.
+const J2000: f64 = 2_451_545.0;
+
+/// The Julian Day of the first mean new moon of 2000 (Meeus, equation 49.1).
+const NEW_MOON_2000: f64 = 2451550.09766;
+
+/// The mean length of a lunar month (from one new moon to the next), in days.
+const SYNODIC_MONTH: f64 = 29.530588861;
+
+/// The coefficients of the polynomials for the mean March equinox, June solstice, September
+/// equinox, and December solstice in years -1000 through 999 (Meeus, table 27.A).
+const MEAN_BEFORE_1000: [[f64; 5]; 4] = [
+  [1721139.29189, 365242.13740, 0.06134, 0.00111, -0.00071],
+  [1721233.25401, 365241.72562, -0.05323, 0.00907, 0.00025],
+  [1721325.70455, 365242.49558, -0.11677, -0.00297, 0.00074],
+  [1721414.39987, 365242.88257, -0.00769, -0.00933, -0.00006],
+];
+
+/// The coefficients of the polynomials for the mean March equinox, June solstice, September
+/// equinox, and December solstice in years 1000 through 3000 (Meeus, table 27.B).
+const MEAN_AFTER_1000: [[f64; 5]; 4] = [
+  [2451623.80984, 365242.37404, 0.05169, -0.00411, -0.00057],
+  [2451716.56767, 365241.62603, 0.00325, 0.00888, -0.00030],
+  [2451810.21715, 365242.01767, -0.11575, 0.00337, 0.00078],
+  [2451900.05952, 365242.74049, -0.06223, -0.00823, 0.00032],
+];
+
+/// The periodic terms used to correct the mean equinoxes and solstices (Meeus, table 27.C).
+const PERIODIC_TERMS: [(f64, f64, f64); 24] = [
+  (485.0, 324.96, 1_934.136),
+  (203.0, 337.23, 32_964.467),
+  (199.0, 342.08, 20.186),
+  (182.0, 27.85, 445_267.112),
+  (156.0, 73.14, 45_036.886),
+  (136.0, 171.52, 22_518.443),
+  (77.0, 222.54, 65_928.934),
+  (74.0, 296.72, 3_034.906),
+  (70.0, 243.58, 9_037.513),
+  (58.0, 119.81, 33_718.147),
+  (52.0, 297.17, 150.678),
+  (50.0, 21.02, 2_281.226),
+  (45.0, 247.54, 29_929.562),
+  (44.0, 325.15, 31_555.956),
+  (29.0, 60.93, 4_443.417),
+  (18.0, 155.12, 67_555.328),
+  (17.0, 288.79, 4_562.452),
+  (16.0, 198.04, 62_894.029),
+  (14.0, 199.76, 31_436.921),
+  (12.0, 95.39, 14_577.848),
+  (12.0, 287.11, 31_931.756),
+  (12.0, 320.81, 34_777.259),
+  (9.0, 227.73, 1_222.114),
+  (8.0, 15.45, 16_859.074),
+];
+
+impl Date {
+  /// The date of the March equinox (the start of astronomical spring in the northern hemisphere)
+  /// in the given year.
+  ///
+  /// The date is computed in Terrestrial Time, which differs from UTC by about a minute in the
+  /// present era, and is accurate to within a few minutes of the actual equinox. Returns `None`
+  /// for years outside of the supported range of -1000 through 3000.
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::date;
+  /// use date::Date;
+  ///
+  /// assert_eq!(Date::march_equinox(2024), Some(date! { 2024-03-20 }));
+  /// assert_eq!(Date::march_equinox(3001), None);
+  /// ```
+  pub fn march_equinox(year: i16) -> Option<Self> {
+    equinox_or_solstice(year, 0)
+  }
+
+  /// The date of the June solstice (the start of astronomical summer in the northern hemisphere)
+  /// in the given year.
+  ///
+  /// See [`Date::march_equinox`] for the accuracy and range of this calculation.
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::date;
+  /// use date::Date;
+  ///
+  /// assert_eq!(Date::june_solstice(2024), Some(date! { 2024-06-20 }));
+  /// ```
+  pub fn june_solstice(year: i16) -> Option<Self> {
+    equinox_or_solstice(year, 1)
+  }
+
+  /// The date of the September equinox (the start of astronomical autumn in the northern
+  /// hemisphere) in the given year.
+  ///
+  /// See [`Date::march_equinox`] for the accuracy and range of this calculation.
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::date;
+  /// use date::Date;
+  ///
+  /// assert_eq!(Date::september_equinox(2024), Some(date! { 2024-09-22 }));
+  /// ```
+  pub fn september_equinox(year: i16) -> Option<Self> {
+    equinox_or_solstice(year, 2)
+  }
+
+  /// The date of the December solstice (the start of astronomical winter in the northern
+  /// hemisphere) in the given year.
+  ///
+  /// See [`Date::march_equinox`] for the accuracy and range of this calculation.
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::date;
+  /// use date::Date;
+  ///
+  /// assert_eq!(Date::december_solstice(2024), Some(date! { 2024-12-21 }));
+  /// ```
+  pub fn december_solstice(year: i16) -> Option<Self> {
+    equinox_or_solstice(year, 3)
+  }
+
+  /// The approximate phase of the moon at noon (UTC) on this date.
+  ///
+  /// This uses the mean length of a lunar month, so the age of the moon may differ from the true
+  /// age by up to about half a day.
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::date;
+  /// use date::LunarPhase;
+  ///
+  /// let moon = date! { 2024-01-25 }.moon_phase();
+  /// assert_eq!(moon.phase(), LunarPhase::Full);
+  /// assert!((moon.age() - 14.8).abs() < 1.0);
+  /// ```
+  pub fn moon_phase(&self) -> MoonPhase {
+    let noon = self.0 as f64 + UNIX_EPOCH_JD + 0.5;
+    MoonPhase { age: (noon - NEW_MOON_2000).rem_euclid(SYNODIC_MONTH) }
+  }
+}
+
+/// The phase of the moon on a given date, as returned by [`Date::moon_phase`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct MoonPhase {
+  age: f64,
+}
+
+impl MoonPhase {
+  /// The number of days since the most recent new moon, between 0 and about 29.53.
+  pub const fn age(&self) -> f64 {
+    self.age
+  }
+
+  /// The fraction of the lunar month that has elapsed since the most recent new moon, between 0
+  /// and 1.
+  pub fn fraction(&self) -> f64 {
+    self.age / SYNODIC_MONTH
+  }
+
+  /// The named phase of the moon.
+  ///
+  /// Each of the eight named phases covers an eighth of the lunar month, centered on the moment
+  /// the phase is named for; for example, the moon is considered [`LunarPhase::Full`] from about
+  /// 1.8 days before the full moon until about 1.8 days after it.
+  pub fn phase(&self) -> LunarPhase {
+    match (self.fraction() * 8.0).round() as u8 {
+      1 => LunarPhase::WaxingCrescent,
+      2 => LunarPhase::FirstQuarter,
+      3 => LunarPhase::WaxingGibbous,
+      4 => LunarPhase::Full,
+      5 => LunarPhase::WaningGibbous,
+      6 => LunarPhase::LastQuarter,
+      7 => LunarPhase::WaningCrescent,
+      _ => LunarPhase::New,
+    }
+  }
+}
+
+/// A named phase of the moon.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum LunarPhase {
+  New,
+  WaxingCrescent,
+  FirstQuarter,
+  WaxingGibbous,
+  Full,
+  WaningGibbous,
+  LastQuarter,
+  WaningCrescent,
+}
+
+impl fmt::Display for LunarPhase {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str(match self {
+      Self::New => "New Moon",
+      Self::WaxingCrescent => "Waxing Crescent",
+      Self::FirstQuarter => "First Quarter",
+      Self::WaxingGibbous => "Waxing Gibbous",
+      Self::Full => "Full Moon",
+      Self::WaningGibbous => "Waning Gibbous",
+      Self::LastQuarter => "Last Quarter",
+      Self::WaningCrescent => "Waning Crescent",
+    })
+  }
+}
+
+/// The date of the given equinox or solstice (0 through 3, beginning with the March equinox), in
+/// the given year (Meeus, chapter 27).
+fn equinox_or_solstice(year: i16, event: usize) -> Option<Date> {
+  let (coefficients, y) = match year {
+    -1000..=999 => (MEAN_BEFORE_1000[event], year as f64 / 1000.0),
+    1000..=3000 => (MEAN_AFTER_1000[event], (year as f64 - 2000.0) / 1000.0),
+    _ => return None,
+  };
+  let jde0 = coefficients.iter().rev().fold(0.0, |acc, c| acc * y + c);
+  let t = (jde0 - J2000) / 36_525.0;
+  let w = (35_999.373 * t - 2.47).to_radians();
+  let delta_lambda = 1.0 + 0.0334 * w.cos() + 0.0007 * (2.0 * w).cos();
+  let s: f64 = PERIODIC_TERMS.iter().map(|(a, b, c)| a * (b + c * t).to_radians().cos()).sum();
+  Some(from_julian_day(jde0 + 0.00001 * s / delta_lambda))
+}
+
+/// The date on which the given Julian Day falls.
+fn from_julian_day(jd: f64) -> Date {
+  Date((jd - UNIX_EPOCH_JD).floor() as i32)
+}
+
+#[cfg(test)]
+mod tests {
+  use assert2::check;
+
+  use super::*;
+
+  #[test]
+  fn test_equinoxes_and_solstices() {
+    // Meeus, example 27.a.
+    check!(Date::june_solstice(1962) == Some(date! { 1962-06-21 }));
+
+    // The day of the month of each equinox and solstice.
+    for (year, days) in [
+      (2000, [20, 21, 22, 21]),
+      (2023, [20, 21, 23, 22]),
+      (2024, [20, 20, 22, 21]),
+      (2025, [20, 21, 22, 21]),
+    ] {
+      check!(Date::march_equinox(year) == Some(Date::new(year, 3, days[0])));
+      check!(Date::june_solstice(year) == Some(Date::new(year, 6, days[1])));
+      check!(Date::september_equinox(year) == Some(Date::new(year, 9, days[2])));
+      check!(Date::december_solstice(year) == Some(Date::new(year, 12, days[3])));
+    }
+  }
+
+  #[test]
+  fn test_range() {
+    for year in -1000..=3000 {
+      let march = Date::march_equinox(year).unwrap();
+      check!((march.year(), march.month()) == (year, 3));
+      check!((17..=22).contains(&march.day()));
+      check!(Date::june_solstice(year).unwrap().year() == year);
+      check!(Date::september_equinox(year).unwrap().year() == year);
+      check!(Date::december_solstice(year).unwrap().month() == 12);
+    }
+    check!(Date::march_equinox(-1001) == None);
+    check!(Date::december_solstice(3001) == None);
+  }
+
+  #[test]
+  fn test_moon_phase() {
+    for (date, phase) in [
+      (date! { 2000-01-06 }, LunarPhase::New),
+      (date! { 2024-01-18 }, LunarPhase::FirstQuarter),
+      (date! { 2024-01-25 }, LunarPhase::Full),
+      (date! { 2024-02-02 }, LunarPhase::LastQuarter),
+      (date! { 2024-02-09 }, LunarPhase::New),
+      (date! { 2024-04-08 }, LunarPhase::New),
+      (date! { 2024-01-14 }, LunarPhase::WaxingCrescent),
+      (date! { 2025-10-07 }, LunarPhase::Full),
+    ] {
+      check!(date.moon_phase().phase() == phase, "{date}");
+    }
+    check!(date! { 2024-02-10 }.moon_phase().age() < 1.0);
+    check!(date! { 2024-02-08 }.moon_phase().age() > 28.0);
+    for date in date! { 1999-01-01 }.iter_through(date! { 2001-01-01 }) {
+      let moon = date.moon_phase();
+      check!((0.0..SYNODIC_MONTH).contains(&moon.age()));
+      check!((0.0..1.0).contains(&moon.fraction()));
+    }
+    check!(LunarPhase::WaningGibbous.to_string() == "Waning Gibbous");
+  }
+
+  #[test]
+  fn test_from_julian_day() {
+    check!(from_julian_day(2_440_587.5) == date! { 1970-01-01 });
+    check!(from_julian_day(2_440_587.49) == date! { 1969-12-31 });
+    check!(from_julian_day(2_451_545.0) == date! { 2000-01-01 });
+  }
+}
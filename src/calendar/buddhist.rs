@@ -0,0 +1,98 @@
+#[cfg(feature = "std")]
+use strptime::Parser;
+
+use crate::format::FormattedDate;
+use crate::Date;
+#[cfg(feature = "std")]
+use crate::DateError;
+
+/// A system of numbering years, used when formatting and parsing dates.
+///
+/// The era is selected per call (see `FormattedDate::with_era` and [`Date::parse_with_era`]),
+/// and does not change how a `Date` is stored.
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub enum Era {
+  /// The common (Gregorian) era.
+  #[default]
+  Common,
+  /// The Buddhist era (พุทธศักราช), used in Thailand, which numbers years 543 years ahead of the
+  /// common era.
+  Buddhist,
+}
+
+impl Era {
+  /// The number of years by which this era is ahead of the common era.
+  pub const fn year_offset(&self) -> i16 {
+    match self {
+      Self::Common => 0,
+      Self::Buddhist => 543,
+    }
+  }
+}
+
+impl<'a> FormattedDate<'a> {
+  /// Format the year using the given era.
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::calendar::Era;
+  /// use date::date;
+  ///
+  /// let date = date! { 2024-04-21 };
+  /// assert_eq!(date.format("%-d/%m/%Y").with_era(Era::Buddhist).to_string(), "21/04/2567");
+  /// ```
+  pub fn with_era(mut self, era: Era) -> Self {
+    self.year_offset = era.year_offset();
+    self
+  }
+}
+
+impl Date {
+  /// Parse a date from a string, according to the provided format string, in which the year is
+  /// given in the provided era.
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::calendar::Era;
+  /// use date::date;
+  /// use date::Date;
+  ///
+  /// let date = Date::parse_with_era("21/04/2567", "%d/%m/%Y", Era::Buddhist)?;
+  /// assert_eq!(date, date! { 2024-04-21 });
+  /// # Ok::<(), date::DateError>(())
+  /// ```
+  #[cfg(feature = "std")]
+  pub fn parse_with_era(
+    date_str: impl AsRef<str>, date_fmt: &'static str, era: Era,
+  ) -> Result<Date, DateError> {
+    let parser = Parser::new(date_fmt);
+    let raw_date = parser.parse(date_str)?.date()?;
+    Self::try_new(raw_date.year() - era.year_offset(), raw_date.month(), raw_date.day())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use assert2::check;
+
+  use super::*;
+
+  #[test]
+  fn test_format() {
+    let date = date! { 2024-02-29 };
+    check!(date.format("%Y-%m-%d").with_era(Era::Common) == "2024-02-29");
+    check!(date.format("%Y-%m-%d").with_era(Era::Buddhist) == "2567-02-29");
+    check!(date.format("%F").with_era(Era::Buddhist) == "2567-02-29");
+    check!(date.format("%y").with_era(Era::Buddhist) == "67");
+  }
+
+  #[cfg(feature = "std")]
+  #[test]
+  fn test_parse() {
+    let date = date! { 2024-02-29 };
+    check!(Date::parse_with_era("2567-02-29", "%Y-%m-%d", Era::Buddhist).unwrap() == date);
+    check!(Date::parse_with_era("2024-02-29", "%Y-%m-%d", Era::Common).unwrap() == date);
+  }
+}
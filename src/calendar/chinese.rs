@@ -0,0 +1,70 @@
+use crate::Date;
+
+/// The first year in [`NEW_YEAR_OFFSETS`].
+const FIRST_YEAR: i16 = 1970;
+
+/// The date of the Chinese New Year in each year from 1970 through 2050, as a number of days
+/// after January 21.
+const NEW_YEAR_OFFSETS: [u8; 81] = [
+  16, 6, 25, 13, 2, 21, 10, 28, 17, 7, // 1970s
+  26, 15, 4, 23, 12, 30, 19, 8, 27, 16, // 1980s
+  6, 25, 14, 2, 20, 10, 29, 17, 7, 26, // 1990s
+  15, 3, 22, 11, 1, 19, 8, 28, 17, 5, // 2000s
+  24, 13, 2, 20, 10, 29, 18, 7, 26, 15, // 2010s
+  4, 22, 11, 1, 20, 8, 27, 16, 5, 23, // 2020s
+  13, 2, 21, 10, 29, 18, 7, 25, 14, 3, // 2030s
+  22, 11, 1, 20, 9, 27, 16, 5, 24, 12, // 2040s
+  2,  // 2050
+];
+
+impl Date {
+  /// The date of the Chinese New Year (the first day of the first month of the Chinese lunisolar
+  /// calendar) in the given Gregorian year.
+  ///
+  /// This is determined from a table, and returns `None` for years outside of the supported range
+  /// of 1970 through 2050.
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::date;
+  /// use date::Date;
+  ///
+  /// assert_eq!(Date::chinese_new_year(2024), Some(date! { 2024-02-10 }));
+  /// assert_eq!(Date::chinese_new_year(2025), Some(date! { 2025-01-29 }));
+  /// assert_eq!(Date::chinese_new_year(1900), None);
+  /// ```
+  pub const fn chinese_new_year(year: i16) -> Option<Self> {
+    if year < FIRST_YEAR || year >= FIRST_YEAR + NEW_YEAR_OFFSETS.len() as i16 {
+      return None;
+    }
+    let offset = NEW_YEAR_OFFSETS[(year - FIRST_YEAR) as usize];
+    Some(Self(Date::new(year, 1, 21).0 + offset as i32))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use assert2::check;
+
+  use super::*;
+
+  #[test]
+  fn test_chinese_new_year() {
+    check!(Date::chinese_new_year(1970) == Some(date! { 1970-02-06 }));
+    check!(Date::chinese_new_year(2023) == Some(date! { 2023-01-22 }));
+    check!(Date::chinese_new_year(2050) == Some(date! { 2050-01-23 }));
+    check!(Date::chinese_new_year(1969) == None);
+    check!(Date::chinese_new_year(2051) == None);
+  }
+
+  #[test]
+  fn test_lunar_years() {
+    // Each Chinese year has either 12 or 13 lunar months, and so is 353-355 or 383-385 days long.
+    for year in 1970..2050 {
+      let start = Date::chinese_new_year(year).unwrap();
+      let days = (Date::chinese_new_year(year + 1).unwrap() - start).days();
+      check!(matches!(days, 353..=355 | 383..=385), "{year}");
+    }
+  }
+}
@@ -0,0 +1,161 @@
+use super::in_range;
+use super::Calendar;
+use super::CalendarDate;
+use crate::Date;
+
+/// The first day of the Hebrew calendar (1 Tishri 1 AM), which was October 7, 3761 BCE in the
+/// Julian calendar.
+const EPOCH: i64 = Date::from_julian_calendar(-3760, 10, 7).0 as i64;
+
+/// The arithmetic Hebrew calendar.
+///
+/// Months are numbered following the biblical convention, beginning with Nisan (1) and ending
+/// with Adar (12) or, in leap years, Adar II (13); the year begins on 1 Tishri (month 7). In leap
+/// years, month 12 is Adar I.
+///
+/// The calendar is computed arithmetically from the molad (mean lunar conjunction), applying the
+/// postponement rules (dehiyyot) used by the fixed Hebrew calendar.
+///
+/// ## Examples
+///
+/// ```
+/// use date::calendar::Calendar;
+/// use date::calendar::CalendarDate;
+/// use date::calendar::Hebrew;
+/// use date::date;
+///
+/// // Passover (15 Nisan), 5784.
+/// assert_eq!(Hebrew.to_gregorian(CalendarDate::new(5784, 1, 15)), Some(date! { 2024-04-23 }));
+/// ```
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct Hebrew;
+
+impl Calendar for Hebrew {
+  fn from_gregorian(&self, date: Date) -> CalendarDate {
+    let days = date.0 as i64;
+    // The average Hebrew year is 35,975,351 / 98,496 days long.
+    let mut year = ((days - EPOCH) * 98_496).div_euclid(35_975_351);
+    while new_year(year + 1) <= days {
+      year += 1;
+    }
+    let start = if days < days_from_hebrew(year, 1, 1) { 7 } else { 1 };
+    let mut month = start;
+    while days > days_from_hebrew(year, month, days_in_month(year, month)) {
+      month += 1;
+    }
+    let day = days - days_from_hebrew(year, month, 1) + 1;
+    CalendarDate::new(year as i32, month, day as u8)
+  }
+
+  fn to_gregorian(&self, date: CalendarDate) -> Option<Date> {
+    let year = date.year() as i64;
+    let (month, day) = (date.month(), date.day());
+    // Limit the year so that the arithmetic cannot overflow.
+    let valid = year.abs() < 100_000
+      && (1..=months_in_year(year)).contains(&month)
+      && day >= 1
+      && day <= days_in_month(year, month);
+    match valid {
+      true => in_range(days_from_hebrew(year, month, day)),
+      false => None,
+    }
+  }
+}
+
+/// The number of days since the Unix epoch of the given Hebrew date.
+fn days_from_hebrew(year: i64, month: u8, day: u8) -> i64 {
+  // The year begins in Tishri (month 7), so months before Tishri come after the rest of the year.
+  let preceding: i64 = match month < 7 {
+    true =>
+      (7..=months_in_year(year)).chain(1..month).map(|m| days_in_month(year, m) as i64).sum(),
+    false => (7..month).map(|m| days_in_month(year, m) as i64).sum(),
+  };
+  new_year(year) + preceding + day as i64 - 1
+}
+
+/// Whether the given year is a leap year (with 13 months).
+const fn is_leap_year(year: i64) -> bool {
+  (7 * year + 1).rem_euclid(19) < 7
+}
+
+/// The number of months in the given year.
+const fn months_in_year(year: i64) -> u8 {
+  match is_leap_year(year) {
+    true => 13,
+    false => 12,
+  }
+}
+
+/// The number of days in the given month.
+const fn days_in_month(year: i64, month: u8) -> u8 {
+  let year_length = new_year(year + 1) - new_year(year);
+  match month {
+    2 | 4 | 6 | 10 | 13 => 29,
+    12 if !is_leap_year(year) => 29,
+    8 if year_length % 10 != 5 => 29, // Heshvan is long only in "complete" years.
+    9 if year_length % 10 == 3 => 29, // Kislev is short in "deficient" years.
+    _ => 30,
+  }
+}
+
+/// The number of days since the Unix epoch of 1 Tishri of the given year.
+const fn new_year(year: i64) -> i64 {
+  EPOCH + elapsed_days(year) + year_length_correction(year)
+}
+
+/// The number of days from the epoch to the molad of Tishri of the given year, postponed if the
+/// molad falls on a Sunday, Wednesday, or Friday.
+const fn elapsed_days(year: i64) -> i64 {
+  let months_elapsed = (235 * year - 234).div_euclid(19);
+  let parts_elapsed = 12_084 + 13_753 * months_elapsed;
+  let days = 29 * months_elapsed + parts_elapsed.div_euclid(25_920);
+  match (3 * (days + 1)).rem_euclid(7) < 3 {
+    true => days + 1,
+    false => days,
+  }
+}
+
+/// The additional postponement of the new year required to avoid years of invalid length.
+const fn year_length_correction(year: i64) -> i64 {
+  let (ny0, ny1, ny2) = (elapsed_days(year - 1), elapsed_days(year), elapsed_days(year + 1));
+  if ny2 - ny1 == 356 {
+    2
+  } else if ny1 - ny0 == 382 {
+    1
+  } else {
+    0
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use assert2::check;
+
+  use super::*;
+
+  #[test]
+  fn test_from_gregorian() {
+    check!(Hebrew.from_gregorian(date! { 2023-09-16 }) == CalendarDate::new(5784, 7, 1));
+    check!(Hebrew.from_gregorian(date! { 2024-10-02 }) == CalendarDate::new(5784, 6, 29));
+    check!(Hebrew.from_gregorian(date! { 2024-03-24 }) == CalendarDate::new(5784, 13, 14));
+    check!(Hebrew.from_gregorian(date! { 2000-01-01 }) == CalendarDate::new(5760, 10, 23));
+  }
+
+  #[test]
+  fn test_to_gregorian() {
+    check!(Hebrew.to_gregorian(CalendarDate::new(5785, 7, 1)) == Some(date! { 2024-10-03 }));
+    check!(Hebrew.to_gregorian(CalendarDate::new(5785, 7, 10)) == Some(date! { 2024-10-12 }));
+    check!(Hebrew.to_gregorian(CalendarDate::new(5785, 13, 1)) == None);
+    check!(Hebrew.to_gregorian(CalendarDate::new(5784, 2, 30)) == None);
+  }
+
+  #[test]
+  fn test_year_lengths() {
+    for year in 5700..5800 {
+      let length = new_year(year + 1) - new_year(year);
+      check!(matches!(length, 353..=355 | 383..=385), "{year}");
+      let months: i64 = (1..=months_in_year(year)).map(|m| days_in_month(year, m) as i64).sum();
+      check!(months == length);
+    }
+  }
+}
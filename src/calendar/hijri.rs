@@ -0,0 +1,187 @@
+use super::in_range;
+use super::Calendar;
+use super::CalendarDate;
+use crate::Date;
+
+/// The first day of the Islamic era (1 Muharram 1 AH), which was July 16, 622 in the Julian
+/// calendar.
+const EPOCH: i32 = Date::from_julian_calendar(622, 7, 16).0;
+
+impl Date {
+  /// Construct a new `Date` from a date in the arithmetic (tabular) Islamic calendar.
+  ///
+  /// The arithmetic Islamic calendar approximates the lunar Hijri calendar with alternating
+  /// 30- and 29-day months, and a 30-year cycle in which years 2, 5, 7, 10, 13, 16, 18, 21, 24,
+  /// 26, and 29 are leap years (with a 30-day twelfth month). This is the "civil" variant, whose
+  /// epoch is Friday, July 16, 622 (Julian).
+  ///
+  /// Religious observances (such as the start of Ramadan) are often determined by local moon
+  /// sighting or by the Umm al-Qura calendar (see [`Date::from_umm_al_qura`]), and may differ
+  /// from the arithmetic calendar by a day or two.
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::date;
+  /// use date::Date;
+  ///
+  /// assert_eq!(Date::from_hijri(1445, 9, 1), date! { 2024-03-11 });
+  /// ```
+  ///
+  /// ## Panic
+  ///
+  /// This function panics if it receives "out-of-bounds" values (e.g. the 30th day of an
+  /// even-numbered month other than Dhu al-Hijjah in a leap year), or if the date is outside the
+  /// range of [`Date::MIN`] to [`Date::MAX`].
+  pub const fn from_hijri(year: i32, month: u8, day: u8) -> Self {
+    assert!(month >= 1 && month <= 12, "Month out-of-bounds");
+    assert!(day >= 1 && day <= hijri_days_in_month(year, month), "Day out-of-bounds");
+    // Limit the year so that the arithmetic cannot overflow.
+    assert!(year.unsigned_abs() < 100_000, "Date out-of-bounds");
+    let days = days_from_hijri(year, month, day);
+    assert!(days >= Date::MIN.0 && days <= Date::MAX.0, "Date out-of-bounds");
+    Self(days)
+  }
+
+  /// The year, month, and day of this date in the arithmetic (tabular) Islamic calendar.
+  ///
+  /// See [`Date::from_hijri`] for a description of the calendar. The Islamic year is shorter than
+  /// the Gregorian year, so the years of the latest representable dates do not fit in an `i16`;
+  /// the result can always be passed back to [`Date::from_hijri`].
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::date;
+  ///
+  /// assert_eq!(date! { 2024-03-11 }.to_hijri(), (1445, 9, 1));
+  /// ```
+  pub const fn to_hijri(&self) -> (i32, u8, u8) {
+    hijri_from_days(self.0)
+  }
+}
+
+/// The arithmetic (tabular) Islamic calendar.
+///
+/// See [`Date::from_hijri`] for a description of the calendar. Months are numbered from Muharram
+/// (1) through Dhu al-Hijjah (12). For the Umm al-Qura calendar, see [`Date::from_umm_al_qura`].
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct Islamic;
+
+impl Calendar for Islamic {
+  fn from_gregorian(&self, date: Date) -> CalendarDate {
+    let (year, month, day) = hijri_from_days(date.0);
+    CalendarDate::new(year, month, day)
+  }
+
+  fn to_gregorian(&self, date: CalendarDate) -> Option<Date> {
+    let (year, month, day) = (date.year(), date.month(), date.day());
+    let valid = (1..=12).contains(&month) && day >= 1 && day <= hijri_days_in_month(year, month);
+    // Limit the year so that the arithmetic cannot overflow.
+    match valid && year.unsigned_abs() < 100_000 {
+      true => in_range(days_from_hijri(year, month, day) as i64),
+      false => None,
+    }
+  }
+}
+
+/// The number of days since the Unix epoch of the given date in the arithmetic Islamic calendar.
+const fn days_from_hijri(year: i32, month: u8, day: u8) -> i32 {
+  let month = month as i32;
+  let year_start = EPOCH + (year - 1) * 354 + (3 + 11 * year).div_euclid(30);
+  year_start + (59 * (month - 1) + 1) / 2 + day as i32 - 1
+}
+
+/// The year, month, and day in the arithmetic Islamic calendar of the given number of days since
+/// the Unix epoch.
+const fn hijri_from_days(days: i32) -> (i32, u8, u8) {
+  let year = (30 * (days - EPOCH) + 10_646).div_euclid(10_631);
+  let prior = days - days_from_hijri(year, 1, 1);
+  let month = (11 * prior + 330) / 325;
+  let month = if month > 12 { 12 } else { month as u8 };
+  let day = days - days_from_hijri(year, month, 1) + 1;
+  (year, month, day as u8)
+}
+
+/// Whether the given year is a leap year in the arithmetic Islamic calendar.
+const fn is_hijri_leap_year(year: i32) -> bool {
+  (14 + 11 * year).rem_euclid(30) < 11
+}
+
+/// The number of days in the given month in the arithmetic Islamic calendar.
+const fn hijri_days_in_month(year: i32, month: u8) -> u8 {
+  match month {
+    12 if is_hijri_leap_year(year) => 30,
+    m if m % 2 == 1 => 30,
+    _ => 29,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use assert2::check;
+
+  use super::*;
+  use crate::interval::DateInterval;
+
+  #[test]
+  fn test_from_hijri() {
+    check!(Date::from_hijri(1, 1, 1) == Date::from_julian_calendar(622, 7, 16));
+    // 1445 is a leap year in the arithmetic calendar, so it ends a day after the observed year.
+    check!(Date::from_hijri(1446, 1, 1) == date! { 2024-07-08 });
+    check!(Date::from_hijri(1445, 9, 30) == date! { 2024-04-09 });
+  }
+
+  #[test]
+  fn test_to_hijri() {
+    check!(Date::from_julian_calendar(622, 7, 16).to_hijri() == (1, 1, 1));
+    check!(Date::from_julian_calendar(622, 7, 15).to_hijri() == (0, 12, 29));
+    check!(date! { 2024-07-07 }.to_hijri() == (1445, 12, 30));
+    check!(date! { 2024-07-08 }.to_hijri() == (1446, 1, 1));
+    check!(Date::MAX.to_hijri().0 > i16::MAX as i32);
+    check!(Date::MAX.to_hijri() == (33_133, 1, 10));
+    check!(days_from_hijri(33_133, 1, 10) == Date::MAX.0);
+    check!(Date::MIN.to_hijri().0 < 0);
+  }
+
+  #[test]
+  fn test_leap_years() {
+    let leap_years: Vec<i32> = (1..=30).filter(|y| is_hijri_leap_year(*y)).collect();
+    check!(leap_years == vec![2, 5, 7, 10, 13, 16, 18, 21, 24, 26, 29]);
+    check!(Date::from_hijri(3, 1, 1) - Date::from_hijri(2, 12, 30) == DateInterval::new(1));
+  }
+
+  #[test]
+  fn test_round_trip() {
+    for date in Date::new(-1000, 1, 1).iter_through(date! { 3000-01-01 }) {
+      let (y, m, d) = date.to_hijri();
+      check!(Date::from_hijri(y, m, d) == date);
+    }
+    for date in [Date::MIN, Date::MAX] {
+      let (y, m, d) = date.to_hijri();
+      check!(Date::from_hijri(y, m, d) == date);
+    }
+  }
+
+  #[test]
+  fn test_calendar() {
+    check!(Islamic.from_gregorian(date! { 2024-03-11 }) == CalendarDate::new(1445, 9, 1));
+    check!(Islamic.to_gregorian(CalendarDate::new(1445, 9, 1)) == Some(date! { 2024-03-11 }));
+    check!(Islamic.to_gregorian(CalendarDate::new(1444, 12, 30)) == None);
+    check!(Islamic.to_gregorian(CalendarDate::new(1444, 13, 1)) == None);
+    check!(Islamic.to_gregorian(CalendarDate::new(40_000, 1, 1)) == None);
+  }
+
+  #[test]
+  #[should_panic]
+  fn test_invalid_day() {
+    Date::from_hijri(1444, 12, 30);
+  }
+
+  #[test]
+  #[should_panic]
+  fn test_out_of_range() {
+    let (year, month, day) = Date::MAX.to_hijri();
+    Date::from_hijri(year, month, day + 1);
+  }
+}
@@ -0,0 +1,137 @@
+use core::fmt;
+
+use crate::Date;
+
+/// A Japanese era (元号, nengō).
+///
+/// Only the eras since the adoption of the Gregorian calendar in Japan are represented.
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum JapaneseEra {
+  /// The Meiji era (明治), beginning October 23, 1868.
+  Meiji,
+  /// The Taishō era (大正), beginning July 30, 1912.
+  Taisho,
+  /// The Shōwa era (昭和), beginning December 25, 1926.
+  Showa,
+  /// The Heisei era (平成), beginning January 8, 1989.
+  Heisei,
+  /// The Reiwa era (令和), beginning May 1, 2019.
+  Reiwa,
+}
+
+impl JapaneseEra {
+  /// The first day of this era.
+  pub const fn start(&self) -> Date {
+    match self {
+      Self::Meiji => date! { 1868-10-23 },
+      Self::Taisho => date! { 1912-07-30 },
+      Self::Showa => date! { 1926-12-25 },
+      Self::Heisei => date! { 1989-01-08 },
+      Self::Reiwa => date! { 2019-05-01 },
+    }
+  }
+
+  /// The romanized name of this era.
+  pub const fn name(&self) -> &'static str {
+    match self {
+      Self::Meiji => "Meiji",
+      Self::Taisho => "Taishō",
+      Self::Showa => "Shōwa",
+      Self::Heisei => "Heisei",
+      Self::Reiwa => "Reiwa",
+    }
+  }
+
+  /// The name of this era in kanji.
+  pub const fn kanji(&self) -> &'static str {
+    match self {
+      Self::Meiji => "明治",
+      Self::Taisho => "大正",
+      Self::Showa => "昭和",
+      Self::Heisei => "平成",
+      Self::Reiwa => "令和",
+    }
+  }
+}
+
+impl fmt::Display for JapaneseEra {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str(self.name())
+  }
+}
+
+impl Date {
+  /// The Japanese era in which this date falls, and the year of that era.
+  ///
+  /// The first year of an era is the (partial) Gregorian year in which it began, so the year
+  /// number changes on January 1 but the era changes on the day of accession. Returns `None` for
+  /// dates before the Meiji era.
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::calendar::JapaneseEra;
+  /// use date::date;
+  ///
+  /// assert_eq!(date! { 2024-04-21 }.japanese_era(), Some((JapaneseEra::Reiwa, 6)));
+  /// assert_eq!(date! { 2019-04-30 }.japanese_era(), Some((JapaneseEra::Heisei, 31)));
+  /// assert_eq!(date! { 2019-05-01 }.japanese_era(), Some((JapaneseEra::Reiwa, 1)));
+  /// ```
+  ///
+  /// Japanese eras are also available when formatting dates, using the `%EC` (era name), `%Ey`
+  /// (era year), and `%EY` (full era year) tokens:
+  ///
+  /// ```
+  /// use date::date;
+  ///
+  /// assert_eq!(date! { 2024-04-21 }.format("%EY%-m月%-d日").to_string(), "令和6年4月21日");
+  /// assert_eq!(date! { 2019-05-01 }.format("%EY").to_string(), "令和元年");
+  /// ```
+  pub const fn japanese_era(&self) -> Option<(JapaneseEra, u16)> {
+    let era = match self.0 {
+      d if d >= JapaneseEra::Reiwa.start().0 => JapaneseEra::Reiwa,
+      d if d >= JapaneseEra::Heisei.start().0 => JapaneseEra::Heisei,
+      d if d >= JapaneseEra::Showa.start().0 => JapaneseEra::Showa,
+      d if d >= JapaneseEra::Taisho.start().0 => JapaneseEra::Taisho,
+      d if d >= JapaneseEra::Meiji.start().0 => JapaneseEra::Meiji,
+      _ => return None,
+    };
+    Some((era, (self.year() - era.start().year() + 1) as u16))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use assert2::check;
+
+  use super::*;
+
+  #[test]
+  fn test_japanese_era() {
+    check!(date! { 1868-10-22 }.japanese_era() == None);
+    check!(date! { 1868-10-23 }.japanese_era() == Some((JapaneseEra::Meiji, 1)));
+    check!(date! { 1912-07-29 }.japanese_era() == Some((JapaneseEra::Meiji, 45)));
+    check!(date! { 1912-07-30 }.japanese_era() == Some((JapaneseEra::Taisho, 1)));
+    check!(date! { 1926-12-25 }.japanese_era() == Some((JapaneseEra::Showa, 1)));
+    check!(date! { 1989-01-07 }.japanese_era() == Some((JapaneseEra::Showa, 64)));
+    check!(date! { 1989-01-08 }.japanese_era() == Some((JapaneseEra::Heisei, 1)));
+    check!(date! { 2020-01-01 }.japanese_era() == Some((JapaneseEra::Reiwa, 2)));
+  }
+
+  #[test]
+  fn test_names() {
+    check!(JapaneseEra::Reiwa.to_string() == "Reiwa");
+    check!(JapaneseEra::Showa.kanji() == "昭和");
+  }
+
+  #[test]
+  fn test_format() {
+    let date = date! { 2024-04-21 };
+    check!(date.format("%EC %Ey") == "令和 06");
+    check!(date.format("%EC%-Ey年") == "令和6年");
+    check!(date.format("%EY") == "令和6年");
+    check!(date! { 1989-01-08 }.format("%EY") == "平成元年");
+    check!(date! { 1850-01-01 }.format("%EC%Ey") == "1850");
+    check!(date! { 1850-01-01 }.format("%EY") == "1850");
+  }
+}
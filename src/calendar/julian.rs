@@ -0,0 +1,154 @@
+use super::in_range;
+use super::Calendar;
+use super::CalendarDate;
+use crate::Date;
+
+impl Date {
+  /// Construct a new `Date` from a date in the (proleptic) Julian calendar.
+  ///
+  /// The Julian calendar was in general use in Europe until the adoption of the Gregorian
+  /// calendar beginning in 1582, and remains in use for the liturgical calendars of several
+  /// Orthodox churches. Every fourth year is a leap year, with no century exceptions.
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::date;
+  /// use date::Date;
+  ///
+  /// // The Gregorian calendar was first adopted on October 15, 1582, which followed October 4 in
+  /// // the Julian calendar.
+  /// assert_eq!(Date::from_julian_calendar(1582, 10, 5), date! { 1582-10-15 });
+  /// // Orthodox Christmas.
+  /// assert_eq!(Date::from_julian_calendar(2023, 12, 25), date! { 2024-01-07 });
+  /// ```
+  ///
+  /// ## Panic
+  ///
+  /// This function panics if it receives "out-of-bounds" values (e.g. "March 32" or "February
+  /// 30"). However, it can be difficult to predict what values will be out-of-bounds in
+  /// non-leap years, so take care.
+  pub const fn from_julian_calendar(year: i16, month: u8, day: u8) -> Self {
+    const MONTH_DAYS: [u8; 12] = [31, 29, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
+    assert!(month >= 1 && month <= 12, "Month out-of-bounds");
+    assert!(day >= 1 && day <= MONTH_DAYS[month as usize - 1], "Day out-of-bounds");
+    if month == 2 && day == 29 {
+      assert!(is_julian_leap_year(year), "February 29 only occurs on leap years")
+    }
+
+    // This is the same algorithm used for Gregorian dates in `Date::new`, but with a four-year
+    // cycle in place of the 400-year cycle.
+    let year = year as i32 - if month <= 2 { 1 } else { 0 };
+    let month = month as i32;
+    let day = day as i32;
+    let era: i32 = if year >= 0 { year } else { year - 3 } / 4;
+    let year_of_era = year - era * 4;
+    let day_of_year = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day - 1;
+    Self(era * 1_461 + year_of_era * 365 + day_of_year - 719_470)
+  }
+
+  /// The year, month, and day of this date in the (proleptic) Julian calendar.
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::date;
+  ///
+  /// assert_eq!(date! { 1582-10-15 }.to_julian_calendar(), (1582, 10, 5));
+  /// assert_eq!(date! { 2024-01-07 }.to_julian_calendar(), (2023, 12, 25));
+  /// ```
+  pub const fn to_julian_calendar(&self) -> (i16, u8, u8) {
+    let shifted = self.0 + 719_470; // Days from March 1, 0 A.D. (Julian)
+    let era = if shifted >= 0 { shifted } else { shifted - 1_460 } / 1_461;
+    let doe = shifted - era * 1_461; // day of era: [0, 1_461)
+    let year_of_era = (doe - doe / 1_460) / 365;
+    let year = year_of_era + era * 4;
+    let day_of_year = doe - 365 * year_of_era;
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    ((year + if month <= 2 { 1 } else { 0 }) as i16, month as u8, day as u8)
+  }
+}
+
+/// The (proleptic) Julian calendar.
+///
+/// See [`Date::from_julian_calendar`] for a description of the calendar.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct Julian;
+
+impl Calendar for Julian {
+  fn from_gregorian(&self, date: Date) -> CalendarDate {
+    let (year, month, day) = date.to_julian_calendar();
+    CalendarDate::new(year as i32, month, day)
+  }
+
+  fn to_gregorian(&self, date: CalendarDate) -> Option<Date> {
+    let year = i16::try_from(date.year()).ok()?;
+    let days_in_month = match date.month() {
+      2 if is_julian_leap_year(year) => 29,
+      2 => 28,
+      4 | 6 | 9 | 11 => 30,
+      1..=12 => 31,
+      _ => return None,
+    };
+    match (1..=days_in_month).contains(&date.day()) {
+      true => in_range(Date::from_julian_calendar(year, date.month(), date.day()).0 as i64),
+      false => None,
+    }
+  }
+}
+
+/// Whether the given year is a leap year in the Julian calendar.
+const fn is_julian_leap_year(year: i16) -> bool {
+  year % 4 == 0
+}
+
+#[cfg(test)]
+mod tests {
+  use assert2::check;
+
+  use super::*;
+
+  #[test]
+  fn test_from_julian_calendar() {
+    check!(Date::from_julian_calendar(1582, 10, 4) == date! { 1582-10-14 });
+    check!(Date::from_julian_calendar(1969, 12, 19) == date! { 1970-01-01 });
+    check!(Date::from_julian_calendar(1900, 2, 29) == date! { 1900-03-13 });
+    check!(Date::from_julian_calendar(200, 3, 1) == date! { 200-03-01 });
+    check!(Date::from_julian_calendar(-100, 3, 1) == Date::new(-100, 2, 27));
+  }
+
+  #[test]
+  fn test_to_julian_calendar() {
+    check!(date! { 1970-01-01 }.to_julian_calendar() == (1969, 12, 19));
+    check!(date! { 1900-03-13 }.to_julian_calendar() == (1900, 2, 29));
+    check!(date! { 2100-03-14 }.to_julian_calendar() == (2100, 2, 29));
+    check!(Date::new(-100, 2, 27).to_julian_calendar() == (-100, 3, 1));
+  }
+
+  #[test]
+  fn test_round_trip() {
+    for date in Date::new(-1000, 1, 1).iter_through(date! { 1000-01-01 }) {
+      let (y, m, d) = date.to_julian_calendar();
+      check!(Date::from_julian_calendar(y, m, d) == date);
+    }
+    let (y, m, d) = Date::MAX.to_julian_calendar();
+    check!(Date::from_julian_calendar(y, m, d) == Date::MAX);
+  }
+
+  #[test]
+  fn test_calendar() {
+    check!(Julian.from_gregorian(date! { 2024-01-07 }) == CalendarDate::new(2023, 12, 25));
+    check!(Julian.to_gregorian(CalendarDate::new(1900, 2, 29)) == Some(date! { 1900-03-13 }));
+    check!(Julian.to_gregorian(CalendarDate::new(1901, 2, 29)) == None);
+    check!(Julian.to_gregorian(CalendarDate::new(1901, 4, 31)) == None);
+    check!(Julian.to_gregorian(CalendarDate::new(32_767, 12, 31)) == None);
+  }
+
+  #[test]
+  #[should_panic]
+  fn test_invalid_leap_day() {
+    Date::from_julian_calendar(1901, 2, 29);
+  }
+}
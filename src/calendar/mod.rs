@@ -0,0 +1,170 @@
+//! Conversions between the proleptic Gregorian calendar used by [`Date`] and other calendar
+//! systems.
+//!
+//! Calendars that represent dates as a year, month, and day implement the [`Calendar`] trait,
+//! which converts to and from a [`CalendarDate`]. The following calendars are provided, and other
+//! crates can implement the trait for additional calendars:
+//!
+//! | Calendar      | Description                                               |
+//! | ------------- | --------------------------------------------------------- |
+//! | [`Gregorian`] | The (proleptic) Gregorian calendar                        |
+//! | [`Hebrew`]    | The arithmetic Hebrew calendar                            |
+//! | [`Islamic`]   | The arithmetic (tabular) Islamic calendar                 |
+//! | [`Julian`]    | The (proleptic) Julian calendar                           |
+//! | [`Persian`]   | The arithmetic Persian (Solar Hijri) calendar             |
+//!
+//! ```
+//! use date::calendar::Calendar;
+//! use date::calendar::CalendarDate;
+//! use date::calendar::Hebrew;
+//! use date::date;
+//!
+//! // Rosh Hashanah, 5785.
+//! assert_eq!(Hebrew.from_gregorian(date! { 2024-10-03 }), CalendarDate::new(5785, 7, 1));
+//! assert_eq!(Hebrew.to_gregorian(CalendarDate::new(5785, 7, 1)), Some(date! { 2024-10-03 }));
+//! ```
+//!
+//! Additionally:
+//!
+//! - The Chinese New Year: [`Date::chinese_new_year`].
+//! - Japanese eras: [`Date::japanese_era`].
+//! - Buddhist era year numbering for formatting and parsing: [`Era`].
+//! - The Umm al-Qura calendar, for the years it has published tables: [`Date::from_umm_al_qura`]
+//!   and [`Date::to_umm_al_qura`].
+//!
+//! This module is available behind the `calendars` feature.
+
+use crate::utils;
+use crate::Date;
+
+mod buddhist;
+mod chinese;
+mod hebrew;
+mod hijri;
+mod japanese;
+mod julian;
+mod persian;
+mod umm_al_qura;
+
+pub use buddhist::Era;
+pub use hebrew::Hebrew;
+pub use hijri::Islamic;
+pub use japanese::JapaneseEra;
+pub use julian::Julian;
+pub use persian::Persian;
+
+/// A calendar system, which can convert dates to and from the Gregorian calendar.
+pub trait Calendar {
+  /// The date in this calendar corresponding to the given Gregorian date.
+  #[allow(clippy::wrong_self_convention)]
+  fn from_gregorian(&self, date: Date) -> CalendarDate;
+
+  /// The Gregorian date corresponding to the given date in this calendar.
+  ///
+  /// Returns `None` if the date is not valid in this calendar, or if it is outside of the range
+  /// that `Date` can represent.
+  fn to_gregorian(&self, date: CalendarDate) -> Option<Date>;
+}
+
+impl<T: Calendar + ?Sized> Calendar for &T {
+  fn from_gregorian(&self, date: Date) -> CalendarDate {
+    (**self).from_gregorian(date)
+  }
+
+  fn to_gregorian(&self, date: CalendarDate) -> Option<Date> {
+    (**self).to_gregorian(date)
+  }
+}
+
+/// A date in a calendar system other than the Gregorian calendar, represented as a year, month,
+/// and day.
+///
+/// The meaning of the month number is specific to each calendar.
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct CalendarDate {
+  year: i32,
+  month: u8,
+  day: u8,
+}
+
+impl CalendarDate {
+  /// A calendar date with the given year, month, and day.
+  ///
+  /// No validation is performed; the date is validated when it is converted using
+  /// [`Calendar::to_gregorian`].
+  pub const fn new(year: i32, month: u8, day: u8) -> Self {
+    Self { year, month, day }
+  }
+
+  /// The year.
+  #[inline]
+  pub const fn year(&self) -> i32 {
+    self.year
+  }
+
+  /// The month.
+  #[inline]
+  pub const fn month(&self) -> u8 {
+    self.month
+  }
+
+  /// The day of the month.
+  #[inline]
+  pub const fn day(&self) -> u8 {
+    self.day
+  }
+}
+
+/// The (proleptic) Gregorian calendar, in which [`Date`] is expressed.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct Gregorian;
+
+impl Calendar for Gregorian {
+  fn from_gregorian(&self, date: Date) -> CalendarDate {
+    let (year, month, day) = date.ymd();
+    CalendarDate::new(year as i32, month, day)
+  }
+
+  fn to_gregorian(&self, date: CalendarDate) -> Option<Date> {
+    let year = i16::try_from(date.year).ok()?;
+    let valid = (1..=12).contains(&date.month)
+      && date.day >= 1
+      && date.day <= utils::days_in_month(year, date.month);
+    valid.then(|| Date::new(year, date.month, date.day))
+  }
+}
+
+/// The given number of days since the Unix epoch as a `Date`, if it is within the range of
+/// representable dates.
+fn in_range(days: i64) -> Option<Date> {
+  (Date::MIN.0 as i64..=Date::MAX.0 as i64).contains(&days).then_some(Date(days as i32))
+}
+
+#[cfg(test)]
+mod tests {
+  use assert2::check;
+
+  use super::*;
+
+  #[test]
+  fn test_gregorian() {
+    check!(Gregorian.from_gregorian(date! { 2024-02-29 }) == CalendarDate::new(2024, 2, 29));
+    check!(Gregorian.to_gregorian(CalendarDate::new(2024, 2, 29)) == Some(date! { 2024-02-29 }));
+    check!(Gregorian.to_gregorian(CalendarDate::new(2023, 2, 29)) == None);
+    check!(Gregorian.to_gregorian(CalendarDate::new(2023, 13, 1)) == None);
+    check!(Gregorian.to_gregorian(CalendarDate::new(40_000, 1, 1)) == None);
+  }
+
+  #[test]
+  fn test_round_trip() {
+    let calendars: [&dyn Calendar; 5] = [&Gregorian, &Hebrew, &Islamic, &Julian, &Persian];
+    for calendar in calendars {
+      for date in date! { 1900-01-01 }.iter_through(date! { 2100-01-01 }) {
+        check!(calendar.to_gregorian(calendar.from_gregorian(date)) == Some(date));
+      }
+      for date in [Date::MIN, Date::MAX] {
+        check!(calendar.to_gregorian(calendar.from_gregorian(date)) == Some(date));
+      }
+    }
+  }
+}
@@ -0,0 +1,116 @@
+use super::in_range;
+use super::Calendar;
+use super::CalendarDate;
+use crate::Date;
+
+/// The number of days since the Unix epoch of the day before 1 Farvardin 1 AP, anchored so that
+/// Nowruz 1403 falls on March 20, 2024.
+const EPOCH: i64 = date! { 2024-03-20 }.0 as i64 - days_before_year(1403) - 1;
+
+/// The arithmetic Persian (Solar Hijri) calendar.
+///
+/// Months are numbered from Farvardin (1) through Esfand (12). The first six months have 31 days,
+/// the next five have 30, and Esfand has 29 (or 30 in leap years).
+///
+/// The official Iranian calendar determines the new year (Nowruz) astronomically, from the time
+/// of the vernal equinox in Tehran. This calendar instead uses a 33-year cycle of eight leap
+/// years, which agrees with the astronomical calendar for the years 1210 through 1629 AP (1831
+/// through 2250), and is extended proleptically in both directions.
+///
+/// ## Examples
+///
+/// ```
+/// use date::calendar::Calendar;
+/// use date::calendar::CalendarDate;
+/// use date::calendar::Persian;
+/// use date::date;
+///
+/// // Nowruz, 1403.
+/// assert_eq!(Persian.from_gregorian(date! { 2024-03-20 }), CalendarDate::new(1403, 1, 1));
+/// ```
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct Persian;
+
+impl Calendar for Persian {
+  fn from_gregorian(&self, date: Date) -> CalendarDate {
+    let days = date.0 as i64;
+    // There are 12,053 days in each 33-year cycle.
+    let mut year = ((days - EPOCH) * 33).div_euclid(12_053);
+    while days_from_persian(year + 1, 1, 1) <= days {
+      year += 1;
+    }
+    let day_of_year = days - days_from_persian(year, 1, 1) + 1;
+    let month = match day_of_year <= 186 {
+      true => (day_of_year + 30) / 31,
+      false => (day_of_year - 6 + 29) / 30,
+    };
+    let day = days - days_from_persian(year, month as u8, 1) + 1;
+    CalendarDate::new(year as i32, month as u8, day as u8)
+  }
+
+  fn to_gregorian(&self, date: CalendarDate) -> Option<Date> {
+    let year = date.year() as i64;
+    let (month, day) = (date.month(), date.day());
+    let days_in_month = match month {
+      1..=6 => 31,
+      7..=11 => 30,
+      12 if is_leap_year(year) => 30,
+      12 => 29,
+      _ => return None,
+    };
+    match (1..=days_in_month).contains(&day) {
+      true => in_range(days_from_persian(year, month, day)),
+      false => None,
+    }
+  }
+}
+
+/// Whether the given year is a leap year: years 1, 5, 9, 13, 17, 22, 26, and 30 of each 33-year
+/// cycle.
+const fn is_leap_year(year: i64) -> bool {
+  (8 * year + 29).rem_euclid(33) < 8
+}
+
+/// The number of days in the years preceding the given year (counting from 1 AP).
+const fn days_before_year(year: i64) -> i64 {
+  365 * (year - 1) + (8 * (year - 1) + 29).div_euclid(33)
+}
+
+/// The number of days since the Unix epoch of the given Persian date.
+const fn days_from_persian(year: i64, month: u8, day: u8) -> i64 {
+  let month = month as i64;
+  let preceding = if month <= 7 { 31 * (month - 1) } else { 30 * (month - 1) + 6 };
+  EPOCH + days_before_year(year) + preceding + day as i64
+}
+
+#[cfg(test)]
+mod tests {
+  use assert2::check;
+
+  use super::*;
+
+  #[test]
+  fn test_from_gregorian() {
+    check!(Persian.from_gregorian(date! { 2024-03-19 }) == CalendarDate::new(1402, 12, 29));
+    check!(Persian.from_gregorian(date! { 2025-03-20 }) == CalendarDate::new(1403, 12, 30));
+    check!(Persian.from_gregorian(date! { 2025-03-21 }) == CalendarDate::new(1404, 1, 1));
+    check!(Persian.from_gregorian(date! { 2024-09-22 }) == CalendarDate::new(1403, 7, 1));
+    check!(Persian.from_gregorian(date! { 1979-03-21 }) == CalendarDate::new(1358, 1, 1));
+  }
+
+  #[test]
+  fn test_to_gregorian() {
+    check!(Persian.to_gregorian(CalendarDate::new(1403, 1, 1)) == Some(date! { 2024-03-20 }));
+    check!(Persian.to_gregorian(CalendarDate::new(1402, 12, 30)) == None);
+    check!(Persian.to_gregorian(CalendarDate::new(1403, 7, 31)) == None);
+  }
+
+  #[test]
+  fn test_leap_years() {
+    let leap_years: Vec<i64> = (1..=33).filter(|y| is_leap_year(*y)).collect();
+    check!(leap_years == vec![1, 5, 9, 13, 17, 22, 26, 30]);
+    check!(is_leap_year(1399));
+    check!(is_leap_year(1403));
+    check!(!is_leap_year(1404));
+  }
+}
@@ -0,0 +1,186 @@
+use crate::Date;
+
+/// The first year covered by [`MONTH_LENGTHS`].
+const FIRST_YEAR: i32 = 1300;
+
+/// The last year covered by [`MONTH_LENGTHS`].
+const LAST_YEAR: i32 = 1600;
+
+/// The lengths of the months of each year from [`FIRST_YEAR`] through [`LAST_YEAR`] in the Umm
+/// al-Qura calendar, as published by the Saudi authorities (and reproduced in ICU): bit `n` is set
+/// if month `n + 1` has 30 days (rather than 29).
+const MONTH_LENGTHS: [u16; (LAST_YEAR - FIRST_YEAR + 1) as usize] = [
+  0x555, 0x2ab, 0x937, 0x2b6, 0x576, 0x36c, 0xb55, 0xaaa, 0x956, 0x49e, 0x95d, 0x2ba, 0x5b5,
+  0x3aa, 0xb4b, 0xa96, 0x52e, 0x2ad, 0x56d, 0xb5a, 0x752, 0xf25, 0xe8a, 0xd16, 0xa56, 0xab5,
+  0x6b4, 0xda9, 0xb92, 0xb25, 0x64b, 0xa9b, 0x35a, 0x6d9, 0x5d4, 0xda5, 0xd4a, 0xa95, 0x536,
+  0x975, 0x2f4, 0x6e9, 0x6d4, 0x6a9, 0x535, 0x25d, 0x4bd, 0x9ba, 0x3b4, 0xb69, 0xb2a, 0xa55,
+  0x4ad, 0xa5d, 0x2da, 0x6d9, 0xeaa, 0xe94, 0xd2a, 0xc56, 0x4ae, 0xa6d, 0x56a, 0xd55, 0xd4a,
+  0xa93, 0x52b, 0xa5b, 0x53a, 0x6b5, 0xea9, 0xd52, 0xd29, 0xa55, 0x4ad, 0x56d, 0xaea, 0x6e4,
+  0xed1, 0xda2, 0xaaa, 0x95a, 0x2da, 0x5b9, 0xbb2, 0x764, 0x6c9, 0x555, 0x2ab, 0x4db, 0xaba,
+  0x5b4, 0xda9, 0xd52, 0xaa5, 0x92d, 0x26d, 0x8ed, 0x2da, 0xad5, 0xaa5, 0xa4b, 0x497, 0x937,
+  0x2b6, 0x975, 0xd69, 0xd52, 0xc95, 0x92b, 0x25b, 0x4db, 0x9d5, 0x5d2, 0xda5, 0xd4a, 0xa95,
+  0x54d, 0xaad, 0x3aa, 0xbd2, 0xbc4, 0xb89, 0xa95, 0x52d, 0x5ad, 0xb6a, 0x6d4, 0xdc9, 0xd92,
+  0xaa6, 0x956, 0x2ae, 0x56d, 0x36a, 0xb55, 0xaaa, 0x94d, 0x49d, 0x95d, 0x2ba, 0x5b5, 0x5aa,
+  0xd55, 0xa9a, 0x92e, 0x26e, 0x55d, 0xada, 0x6d4, 0x6a5, 0xb27, 0xa4d, 0x4ad, 0x56d, 0xb5a,
+  0x754, 0xf49, 0xe92, 0xd26, 0xa56, 0x356, 0x6b5, 0xbaa, 0xb92, 0xb25, 0x68b, 0xa9b, 0x55a,
+  0xada, 0x5b4, 0xda9, 0xb52, 0xa9a, 0x536, 0x276, 0x575, 0xaf2, 0x6d4, 0x6a9, 0x555, 0x2ad,
+  0x4bd, 0x9ba, 0x574, 0xb69, 0xb52, 0xa95, 0x52d, 0xa5d, 0x4da, 0xad9, 0x6b2, 0xe95, 0xe2a,
+  0xc96, 0x92e, 0xaad, 0x56a, 0xd65, 0xd4a, 0xd15, 0x62b, 0xc5b, 0x53a, 0x6b5, 0xdb2, 0xd64,
+  0xd29, 0xa55, 0x4ad, 0x96d, 0xaea, 0x6e8, 0xed1, 0xda4, 0xd4a, 0xa6a, 0x2da, 0x5b9, 0xb72,
+  0xb68, 0x6d1, 0x655, 0x4ab, 0x95b, 0x2ba, 0x5b5, 0xda9, 0xd52, 0xca6, 0x94e, 0x46e, 0x95d,
+  0x4da, 0xad5, 0xaaa, 0xa4d, 0x49b, 0x937, 0x4b6, 0x975, 0xd6a, 0xd52, 0xaa5, 0x94b, 0x2ab,
+  0x55b, 0xad9, 0x5d2, 0xdc5, 0xd92, 0xb25, 0x555, 0xab5, 0x5b4, 0xba9, 0x7a2, 0x745, 0x593,
+  0xaab, 0x4d6, 0x9d6, 0x5d2, 0xba5, 0xb4a, 0xa95, 0x4ad, 0x15d, 0x2dd, 0x9da, 0x5b4, 0x5a9,
+  0x52d, 0x25b, 0x8b7, 0x176, 0x56d, 0xb6a, 0xaca, 0xa96, 0x52b, 0x15b, 0x2bb, 0x5b6, 0xdaa,
+  0xb94, 0xd46, 0xa8d, 0x52d, 0xa9d, 0x55a, 0x755, 0x749, 0xf13, 0xe4a, 0xa96, 0x556, 0x6b5,
+  0xbaa, 0xb94,
+];
+
+/// The number of days since the Unix epoch of the first day of each year from [`FIRST_YEAR`]
+/// through the year after [`LAST_YEAR`] in the Umm al-Qura calendar.
+const YEAR_STARTS: [i32; MONTH_LENGTHS.len() + 1] = {
+  // 1 Muharram 1300 was November 12, 1882.
+  let mut starts = [Date::new(1882, 11, 12).0; MONTH_LENGTHS.len() + 1];
+  let mut i = 0;
+  while i < MONTH_LENGTHS.len() {
+    starts[i + 1] = starts[i] + 348 + MONTH_LENGTHS[i].count_ones() as i32;
+    i += 1;
+  }
+  starts
+};
+
+impl Date {
+  /// Construct a new `Date` from a date in the Umm al-Qura calendar, or return `None` if the date
+  /// is not valid or falls outside the years 1300 through 1600 AH (1882 through 2174).
+  ///
+  /// The Umm al-Qura calendar is the official calendar of Saudi Arabia, and is widely used to
+  /// schedule Islamic observances such as Ramadan and the Eids. Unlike the arithmetic calendar
+  /// (see [`Date::from_hijri`]), its months follow astronomical calculations of the lunar cycle;
+  /// since it is defined by published month-start tables rather than by a formula, only the years
+  /// covered by those tables are supported.
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::date;
+  /// use date::Date;
+  ///
+  /// // Ramadan and Eid al-Fitr, 1445.
+  /// assert_eq!(Date::from_umm_al_qura(1445, 9, 1), Some(date! { 2024-03-11 }));
+  /// assert_eq!(Date::from_umm_al_qura(1445, 10, 1), Some(date! { 2024-04-10 }));
+  /// assert_eq!(Date::from_umm_al_qura(1445, 10, 30), None);
+  /// assert_eq!(Date::from_umm_al_qura(1601, 1, 1), None);
+  /// ```
+  pub const fn from_umm_al_qura(year: i32, month: u8, day: u8) -> Option<Self> {
+    if year < FIRST_YEAR || year > LAST_YEAR || month < 1 || month > 12 || day < 1 {
+      return None;
+    }
+    let lengths = MONTH_LENGTHS[(year - FIRST_YEAR) as usize];
+    if day > month_length(lengths, month) {
+      return None;
+    }
+    let mut days = YEAR_STARTS[(year - FIRST_YEAR) as usize];
+    let mut m = 1;
+    while m < month {
+      days += month_length(lengths, m) as i32;
+      m += 1;
+    }
+    Some(Self(days + day as i32 - 1))
+  }
+
+  /// The year, month, and day of this date in the Umm al-Qura calendar, or `None` if it falls
+  /// outside the years 1300 through 1600 AH (1882 through 2174).
+  ///
+  /// See [`Date::from_umm_al_qura`] for a description of the calendar.
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::date;
+  /// use date::Date;
+  ///
+  /// assert_eq!(date! { 2024-04-10 }.to_umm_al_qura(), Some((1445, 10, 1)));
+  /// assert_eq!(date! { 1800-01-01 }.to_umm_al_qura(), None);
+  /// ```
+  pub const fn to_umm_al_qura(&self) -> Option<(i32, u8, u8)> {
+    if self.0 < YEAR_STARTS[0] || self.0 >= YEAR_STARTS[YEAR_STARTS.len() - 1] {
+      return None;
+    }
+    // Find the last year that starts on or before this date.
+    let (mut low, mut high) = (0, YEAR_STARTS.len() - 1);
+    while high - low > 1 {
+      let mid = (low + high) / 2;
+      match YEAR_STARTS[mid] <= self.0 {
+        true => low = mid,
+        false => high = mid,
+      }
+    }
+    let lengths = MONTH_LENGTHS[low];
+    let mut day = self.0 - YEAR_STARTS[low] + 1;
+    let mut month = 1;
+    while day > month_length(lengths, month) as i32 {
+      day -= month_length(lengths, month) as i32;
+      month += 1;
+    }
+    Some((FIRST_YEAR + low as i32, month, day as u8))
+  }
+}
+
+/// The number of days in the given month of a year with the given month lengths.
+const fn month_length(lengths: u16, month: u8) -> u8 {
+  match lengths >> (month - 1) & 1 {
+    1 => 30,
+    _ => 29,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use assert2::check;
+
+  use super::*;
+
+  #[test]
+  fn test_from_umm_al_qura() {
+    check!(Date::from_umm_al_qura(1300, 1, 1) == Some(date! { 1882-11-12 }));
+    check!(Date::from_umm_al_qura(1445, 9, 1) == Some(date! { 2024-03-11 }));
+    check!(Date::from_umm_al_qura(1445, 12, 10) == Some(date! { 2024-06-16 }));
+    check!(Date::from_umm_al_qura(1446, 1, 1) == Some(date! { 2024-07-07 }));
+    check!(Date::from_umm_al_qura(1600, 12, 30) == Some(date! { 2174-11-25 }));
+    check!(Date::from_umm_al_qura(1299, 12, 29) == None);
+    check!(Date::from_umm_al_qura(1601, 1, 1) == None);
+    check!(Date::from_umm_al_qura(1445, 0, 1) == None);
+    check!(Date::from_umm_al_qura(1445, 13, 1) == None);
+    check!(Date::from_umm_al_qura(1445, 1, 0) == None);
+    check!(Date::from_umm_al_qura(1445, 1, 30) == None);
+    check!(Date::from_umm_al_qura(1445, 10, 30) == None);
+  }
+
+  #[test]
+  fn test_to_umm_al_qura() {
+    check!(date! { 1882-11-11 }.to_umm_al_qura() == None);
+    check!(date! { 1882-11-12 }.to_umm_al_qura() == Some((1300, 1, 1)));
+    check!(date! { 2024-04-09 }.to_umm_al_qura() == Some((1445, 9, 30)));
+    check!(date! { 2174-11-25 }.to_umm_al_qura() == Some((1600, 12, 30)));
+    check!(date! { 2174-11-26 }.to_umm_al_qura() == None);
+    check!(Date::MIN.to_umm_al_qura() == None);
+    check!(Date::MAX.to_umm_al_qura() == None);
+  }
+
+  #[test]
+  fn test_round_trip() {
+    for date in date! { 1882-11-12 }.iter_through(date! { 2174-11-25 }) {
+      let (year, month, day) = date.to_umm_al_qura().unwrap();
+      check!(Date::from_umm_al_qura(year, month, day) == Some(date));
+    }
+  }
+
+  #[test]
+  fn test_month_lengths() {
+    for lengths in MONTH_LENGTHS {
+      check!((6..=7).contains(&lengths.count_ones()));
+      check!(lengths >> 12 == 0);
+    }
+    check!(YEAR_STARTS[YEAR_STARTS.len() - 1] == date! { 2174-11-26 }.0);
+  }
+}
@@ -0,0 +1,226 @@
+use core::fmt;
+use core::str::FromStr;
+
+use crate::Date;
+
+/// The dates matched by a cron expression.
+///
+/// A cron expression has five whitespace-separated fields: minute, hour, day of the month, month,
+/// and day of the week. Since this crate deals only in dates, the minute and hour fields must be
+/// present but are otherwise ignored; the last three fields may also be given on their own.
+///
+/// Each field is `*` (any value), a number, a range (`1-5`), or a comma-separated list of these,
+/// and numbers and ranges may be followed by a step (`*/2` or `1-15/7`). Months and weekdays may
+/// also be written using three-letter English names (`JAN`, `MON`), in any case; weekdays are
+/// numbered from 0 (Sunday) to 6, and 7 is also Sunday. The `@yearly` (or `@annually`),
+/// `@monthly`, `@weekly`, and `@daily` (or `@midnight`) shorthands are also accepted.
+///
+/// As in most cron implementations, if both the day of the month and the day of the week are
+/// restricted (that is, neither begins with `*`), a date matches if _either_ matches.
+///
+/// ## Examples
+///
+/// ```
+/// use date::date;
+/// use date::CronSchedule;
+///
+/// let schedule: CronSchedule = "0 6 1,15 JAN,APR,JUL,OCT *".parse()?;
+/// assert!(schedule.matches(date! { 2024-04-15 }));
+/// assert!(!schedule.matches(date! { 2024-05-15 }));
+/// assert_eq!(schedule.next_after(date! { 2024-04-15 }), Some(date! { 2024-07-01 }));
+///
+/// let weekdays: CronSchedule = "* * MON-FRI".parse()?;
+/// assert_eq!(weekdays.next_after(date! { 2024-04-19 }), Some(date! { 2024-04-22 }));
+/// # Ok::<(), date::ParseCronError>(())
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct CronSchedule {
+  days: u32,
+  months: u16,
+  weekdays: u8,
+  any_day: bool,
+  any_weekday: bool,
+}
+
+impl CronSchedule {
+  /// Whether the given date matches the schedule.
+  pub const fn matches(&self, date: Date) -> bool {
+    if self.months & 1 << date.month() == 0 {
+      return false;
+    }
+    let day = self.days & 1 << date.day() != 0;
+    let weekday = self.weekdays & 1 << date.weekday() as u8 != 0;
+    match (self.any_day, self.any_weekday) {
+      (false, false) => day || weekday,
+      _ => day && weekday,
+    }
+  }
+
+  /// The first date after the given date that matches the schedule, or `None` if there is no such
+  /// date before [`Date::MAX`] (or no such date at all, such as for `* 30 2`).
+  pub fn next_after(&self, date: Date) -> Option<Date> {
+    // The Gregorian calendar repeats every 400 years (which is a whole number of weeks), so a
+    // schedule that matches no date in that span never matches.
+    const CYCLE: i32 = 146_097;
+    let limit = (date.0 as i64 + CYCLE as i64).min(Date::MAX.0 as i64) as i32;
+    let mut cursor = date.0 + 1;
+    while cursor <= limit {
+      let candidate = Date(cursor);
+      if self.months & 1 << candidate.month() == 0 {
+        cursor = candidate.last_of_month().0 + 1;
+        continue;
+      }
+      if self.matches(candidate) {
+        return Some(candidate);
+      }
+      cursor += 1;
+    }
+    None
+  }
+}
+
+impl FromStr for CronSchedule {
+  type Err = ParseCronError;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let s = match s.trim() {
+      "@yearly" | "@annually" => "1 1 *",
+      "@monthly" => "1 * *",
+      "@weekly" => "* * 0",
+      "@daily" | "@midnight" => "* * *",
+      s => s,
+    };
+    let mut fields = s.split_whitespace();
+    if s.split_whitespace().count() == 5 {
+      parse_field(fields.next().ok_or(ParseCronError)?, 0, 59, &[])?;
+      parse_field(fields.next().ok_or(ParseCronError)?, 0, 23, &[])?;
+    }
+    let (Some(days), Some(months), Some(weekdays), None) =
+      (fields.next(), fields.next(), fields.next(), fields.next())
+    else {
+      return Err(ParseCronError);
+    };
+    let weekday_mask = parse_field(weekdays, 0, 7, &WEEKDAYS)?;
+    Ok(Self {
+      days: parse_field(days, 1, 31, &[])? as u32,
+      months: parse_field(months, 1, 12, &MONTHS)? as u16,
+      weekdays: (weekday_mask | weekday_mask >> 7) as u8 & 0x7f,
+      any_day: days.starts_with('*'),
+      any_weekday: weekdays.starts_with('*'),
+    })
+  }
+}
+
+/// The names of the months, beginning with January (month 1).
+const MONTHS: [&str; 12] =
+  ["JAN", "FEB", "MAR", "APR", "MAY", "JUN", "JUL", "AUG", "SEP", "OCT", "NOV", "DEC"];
+
+/// The names of the weekdays, beginning with Sunday (weekday 0).
+const WEEKDAYS: [&str; 7] = ["SUN", "MON", "TUE", "WED", "THU", "FRI", "SAT"];
+
+/// Parse a cron field into a bitmask of the values it matches.
+///
+/// Names, if any, correspond to the values beginning with `min`.
+fn parse_field(field: &str, min: u8, max: u8, names: &[&str]) -> Result<u64, ParseCronError> {
+  let value = |v: &str| match names.iter().position(|n| n.eq_ignore_ascii_case(v)) {
+    Some(index) => Ok(min + index as u8),
+    None => v.parse::<u8>().ok().filter(|v| (min..=max).contains(v)).ok_or(ParseCronError),
+  };
+  let mut mask = 0;
+  for part in field.split(',') {
+    let (range, step) = match part.split_once('/') {
+      Some((range, step)) =>
+        (range, step.parse::<u8>().ok().filter(|s| *s > 0).ok_or(ParseCronError)?),
+      None => (part, 1),
+    };
+    let (start, end) = match (range, range.split_once('-')) {
+      ("*", _) => (min, max),
+      (_, Some((start, end))) => (value(start)?, value(end)?),
+      (start, None) if part.contains('/') => (value(start)?, max),
+      (start, None) => (value(start)?, value(start)?),
+    };
+    if start > end {
+      return Err(ParseCronError);
+    }
+    mask |= (start..=end).step_by(step as usize).fold(0, |m, v| m | 1 << v);
+  }
+  Ok(mask)
+}
+
+/// An error returned when parsing a [`CronSchedule`] from a string fails.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ParseCronError;
+
+impl fmt::Display for ParseCronError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str("Invalid cron expression")
+  }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseCronError {}
+
+#[cfg(test)]
+mod tests {
+  use assert2::check;
+
+  use super::*;
+
+  fn cron(s: &str) -> CronSchedule {
+    s.parse().unwrap()
+  }
+
+  #[test]
+  fn test_parse() {
+    check!(cron("0 0 * * *") == cron("* * *"));
+    check!(cron("@daily") == cron("* * *"));
+    check!(cron("@weekly") == cron("* * SUN"));
+    check!(cron("* * 7") == cron("* * 0"));
+    check!(cron("* * 5-7") == cron("* * FRI,SAT,SUN"));
+    check!(cron("* jan-mar *") == cron("* 1,2,3 *"));
+    check!(cron("1/10 * *") == cron("1,11,21,31 * *"));
+    check!(cron("*/10 * *").matches(date! { 2024-01-31 }));
+    check!(!cron("*/10 * *").matches(date! { 2024-01-30 }));
+    check!(cron("5/10 * *") == cron("5,15,25 * *"));
+    check!(cron("1-15/7 * *") == cron("1,8,15 * *"));
+    for invalid in [
+      "", "* *", "* * * *", "* * * * * *", "0 * *", "60 0 * * *", "0 24 * * *", "32 * *",
+      "* 13 *", "* 0 *", "* * 8", "5-1 * *", "*/0 * *", "1- * *", "1,,2 * *", "* FOO *",
+      "@hourly",
+    ] {
+      check!(invalid.parse::<CronSchedule>() == Err(ParseCronError), "{invalid}");
+    }
+  }
+
+  #[test]
+  fn test_matches() {
+    let schedule = cron("0 0 13 * FRI");
+    check!(schedule.matches(date! { 2024-09-13 }));
+    check!(schedule.matches(date! { 2024-09-20 }));
+    check!(schedule.matches(date! { 2024-10-13 }));
+    check!(!schedule.matches(date! { 2024-10-14 }));
+
+    let schedule = cron("* 2 *");
+    check!(schedule.matches(date! { 2024-02-29 }));
+    check!(!schedule.matches(date! { 2024-03-01 }));
+    let year = date! { 2024-01-01 }.iter_through(date! { 2024-12-31 });
+    check!(year.filter(|d| schedule.matches(*d)).count() == 29);
+
+    // A restricted day with an unrestricted weekday matches on the day alone.
+    let schedule = cron("*/2 * *");
+    check!(schedule.matches(date! { 2024-04-01 }));
+    check!(!schedule.matches(date! { 2024-04-02 }));
+  }
+
+  #[test]
+  fn test_next_after() {
+    check!(cron("@monthly").next_after(date! { 2024-01-01 }) == Some(date! { 2024-02-01 }));
+    check!(cron("@yearly").next_after(date! { 2024-06-15 }) == Some(date! { 2025-01-01 }));
+    check!(cron("29 2 *").next_after(date! { 2024-02-29 }) == Some(date! { 2028-02-29 }));
+    check!(cron("29 2 *").next_after(date! { 2096-03-01 }) == Some(date! { 2104-02-29 }));
+    check!(cron("* * MON-FRI").next_after(date! { 2024-04-19 }) == Some(date! { 2024-04-22 }));
+    check!(cron("30 2 *").next_after(date! { 2024-01-01 }) == None);
+    check!(cron("* * *").next_after(Date::MAX) == None);
+    check!(cron("* * *").next_after(Date(Date::MAX.0 - 1)) == Some(Date::MAX));
+  }
+}
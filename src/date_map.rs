@@ -0,0 +1,370 @@
+//! A map keyed by date, stored densely for daily time series.
+
+use alloc::vec::Vec;
+use core::iter;
+use core::ops::Bound;
+use core::ops::RangeBounds;
+
+use crate::Date;
+
+/// A map from dates to values, backed by a contiguous vector.
+///
+/// Unlike a `HashMap` or `BTreeMap`, a `DateMap` stores one slot for every day between its first
+/// and last dates, so looking up or inserting a value is a single index operation. This makes it
+/// well-suited to daily time series, in which most days have a value; it is a poor fit for a few
+/// dates spread over a long period, since the days between them also take up space.
+///
+/// Inserting a date before the first date in the map shifts the existing values, and so takes
+/// time proportional to the size of the map; inserting dates in ascending order is efficient.
+///
+/// ## Examples
+///
+/// ```
+/// use date::date;
+/// use date::DateMap;
+/// use date::MissingDay;
+///
+/// let mut prices = DateMap::new();
+/// prices.insert(date! { 2024-04-19 }, 101.5);
+/// prices.insert(date! { 2024-04-22 }, 102.25);
+/// assert_eq!(prices.get(date! { 2024-04-19 }), Some(&101.5));
+/// assert_eq!(prices.get(date! { 2024-04-20 }), None);
+/// assert_eq!(prices.get_filled(date! { 2024-04-20 }, MissingDay::Previous), Some(&101.5));
+/// assert_eq!(prices.len(), 2);
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DateMap<T> {
+  start: Date,
+  values: Vec<Option<T>>,
+  len: usize,
+}
+
+/// How [`DateMap`] treats a day that does not have a value.
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub enum MissingDay {
+  /// The day has no value.
+  #[default]
+  Empty,
+  /// Use the value of the closest earlier day that has one (a "forward fill").
+  Previous,
+  /// Use the value of the closest later day that has one (a "backward fill").
+  Next,
+}
+
+impl<T> DateMap<T> {
+  /// Create a new, empty map.
+  pub const fn new() -> Self {
+    Self { start: Date::from_unix_days(0), values: Vec::new(), len: 0 }
+  }
+
+  /// Create a new, empty map with space for the given number of days, beginning on the given
+  /// date, without reallocating.
+  pub fn with_capacity(start: Date, days: usize) -> Self {
+    Self { start, values: Vec::with_capacity(days), len: 0 }
+  }
+
+  /// The number of dates that have a value.
+  pub const fn len(&self) -> usize {
+    self.len
+  }
+
+  /// Whether no dates have a value.
+  pub const fn is_empty(&self) -> bool {
+    self.len == 0
+  }
+
+  /// The earliest date that has a value.
+  pub fn first_date(&self) -> Option<Date> {
+    self.iter().next().map(|(date, _)| date)
+  }
+
+  /// The latest date that has a value.
+  pub fn last_date(&self) -> Option<Date> {
+    let index = self.values.iter().rposition(Option::is_some)?;
+    Some(self.date_at(index))
+  }
+
+  /// The value for the given date.
+  pub fn get(&self, date: Date) -> Option<&T> {
+    self.values.get(self.index(date)?)?.as_ref()
+  }
+
+  /// A mutable reference to the value for the given date.
+  pub fn get_mut(&mut self, date: Date) -> Option<&mut T> {
+    let index = self.index(date)?;
+    self.values.get_mut(index)?.as_mut()
+  }
+
+  /// The value for the given date, or, if the date has no value, the value determined by the
+  /// given policy.
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::date;
+  /// use date::DateMap;
+  /// use date::MissingDay;
+  ///
+  /// let map = DateMap::from([(date! { 2024-01-01 }, 'a'), (date! { 2024-01-05 }, 'b')]);
+  /// assert_eq!(map.get_filled(date! { 2024-01-03 }, MissingDay::Empty), None);
+  /// assert_eq!(map.get_filled(date! { 2024-01-03 }, MissingDay::Previous), Some(&'a'));
+  /// assert_eq!(map.get_filled(date! { 2024-01-03 }, MissingDay::Next), Some(&'b'));
+  /// ```
+  pub fn get_filled(&self, date: Date, policy: MissingDay) -> Option<&T> {
+    let offset = date.unix_days() as i64 - self.start.unix_days() as i64;
+    let last = self.values.len() as i64 - 1;
+    match policy {
+      MissingDay::Empty => self.get(date),
+      MissingDay::Previous if offset < 0 => None,
+      MissingDay::Previous => {
+        let end = offset.min(last) + 1;
+        self.values[..end as usize].iter().rev().find_map(Option::as_ref)
+      },
+      MissingDay::Next if offset > last => None,
+      MissingDay::Next => self.values[offset.max(0) as usize..].iter().find_map(Option::as_ref),
+    }
+  }
+
+  /// Whether the given date has a value.
+  pub fn contains(&self, date: Date) -> bool {
+    self.get(date).is_some()
+  }
+
+  /// Set the value for the given date, returning the previous value, if any.
+  pub fn insert(&mut self, date: Date, value: T) -> Option<T> {
+    if self.values.is_empty() {
+      self.start = date;
+    }
+    if date < self.start {
+      let days = (self.start.unix_days() - date.unix_days()) as usize;
+      self.values.splice(0..0, iter::repeat_with(|| None).take(days));
+      self.start = date;
+    }
+    let index = (date.unix_days() - self.start.unix_days()) as usize;
+    if index >= self.values.len() {
+      self.values.resize_with(index + 1, || None);
+    }
+    let previous = self.values[index].replace(value);
+    if previous.is_none() {
+      self.len += 1;
+    }
+    previous
+  }
+
+  /// Remove the value for the given date, returning it, if any.
+  pub fn remove(&mut self, date: Date) -> Option<T> {
+    let index = self.index(date)?;
+    let previous = self.values.get_mut(index)?.take();
+    if previous.is_some() {
+      self.len -= 1;
+    }
+    previous
+  }
+
+  /// Remove all values.
+  pub fn clear(&mut self) {
+    self.values.clear();
+    self.len = 0;
+  }
+
+  /// The dates and values in the map, in ascending order of date.
+  pub fn iter(&self) -> impl DoubleEndedIterator<Item = (Date, &T)> {
+    let start = self.start;
+    self.values.iter().enumerate().filter_map(move |(i, v)| {
+      v.as_ref().map(|v| (Date::from_unix_days(start.unix_days() + i as i32), v))
+    })
+  }
+
+  /// The dates and values within the given range of dates, in ascending order of date.
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::date;
+  /// use date::DateMap;
+  ///
+  /// let january = date! { 2024-01-01 }.iter_through(date! { 2024-01-31 });
+  /// let map: DateMap<_> = january.map(|d| (d, d.day())).collect();
+  /// let week = map.range(date! { 2024-01-08 }..date! { 2024-01-15 });
+  /// let week: Vec<_> = week.map(|(_, v)| *v).collect();
+  /// assert_eq!(week, vec![8, 9, 10, 11, 12, 13, 14]);
+  /// ```
+  pub fn range(
+    &self, range: impl RangeBounds<Date>,
+  ) -> impl DoubleEndedIterator<Item = (Date, &T)> {
+    let (from, to) = self.bounds(range);
+    let start = self.start;
+    self.values[from..to].iter().enumerate().filter_map(move |(i, v)| {
+      v.as_ref().map(|v| (Date::from_unix_days(start.unix_days() + (from + i) as i32), v))
+    })
+  }
+
+  /// Every date from `start` through `end` (inclusive), with its value as determined by the given
+  /// policy.
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::date;
+  /// use date::DateMap;
+  /// use date::MissingDay;
+  ///
+  /// let map = DateMap::from([(date! { 2024-01-02 }, 1), (date! { 2024-01-04 }, 2)]);
+  /// let filled: Vec<_> = map
+  ///   .iter_days(date! { 2024-01-01 }, date! { 2024-01-05 }, MissingDay::Previous)
+  ///   .map(|(_, v)| v.copied())
+  ///   .collect();
+  /// assert_eq!(filled, vec![None, Some(1), Some(1), Some(2), Some(2)]);
+  /// ```
+  pub fn iter_days(
+    &self, start: Date, end: Date, policy: MissingDay,
+  ) -> impl Iterator<Item = (Date, Option<&T>)> {
+    let mut previous = match policy {
+      MissingDay::Previous => self.get_filled(start, policy),
+      _ => None,
+    };
+    start.iter_through(end).map(move |date| match policy {
+      MissingDay::Empty => (date, self.get(date)),
+      MissingDay::Previous => {
+        previous = self.get(date).or(previous);
+        (date, previous)
+      },
+      MissingDay::Next => (date, self.get_filled(date, policy)),
+    })
+  }
+
+  /// The index in `values` of the given date, if it is not before the start of the map.
+  fn index(&self, date: Date) -> Option<usize> {
+    usize::try_from(date.unix_days() as i64 - self.start.unix_days() as i64).ok()
+  }
+
+  /// The date at the given index in `values`.
+  fn date_at(&self, index: usize) -> Date {
+    Date::from_unix_days(self.start.unix_days() + index as i32)
+  }
+
+  /// The indices in `values` (start inclusive, end exclusive) covered by the given range.
+  fn bounds(&self, range: impl RangeBounds<Date>) -> (usize, usize) {
+    let offset = |date: &Date| date.unix_days() as i64 - self.start.unix_days() as i64;
+    let len = self.values.len() as i64;
+    let from = match range.start_bound() {
+      Bound::Included(date) => offset(date),
+      Bound::Excluded(date) => offset(date) + 1,
+      Bound::Unbounded => 0,
+    };
+    let to = match range.end_bound() {
+      Bound::Included(date) => offset(date) + 1,
+      Bound::Excluded(date) => offset(date),
+      Bound::Unbounded => len,
+    };
+    let (from, to) = (from.clamp(0, len), to.clamp(0, len));
+    (from as usize, to.max(from) as usize)
+  }
+}
+
+impl<T> Default for DateMap<T> {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl<T, const N: usize> From<[(Date, T); N]> for DateMap<T> {
+  fn from(values: [(Date, T); N]) -> Self {
+    values.into_iter().collect()
+  }
+}
+
+impl<T> Extend<(Date, T)> for DateMap<T> {
+  fn extend<I: IntoIterator<Item = (Date, T)>>(&mut self, iter: I) {
+    for (date, value) in iter {
+      self.insert(date, value);
+    }
+  }
+}
+
+impl<T> FromIterator<(Date, T)> for DateMap<T> {
+  fn from_iter<I: IntoIterator<Item = (Date, T)>>(iter: I) -> Self {
+    let mut map = Self::new();
+    map.extend(iter);
+    map
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use assert2::check;
+
+  use super::*;
+
+  #[test]
+  fn test_insert_get() {
+    let mut map = DateMap::new();
+    check!(map.is_empty());
+    check!(map.insert(date! { 2024-01-10 }, 10) == None);
+    check!(map.insert(date! { 2024-01-12 }, 12) == None);
+    check!(map.insert(date! { 2024-01-08 }, 8) == None);
+    check!(map.insert(date! { 2024-01-12 }, 120) == Some(12));
+    check!(map.len() == 3);
+    check!(map.get(date! { 2024-01-08 }) == Some(&8));
+    check!(map.get(date! { 2024-01-09 }) == None);
+    check!(map.get(date! { 2024-01-12 }) == Some(&120));
+    check!(map.get(date! { 2024-01-07 }) == None);
+    check!(map.get(date! { 2024-01-13 }) == None);
+    check!(map.contains(date! { 2024-01-10 }));
+    *map.get_mut(date! { 2024-01-10 }).unwrap() += 1;
+    check!(map.get(date! { 2024-01-10 }) == Some(&11));
+    check!(map.first_date() == Some(date! { 2024-01-08 }));
+    check!(map.last_date() == Some(date! { 2024-01-12 }));
+  }
+
+  #[test]
+  fn test_remove() {
+    let mut map: DateMap<_> = [(date! { 2024-01-01 }, 'a'), (date! { 2024-01-03 }, 'c')].into();
+    check!(map.remove(date! { 2024-01-02 }) == None);
+    check!(map.remove(date! { 2023-12-31 }) == None);
+    check!(map.remove(date! { 2024-01-03 }) == Some('c'));
+    check!(map.len() == 1);
+    check!(map.last_date() == Some(date! { 2024-01-01 }));
+    map.clear();
+    check!(map.is_empty());
+    check!(map.first_date() == None);
+    map.insert(date! { 1999-12-31 }, 'z');
+    check!(map.iter().collect::<Vec<_>>() == vec![(date! { 1999-12-31 }, &'z')]);
+  }
+
+  #[test]
+  fn test_iter_and_range() {
+    let dates = date! { 2024-01-01 }.iter_through(date! { 2024-01-10 });
+    let map: DateMap<_> = dates.filter(|d| d.day() % 2 == 0).map(|d| (d, d.day())).collect();
+    check!(map.iter().map(|(_, v)| *v).collect::<Vec<_>>() == vec![2, 4, 6, 8, 10]);
+    check!(map.iter().next_back() == Some((date! { 2024-01-10 }, &10)));
+    let values = |r: Vec<(Date, &u8)>| r.into_iter().map(|(_, v)| *v).collect::<Vec<_>>();
+    let (jan4, jan8) = (date! { 2024-01-04 }, date! { 2024-01-08 });
+    check!(values(map.range(jan4..=jan8).collect()) == vec![4, 6, 8]);
+    check!(values(map.range(jan4..jan8).collect()) == vec![4, 6]);
+    check!(values(map.range(..date! { 2024-01-05 }).collect()) == vec![2, 4]);
+    check!(values(map.range(date! { 2024-01-09 }..).collect()) == vec![10]);
+    check!(map.range(date! { 2023-01-01 }..date! { 2023-02-01 }).count() == 0);
+    check!(map.range(date! { 2025-01-01 }..).count() == 0);
+    check!(map.range(jan8..jan4).count() == 0);
+  }
+
+  #[test]
+  fn test_missing_days() {
+    let map: DateMap<_> = [(date! { 2024-01-03 }, 3), (date! { 2024-01-06 }, 6)].into();
+    let filled = |policy| {
+      map
+        .iter_days(date! { 2024-01-01 }, date! { 2024-01-08 }, policy)
+        .map(|(_, v)| v.copied())
+        .collect::<Vec<_>>()
+    };
+    let (x, a, b) = (None, Some(3), Some(6));
+    check!(filled(MissingDay::Empty) == [x, x, a, x, x, b, x, x]);
+    check!(filled(MissingDay::Previous) == [x, x, a, a, a, b, b, b]);
+    check!(filled(MissingDay::Next) == [a, a, a, b, b, b, x, x]);
+    check!(map.get_filled(date! { 2024-02-01 }, MissingDay::Previous) == Some(&6));
+    check!(map.get_filled(date! { 2023-02-01 }, MissingDay::Next) == Some(&3));
+    check!(DateMap::<u8>::new().get_filled(date! { 2023-02-01 }, MissingDay::Next) == None);
+    check!(DateMap::<u8>::new().get_filled(date! { 2023-02-01 }, MissingDay::Previous) == None);
+  }
+}
@@ -0,0 +1,330 @@
+use alloc::vec::Vec;
+
+use crate::Date;
+use crate::DateRange;
+
+/// A set of dates, stored as a sorted list of disjoint [`DateRange`]s.
+///
+/// Overlapping and adjacent ranges are merged as they are inserted, so the set always holds the
+/// fewest ranges needed to represent its dates. This makes it well-suited to calendars made up
+/// of spans of days, such as holidays, blackout windows, or availability.
+///
+/// ## Examples
+///
+/// ```
+/// use date::date;
+/// use date::DateRange;
+/// use date::DateSet;
+///
+/// let mut blackout = DateSet::new();
+/// blackout.insert_range(DateRange::new(date! { 2024-12-20 }, date! { 2024-12-31 }));
+/// blackout.insert_range(DateRange::new(date! { 2025-01-01 }, date! { 2025-01-02 }));
+/// assert_eq!(blackout.ranges().len(), 1);
+/// assert!(blackout.contains(date! { 2025-01-01 }));
+///
+/// let december = DateRange::new(date! { 2024-12-01 }, date! { 2024-12-31 });
+/// let available = blackout.complement(december);
+/// assert_eq!(available.ranges(), [DateRange::new(date! { 2024-12-01 }, date! { 2024-12-19 })]);
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct DateSet {
+  ranges: Vec<DateRange>,
+}
+
+impl DateSet {
+  /// Create a new, empty set.
+  pub const fn new() -> Self {
+    Self { ranges: Vec::new() }
+  }
+
+  /// The disjoint ranges that make up the set, in ascending order.
+  pub fn ranges(&self) -> &[DateRange] {
+    &self.ranges
+  }
+
+  /// Whether the set contains no dates.
+  pub fn is_empty(&self) -> bool {
+    self.ranges.is_empty()
+  }
+
+  /// The number of dates in the set.
+  pub fn len_days(&self) -> u64 {
+    self.ranges.iter().map(|r| r.len_days() as u64).sum()
+  }
+
+  /// Whether the given date is in the set.
+  pub fn contains(&self, date: Date) -> bool {
+    let i = self.ranges.partition_point(|r| r.end() < date);
+    self.ranges.get(i).is_some_and(|r| r.start() <= date)
+  }
+
+  /// An iterator over every date in the set, in ascending order.
+  pub fn iter(&self) -> impl Iterator<Item = Date> + '_ {
+    self.ranges.iter().flat_map(DateRange::iter)
+  }
+
+  /// Add a single date to the set.
+  pub fn insert(&mut self, date: Date) {
+    self.insert_range(DateRange::new(date, date));
+  }
+
+  /// Add every date in the given range to the set.
+  pub fn insert_range(&mut self, range: DateRange) {
+    if range.is_empty() {
+      return;
+    }
+    let (mut start, mut end) = (range.start(), range.end());
+
+    // Find the ranges that overlap or are adjacent to the new one, and merge them.
+    let i = self.ranges.partition_point(|r| r.end().0 + 1 < start.0);
+    let j = self.ranges.partition_point(|r| r.start().0 <= end.0 + 1);
+    if i < j {
+      start = start.min(self.ranges[i].start());
+      end = end.max(self.ranges[j - 1].end());
+    }
+    self.ranges.splice(i..j, [DateRange::new(start, end)]);
+  }
+
+  /// Remove a single date from the set.
+  pub fn remove(&mut self, date: Date) {
+    self.remove_range(DateRange::new(date, date));
+  }
+
+  /// Remove every date in the given range from the set.
+  pub fn remove_range(&mut self, range: DateRange) {
+    if range.is_empty() {
+      return;
+    }
+    let (start, end) = (range.start(), range.end());
+
+    // Find the ranges that overlap the removed one, and keep only their parts outside of it.
+    let i = self.ranges.partition_point(|r| r.end() < start);
+    let j = self.ranges.partition_point(|r| r.start() <= end);
+    if i == j {
+      return;
+    }
+    let mut remaining = Vec::with_capacity(2);
+    if self.ranges[i].start() < start {
+      remaining.push(DateRange::new(self.ranges[i].start(), Date(start.0 - 1)));
+    }
+    if self.ranges[j - 1].end() > end {
+      remaining.push(DateRange::new(Date(end.0 + 1), self.ranges[j - 1].end()));
+    }
+    self.ranges.splice(i..j, remaining);
+  }
+
+  /// The dates that are in either set.
+  pub fn union(&self, other: &DateSet) -> DateSet {
+    let mut answer = self.clone();
+    answer.extend(other.ranges.iter().copied());
+    answer
+  }
+
+  /// The dates that are in both sets.
+  pub fn intersection(&self, other: &DateSet) -> DateSet {
+    let mut ranges = Vec::new();
+    let (mut a, mut b) = (self.ranges.iter().peekable(), other.ranges.iter().peekable());
+    while let (Some(x), Some(y)) = (a.peek(), b.peek()) {
+      if let Some(range) = x.intersect(**y) {
+        ranges.push(range);
+      }
+      match x.end() < y.end() {
+        true => a.next(),
+        false => b.next(),
+      };
+    }
+    DateSet { ranges }
+  }
+
+  /// The dates that are in this set but not the other.
+  pub fn difference(&self, other: &DateSet) -> DateSet {
+    let mut answer = self.clone();
+    for range in &other.ranges {
+      answer.remove_range(*range);
+    }
+    answer
+  }
+
+  /// The dates within the given range that are not in this set.
+  pub fn complement(&self, within: DateRange) -> DateSet {
+    DateSet::from_iter([within]).difference(self)
+  }
+}
+
+impl Extend<DateRange> for DateSet {
+  fn extend<I: IntoIterator<Item = DateRange>>(&mut self, iter: I) {
+    for range in iter {
+      self.insert_range(range);
+    }
+  }
+}
+
+impl Extend<Date> for DateSet {
+  fn extend<I: IntoIterator<Item = Date>>(&mut self, iter: I) {
+    for date in iter {
+      self.insert(date);
+    }
+  }
+}
+
+impl FromIterator<DateRange> for DateSet {
+  fn from_iter<I: IntoIterator<Item = DateRange>>(iter: I) -> Self {
+    let mut set = Self::new();
+    set.extend(iter);
+    set
+  }
+}
+
+impl FromIterator<Date> for DateSet {
+  fn from_iter<I: IntoIterator<Item = Date>>(iter: I) -> Self {
+    let mut set = Self::new();
+    set.extend(iter);
+    set
+  }
+}
+
+impl From<DateRange> for DateSet {
+  fn from(range: DateRange) -> Self {
+    Self::from_iter([range])
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use assert2::check;
+
+  use super::*;
+
+  fn range(start: Date, end: Date) -> DateRange {
+    DateRange::new(start, end)
+  }
+
+  #[test]
+  fn test_insert() {
+    let mut set = DateSet::new();
+    check!(set.is_empty());
+    set.insert_range(range(date! { 2024-01-10 }, date! { 2024-01-20 }));
+    set.insert_range(range(date! { 2024-02-01 }, date! { 2024-02-05 }));
+    set.insert(date! { 2024-01-01 });
+    check!(set.ranges().len() == 3);
+    check!(set.len_days() == 17);
+
+    // Adjacent and overlapping ranges are merged.
+    set.insert_range(range(date! { 2024-01-21 }, date! { 2024-01-25 }));
+    check!(set.ranges()[1] == range(date! { 2024-01-10 }, date! { 2024-01-25 }));
+    set.insert_range(range(date! { 2024-01-02 }, date! { 2024-02-03 }));
+    check!(set.ranges() == [range(date! { 2024-01-01 }, date! { 2024-02-05 })]);
+
+    // Empty ranges are ignored.
+    set.insert_range(range(date! { 2030-01-02 }, date! { 2030-01-01 }));
+    check!(set.ranges().len() == 1);
+    set.insert_range(range(Date::MIN, Date::MIN));
+    set.insert_range(range(Date::MAX, Date::MAX));
+    check!(set.ranges().len() == 3);
+  }
+
+  #[test]
+  fn test_remove() {
+    let mut set = DateSet::from(range(date! { 2024-01-01 }, date! { 2024-01-31 }));
+    set.remove_range(range(date! { 2024-01-10 }, date! { 2024-01-19 }));
+    check!(
+      set.ranges()
+        == [
+          range(date! { 2024-01-01 }, date! { 2024-01-09 }),
+          range(date! { 2024-01-20 }, date! { 2024-01-31 }),
+        ]
+    );
+    set.remove(date! { 2024-01-01 });
+    set.remove(date! { 2024-01-31 });
+    set.remove(date! { 2024-02-15 });
+    check!(
+      set.ranges()
+        == [
+          range(date! { 2024-01-02 }, date! { 2024-01-09 }),
+          range(date! { 2024-01-20 }, date! { 2024-01-30 }),
+        ]
+    );
+    set.remove_range(range(date! { 2024-01-05 }, date! { 2024-01-25 }));
+    check!(
+      set.ranges()
+        == [
+          range(date! { 2024-01-02 }, date! { 2024-01-04 }),
+          range(date! { 2024-01-26 }, date! { 2024-01-30 }),
+        ]
+    );
+    set.remove_range(range(Date::MIN, Date::MAX));
+    check!(set.is_empty());
+  }
+
+  #[test]
+  fn test_contains() {
+    let set: DateSet = [
+      range(date! { 2024-01-01 }, date! { 2024-01-05 }),
+      range(date! { 2024-01-10 }, date! { 2024-01-15 }),
+    ]
+    .into_iter()
+    .collect();
+    let expected: Vec<Date> = set.iter().collect();
+    check!(expected.len() == 11);
+    for date in date! { 2023-12-25 }.iter_through(date! { 2024-01-20 }) {
+      check!(set.contains(date) == expected.contains(&date));
+    }
+    check!(
+      date! { 2024-01-01 }.iter_through(date! { 2024-01-05 }).collect::<DateSet>() == {
+        DateSet::from(range(date! { 2024-01-01 }, date! { 2024-01-05 }))
+      }
+    );
+  }
+
+  #[test]
+  fn test_set_algebra() {
+    let a: DateSet = [
+      range(date! { 2024-01-01 }, date! { 2024-01-10 }),
+      range(date! { 2024-01-20 }, date! { 2024-01-31 }),
+    ]
+    .into_iter()
+    .collect();
+    let b: DateSet = [
+      range(date! { 2024-01-05 }, date! { 2024-01-22 }),
+      range(date! { 2024-01-25 }, date! { 2024-01-25 }),
+    ]
+    .into_iter()
+    .collect();
+    check!(a.union(&b).ranges() == [range(date! { 2024-01-01 }, date! { 2024-01-31 })]);
+    check!(
+      a.intersection(&b).ranges()
+        == [
+          range(date! { 2024-01-05 }, date! { 2024-01-10 }),
+          range(date! { 2024-01-20 }, date! { 2024-01-22 }),
+          range(date! { 2024-01-25 }, date! { 2024-01-25 }),
+        ]
+    );
+    check!(
+      a.difference(&b).ranges()
+        == [
+          range(date! { 2024-01-01 }, date! { 2024-01-04 }),
+          range(date! { 2024-01-23 }, date! { 2024-01-24 }),
+          range(date! { 2024-01-26 }, date! { 2024-01-31 }),
+        ]
+    );
+    check!(b.difference(&a).ranges() == [range(date! { 2024-01-11 }, date! { 2024-01-19 })]);
+    let within = range(date! { 2023-12-30 }, date! { 2024-01-21 });
+    check!(
+      a.complement(within).ranges()
+        == [
+          range(date! { 2023-12-30 }, date! { 2023-12-31 }),
+          range(date! { 2024-01-11 }, date! { 2024-01-19 }),
+        ]
+    );
+
+    // Check every operation against a day-by-day evaluation.
+    let days = date! { 2023-12-25 }.iter_through(date! { 2024-02-05 });
+    for date in days {
+      let (x, y) = (a.contains(date), b.contains(date));
+      check!(a.union(&b).contains(date) == (x || y));
+      check!(a.intersection(&b).contains(date) == (x && y));
+      check!(a.difference(&b).contains(date) == (x && !y));
+      check!(a.complement(within).contains(date) == (within.contains(date) && !x));
+    }
+  }
+}
@@ -0,0 +1,135 @@
+//! Day count conventions, used to compute interest accrual between two dates.
+
+use crate::utils;
+use crate::Date;
+
+/// A day count convention.
+///
+/// Day count conventions determine the fraction of a year that elapses between two dates, which
+/// is used to compute the interest that accrues over that period.
+///
+/// ## Examples
+///
+/// ```
+/// use date::date;
+/// use date::day_count::DayCount;
+///
+/// let (start, end) = (date! { 2024-01-01 }, date! { 2024-07-01 });
+/// assert_eq!(DayCount::Act360.year_fraction(start, end), 182.0 / 360.0);
+/// assert_eq!(DayCount::Thirty360Us.year_fraction(start, end), 0.5);
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum DayCount {
+  /// Actual/365 Fixed: the actual number of days, divided by 365.
+  Act365Fixed,
+  /// Actual/360: the actual number of days, divided by 360.
+  Act360,
+  /// 30/360 US (also known as 30/360 Bond Basis with the end-of-month rule): every month is
+  /// treated as having 30 days, with special handling for the end of February.
+  Thirty360Us,
+  /// 30E/360 (also known as the Eurobond Basis): every month is treated as having 30 days, and
+  /// the 31st of any month is treated as the 30th.
+  Thirty360European,
+  /// Actual/Actual ISDA: the actual number of days falling in leap years, divided by 366, plus
+  /// the actual number of days falling in other years, divided by 365.
+  ActActIsda,
+}
+
+impl DayCount {
+  /// The fraction of a year between `start` and `end` under this convention.
+  ///
+  /// If `end` precedes `start`, the result is negative.
+  pub fn year_fraction(&self, start: Date, end: Date) -> f64 {
+    if end < start {
+      return -self.year_fraction(end, start);
+    }
+    match self {
+      Self::Act365Fixed => (end - start).days() as f64 / 365.0,
+      Self::Act360 => (end - start).days() as f64 / 360.0,
+      Self::Thirty360Us => {
+        let (y1, m1, mut d1) = start.ymd();
+        let (y2, m2, mut d2) = end.ymd();
+        let start_feb_end = m1 == 2 && d1 == utils::days_in_month(y1, 2);
+        if start_feb_end && m2 == 2 && d2 == utils::days_in_month(y2, 2) {
+          d2 = 30;
+        }
+        if start_feb_end {
+          d1 = 30;
+        }
+        if d2 == 31 && d1 >= 30 {
+          d2 = 30;
+        }
+        d1 = d1.min(30);
+        thirty_360((y1, m1, d1), (y2, m2, d2))
+      },
+      Self::Thirty360European => {
+        let (y1, m1, d1) = start.ymd();
+        let (y2, m2, d2) = end.ymd();
+        thirty_360((y1, m1, d1.min(30)), (y2, m2, d2.min(30)))
+      },
+      Self::ActActIsda => {
+        let mut fraction = 0.0;
+        let mut cursor = start;
+        while cursor.year() < end.year() {
+          let next_year = Date::new(cursor.year() + 1, 1, 1);
+          let year_len = utils::days_in_year(cursor.year()) as f64;
+          fraction += (next_year - cursor).days() as f64 / year_len;
+          cursor = next_year;
+        }
+        fraction + (end - cursor).days() as f64 / utils::days_in_year(end.year()) as f64
+      },
+    }
+  }
+}
+
+/// The 30/360 year fraction, given already-adjusted year, month, and day components.
+fn thirty_360(start: (i16, u8, u8), end: (i16, u8, u8)) -> f64 {
+  let years = end.0 as i32 - start.0 as i32;
+  let months = end.1 as i32 - start.1 as i32;
+  let days = end.2 as i32 - start.2 as i32;
+  (360 * years + 30 * months + days) as f64 / 360.0
+}
+
+#[cfg(test)]
+mod tests {
+  use assert2::check;
+
+  use super::*;
+
+  #[test]
+  fn test_act() {
+    let (start, end) = (date! { 2023-01-15 }, date! { 2023-07-15 });
+    check!(DayCount::Act365Fixed.year_fraction(start, end) == 181.0 / 365.0);
+    check!(DayCount::Act360.year_fraction(start, end) == 181.0 / 360.0);
+    check!(DayCount::Act360.year_fraction(end, start) == -181.0 / 360.0);
+    check!(DayCount::Act365Fixed.year_fraction(start, start) == 0.0);
+  }
+
+  #[test]
+  fn test_thirty_360_us() {
+    let yf = |a, b| DayCount::Thirty360Us.year_fraction(a, b);
+    check!(yf(date! { 2024-01-31 }, date! { 2024-03-31 }) == 60.0 / 360.0);
+    check!(yf(date! { 2024-01-30 }, date! { 2024-03-31 }) == 60.0 / 360.0);
+    check!(yf(date! { 2024-01-15 }, date! { 2024-03-31 }) == 76.0 / 360.0);
+    check!(yf(date! { 2023-02-28 }, date! { 2024-02-29 }) == 1.0);
+    check!(yf(date! { 2024-02-29 }, date! { 2024-03-31 }) == 30.0 / 360.0);
+    check!(yf(date! { 2023-02-28 }, date! { 2023-08-31 }) == 0.5);
+  }
+
+  #[test]
+  fn test_thirty_360_european() {
+    let yf = |a, b| DayCount::Thirty360European.year_fraction(a, b);
+    check!(yf(date! { 2024-01-31 }, date! { 2024-03-31 }) == 60.0 / 360.0);
+    check!(yf(date! { 2024-01-15 }, date! { 2024-03-31 }) == 75.0 / 360.0);
+    check!(yf(date! { 2023-02-28 }, date! { 2023-08-31 }) == 182.0 / 360.0);
+  }
+
+  #[test]
+  fn test_act_act_isda() {
+    let yf = |a, b| DayCount::ActActIsda.year_fraction(a, b);
+    check!(yf(date! { 2023-01-01 }, date! { 2024-01-01 }) == 1.0);
+    check!(yf(date! { 2024-01-01 }, date! { 2025-01-01 }) == 1.0);
+    check!(yf(date! { 2023-11-01 }, date! { 2024-05-01 }) == 61.0 / 365.0 + 121.0 / 366.0);
+    check!(yf(date! { 2022-07-01 }, date! { 2025-07-01 }) == 184.0 / 365.0 + 2.0 + 181.0 / 365.0);
+  }
+}
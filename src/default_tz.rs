@@ -0,0 +1,62 @@
+use std::error::Error;
+use std::fmt;
+use std::sync::RwLock;
+
+use crate::tz::TimeZoneRef;
+
+/// The process-wide default time zone, if one has been set.
+static DEFAULT_TZ: RwLock<Option<TimeZoneRef<'static>>> = RwLock::new(None);
+
+/// Set the process-wide default time zone, by its IANA name (for example, `"Europe/Berlin"`).
+///
+/// Once set, [`Date::today`](crate::Date::today) uses this time zone in place of the system's
+/// local time zone, which makes the current date independent of the `TZ` environment variable and
+/// similar configuration. Functions that take an explicit time zone, such as
+/// [`Date::today_tz`](crate::Date::today_tz), are unaffected.
+///
+/// ## Examples
+///
+/// ```
+/// use date::tz;
+///
+/// date::set_default_timezone("Europe/Berlin").unwrap();
+/// assert_eq!(date::default_timezone(), Some(tz::europe::BERLIN));
+/// assert!(date::set_default_timezone("Europe/Atlantis").is_err());
+/// # date::clear_default_timezone();
+/// ```
+pub fn set_default_timezone(name: &str) -> Result<(), UnknownTimeZoneError> {
+  let tz = tzdb::tz_by_name(name).ok_or_else(|| UnknownTimeZoneError { name: name.into() })?;
+  *DEFAULT_TZ.write().unwrap_or_else(|err| err.into_inner()) = Some(tz);
+  Ok(())
+}
+
+/// Clear the process-wide default time zone, so that the system's local time zone is used.
+pub fn clear_default_timezone() {
+  *DEFAULT_TZ.write().unwrap_or_else(|err| err.into_inner()) = None;
+}
+
+/// The process-wide default time zone, if one has been set with [`set_default_timezone`].
+pub fn default_timezone() -> Option<TimeZoneRef<'static>> {
+  *DEFAULT_TZ.read().unwrap_or_else(|err| err.into_inner())
+}
+
+/// An error returned when a time zone name is not found in the time zone database.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UnknownTimeZoneError {
+  name: String,
+}
+
+impl UnknownTimeZoneError {
+  /// The time zone name that was not found.
+  pub fn name(&self) -> &str {
+    &self.name
+  }
+}
+
+impl fmt::Display for UnknownTimeZoneError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "Unknown time zone: {}", self.name)
+  }
+}
+
+impl Error for UnknownTimeZoneError {}
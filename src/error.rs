@@ -0,0 +1,191 @@
+#[cfg(feature = "std")]
+use alloc::string::String;
+#[cfg(feature = "std")]
+use alloc::string::ToString;
+use core::fmt;
+
+/// An error returned by fallible operations in this crate.
+///
+/// Functions that can fail for several reasons (such as [`Date::parse`](crate::Date::parse))
+/// return this type directly. Functions that can only fail in one way return a more specific
+/// error type, each of which converts into `DateError`, so that the `?` operator can be used to
+/// collect errors of any kind.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum DateError {
+  /// The month was not between 1 and 12.
+  MonthOutOfRange { month: u8 },
+  /// The day was not a valid day of the given month.
+  DayOutOfRange { year: i16, month: u8, day: u8 },
+  /// The day of the year was not a valid day of the given year.
+  OrdinalOutOfRange { year: i16, ordinal: u16 },
+  /// A string could not be parsed, either because it did not match the expected format or
+  /// because the format string itself was not valid.
+  Parse(ParseDetail),
+  /// A weekday name or number was not valid.
+  InvalidWeekday,
+  /// A month name or number was not valid.
+  InvalidMonth,
+  /// A time zone name was not recognized.
+  UnknownTimeZone,
+  /// A time zone has no information for the requested time.
+  NoLocalTime,
+}
+
+impl DateError {
+  /// A parse error with no further detail.
+  pub(crate) const PARSE: Self = Self::Parse(ParseDetail::NONE);
+}
+
+impl fmt::Display for DateError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::MonthOutOfRange { month } => write!(f, "Month out-of-bounds: {month}"),
+      Self::DayOutOfRange { year, month, day } => {
+        write!(f, "Day out-of-bounds: {year:04}-{month:02}-{day:02}")
+      },
+      Self::OrdinalOutOfRange { year, ordinal } => {
+        write!(f, "Day of year out-of-bounds: {year:04}-{ordinal:03}")
+      },
+      Self::Parse(detail) => match detail.message() {
+        Some(message) => write!(f, "Input does not conform to the expected format:\n{message}"),
+        None => f.write_str("Input does not conform to the expected format"),
+      },
+      Self::InvalidWeekday => f.write_str("Invalid weekday"),
+      Self::InvalidMonth => f.write_str("Invalid month"),
+      Self::UnknownTimeZone => f.write_str("Unknown time zone"),
+      Self::NoLocalTime => f.write_str("No local time found for the time zone"),
+    }
+  }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DateError {}
+
+/// The details of a [`DateError::Parse`] error.
+///
+/// Errors from parsing with a format string (such as [`Date::parse`](crate::Date::parse)) carry
+/// the parser's diagnostic, which shows the input and where parsing failed; it is also included
+/// in the error message. Other parse errors carry no further detail.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ParseDetail {
+  #[cfg(feature = "std")]
+  message: Option<String>,
+}
+
+impl ParseDetail {
+  /// No further detail.
+  const NONE: Self = Self {
+    #[cfg(feature = "std")]
+    message: None,
+  };
+
+  /// The parser's diagnostic, if any.
+  pub fn message(&self) -> Option<&str> {
+    #[cfg(feature = "std")]
+    return self.message.as_deref();
+    #[cfg(not(feature = "std"))]
+    None
+  }
+}
+
+#[cfg(feature = "std")]
+impl From<strptime::ParseError> for DateError {
+  fn from(error: strptime::ParseError) -> Self {
+    Self::Parse(ParseDetail { message: Some(error.to_string()) })
+  }
+}
+
+#[cfg(feature = "tz")]
+impl From<::tz::error::FindLocalTimeTypeError> for DateError {
+  fn from(_: ::tz::error::FindLocalTimeTypeError) -> Self {
+    Self::NoLocalTime
+  }
+}
+
+impl From<crate::InvalidWeekdayError> for DateError {
+  fn from(_: crate::InvalidWeekdayError) -> Self {
+    Self::InvalidWeekday
+  }
+}
+
+impl From<crate::InvalidMonthError> for DateError {
+  fn from(_: crate::InvalidMonthError) -> Self {
+    Self::InvalidMonth
+  }
+}
+
+impl From<crate::ParseIsoWeekDateError> for DateError {
+  fn from(_: crate::ParseIsoWeekDateError) -> Self {
+    Self::PARSE
+  }
+}
+
+impl From<crate::interval::ParseDurationError> for DateError {
+  fn from(_: crate::interval::ParseDurationError) -> Self {
+    Self::PARSE
+  }
+}
+
+impl From<crate::ParseQuarterError> for DateError {
+  fn from(_: crate::ParseQuarterError) -> Self {
+    Self::PARSE
+  }
+}
+
+impl From<crate::ParseHalfYearError> for DateError {
+  fn from(_: crate::ParseHalfYearError) -> Self {
+    Self::PARSE
+  }
+}
+
+#[cfg(feature = "rrule")]
+impl From<crate::recurrence::RRuleError> for DateError {
+  fn from(_: crate::recurrence::RRuleError) -> Self {
+    Self::PARSE
+  }
+}
+
+#[cfg(feature = "tz")]
+impl From<crate::UnknownTimeZoneError> for DateError {
+  fn from(_: crate::UnknownTimeZoneError) -> Self {
+    Self::UnknownTimeZone
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use assert2::check;
+
+  use super::*;
+  use crate::Date;
+
+  #[test]
+  fn test_out_of_range() {
+    check!(Date::try_new(2024, 2, 29).is_ok());
+    check!(
+      Date::try_new(2023, 2, 29)
+        == Err(DateError::DayOutOfRange { year: 2023, month: 2, day: 29 })
+    );
+    check!(Date::try_new(2023, 0, 1) == Err(DateError::MonthOutOfRange { month: 0 }));
+    check!(Date::try_new(2023, 13, 1).unwrap_err().to_string() == "Month out-of-bounds: 13");
+    check!(Date::try_new(2023, 4, 31).unwrap_err().to_string() == "Day out-of-bounds: 2023-04-31");
+  }
+
+  #[test]
+  fn test_from() {
+    fn parse_all(weekday: &str, month: &str, quarter: &str) -> Result<(), DateError> {
+      weekday.parse::<crate::Weekday>()?;
+      month.parse::<crate::Month>()?;
+      quarter.parse::<crate::Quarter>()?;
+      Ok(())
+    }
+    check!(parse_all("Sat", "April", "2024-Q2") == Ok(()));
+    check!(parse_all("Caturday", "April", "2024-Q2") == Err(DateError::InvalidWeekday));
+    check!(parse_all("Sat", "Smarch", "2024-Q2") == Err(DateError::InvalidMonth));
+    check!(parse_all("Sat", "April", "2024-Q5") == Err(DateError::PARSE));
+    check!(DateError::from(crate::ParseIsoWeekDateError) == DateError::PARSE);
+    check!(DateError::from(crate::ParseHalfYearError) == DateError::PARSE);
+    check!(DateError::PARSE.to_string() == "Input does not conform to the expected format");
+  }
+}
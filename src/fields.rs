@@ -0,0 +1,109 @@
+use crate::Date;
+use crate::Weekday;
+
+/// The calendar components of a date, computed together.
+///
+/// Methods such as [`Date::year`], [`Date::month`], and [`Date::day`] each convert from the
+/// internal representation separately. Code that needs several components can instead call
+/// [`Date::fields`], which performs the conversion once.
+///
+/// ## Examples
+///
+/// ```
+/// use date::date;
+/// use date::Weekday;
+///
+/// let fields = date! { 2012-04-21 }.fields();
+/// assert_eq!(fields.year(), 2012);
+/// assert_eq!(fields.month(), 4);
+/// assert_eq!(fields.day(), 21);
+/// assert_eq!(fields.weekday(), Weekday::Saturday);
+/// assert_eq!(fields.day_of_year(), 112);
+/// ```
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub struct DateFields {
+  year: i16,
+  month: u8,
+  day: u8,
+  weekday: Weekday,
+  day_of_year: u16,
+}
+
+impl DateFields {
+  /// The year number in the calendar date.
+  #[inline]
+  pub const fn year(&self) -> i16 {
+    self.year
+  }
+
+  /// The month number, starting from 1.
+  #[inline]
+  pub const fn month(&self) -> u8 {
+    self.month
+  }
+
+  /// The day of the month, starting from 1.
+  #[inline]
+  pub const fn day(&self) -> u8 {
+    self.day
+  }
+
+  /// The day of the week.
+  #[inline]
+  pub const fn weekday(&self) -> Weekday {
+    self.weekday
+  }
+
+  /// The day of the year. Range: `[1, 366]`
+  #[inline]
+  pub const fn day_of_year(&self) -> u16 {
+    self.day_of_year
+  }
+
+  /// The week number of the year (between 0 and 53, inclusive), with a new week starting each
+  /// Sunday.
+  ///
+  /// This is the same as [`Date::week`].
+  #[inline]
+  pub const fn week(&self) -> u16 {
+    (self.day_of_year + 6 - self.weekday as u16) / 7
+  }
+}
+
+impl Date {
+  /// The year, month, day, weekday, and day of the year for this date.
+  pub const fn fields(&self) -> DateFields {
+    let (year, month, day) = self.ymd();
+    let day_of_year = (self.0 - Date::new(year, 1, 1).0 + 1) as u16;
+    DateFields { year, month, day, weekday: self.weekday(), day_of_year }
+  }
+}
+
+impl From<Date> for DateFields {
+  fn from(date: Date) -> Self {
+    date.fields()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use assert2::check;
+
+  use super::*;
+
+  #[test]
+  fn test_fields() {
+    for date in date! { 1999-12-01 }.iter_through(date! { 2005-01-31 }) {
+      let fields = date.fields();
+      check!(fields.year() == date.year());
+      check!(fields.month() == date.month());
+      check!(fields.day() == date.day());
+      check!(fields.weekday() == date.weekday());
+      check!(fields.day_of_year() == date.day_of_year());
+      check!(fields.week() == date.week());
+    }
+    for date in [Date::MIN, Date::MAX] {
+      check!(DateFields::from(date).day_of_year() == date.day_of_year());
+    }
+  }
+}
@@ -1,16 +1,27 @@
-use std::fmt::Debug;
-use std::fmt::Display;
-use std::fmt::Error;
-use std::fmt::Formatter;
-use std::fmt::Result;
-use std::fmt::Write;
+use core::fmt::Debug;
+use core::fmt::Display;
+use core::fmt::Error;
+use core::fmt::Formatter;
+use core::fmt::Result;
+use core::fmt::Write;
 
 use crate::Date;
+use crate::DateFields;
+use crate::Month;
+use crate::WeekPolicy;
 
 /// A date with a requested format.
 pub struct FormattedDate<'a> {
   pub(crate) date: &'a Date,
   pub(crate) format: &'a str,
+  pub(crate) year_offset: i16,
+}
+
+impl<'a> FormattedDate<'a> {
+  /// Whether the format string is valid for this date.
+  pub(crate) fn is_valid(&self) -> bool {
+    write!(Discard, "{}", self).is_ok()
+  }
 }
 
 impl<'a> Debug for FormattedDate<'a> {
@@ -21,11 +32,18 @@ impl<'a> Debug for FormattedDate<'a> {
 
 impl<'a> Display for FormattedDate<'a> {
   fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+    // ISO 8601 dates are by far the most common format, so skip the token loop for them.
+    if self.year_offset == 0 && matches!(self.format, "%Y-%m-%d" | "%F") {
+      return f.write_str(self.date.to_iso_string().as_str());
+    }
+
     // Iterate over the format string and consume it.
-    let d = self.date;
-    let ymd = self.date.ymd();
+    let fields = self.date.fields();
+    let ymd = (fields.year() as i32 + self.year_offset as i32, fields.month(), fields.day());
     let mut flag = false;
     let mut padding = Padding::Default;
+    #[cfg(feature = "calendars")]
+    let mut era = false;
     for c in self.format.chars() {
       if flag {
         // Apply padding if this is a padding change.
@@ -34,6 +52,8 @@ impl<'a> Display for FormattedDate<'a> {
           '0' => { padding = Padding::Zero; continue; },
           '-' => { padding = Padding::Suppress; continue; },
           '_' => { padding = Padding::Space; continue; },
+          #[cfg(feature = "calendars")]
+          'E' => { era = true; continue; },
           _ => {},
         };
 
@@ -50,27 +70,47 @@ impl<'a> Display for FormattedDate<'a> {
 
         // Write out the formatted component.
         flag = false;
+        #[cfg(feature = "calendars")]
+        if core::mem::take(&mut era) {
+          if let Some((e, year)) = self.date.japanese_era() {
+            match c {
+              'C' => f.write_str(e.kanji())?,
+              'y' => write_padded!(f, padding, 2, year)?,
+              'Y' if year == 1 => write!(f, "{}元年", e.kanji())?,
+              'Y' => write!(f, "{}{}年", e.kanji(), year)?,
+              _ => Err(Error)?,
+            }
+            continue;
+          }
+        }
         match c {
-          'Y' => write_padded!(f, padding, 4, ymd.0)?,
+          'Y' => {
+            f.write_str(year_sign(ymd.0))?;
+            write_padded!(f, padding, 4, ymd.0.unsigned_abs())?
+          },
           'C' => write_padded!(f, padding, 2, ymd.0 / 100)?,
           'y' => write_padded!(f, padding, 2, ymd.0 % 100)?,
           'm' => write_padded!(f, padding, 2, ymd.1)?,
-          'b' | 'h' => write!(f, "{}", d.month_abbv())?,
-          'B' => write!(f, "{}", d.month_name())?,
+          'b' | 'h' => f.write_str(fields.month_abbv())?,
+          'B' => f.write_str(fields.month_name())?,
           'd' => write_padded!(f, padding, 2, ymd.2)?,
-          'a' => write!(f, "{}", d.weekday().abbv())?,
-          'A' => write!(f, "{}", d.weekday())?,
-          'w' => write!(f, "{}", d.weekday() as u8)?,
-          'u' => write!(f, "{}", match d.weekday() {
-            crate::Weekday::Sunday => 7,
-            _ => self.date.weekday() as u8,
-          })?,
-          // U, W
-          'j' => write_padded!(f, padding, 3, d.day_of_year())?,
-          'U' => write_padded!(f, padding, 2, d.week())?,
+          'a' => f.write_str(fields.weekday().abbv())?,
+          'A' => write!(f, "{}", fields.weekday())?,
+          'w' => write!(f, "{}", fields.weekday() as u8)?,
+          'u' => write!(f, "{}", fields.weekday().number_from_monday())?,
+          'j' => write_padded!(f, padding, 3, fields.day_of_year())?,
+          'U' => write_padded!(f, padding, 2, self.date.week_number(WeekPolicy::FIRST_SUNDAY))?,
+          'W' => write_padded!(f, padding, 2, self.date.week_number(WeekPolicy::FIRST_MONDAY))?,
+          'V' => write_padded!(f, padding, 2, self.date.week_number(WeekPolicy::ISO))?,
           'D' => write!(f, "{:02}/{:02}/{:02}", ymd.1, ymd.2, ymd.0)?,
-          'F' => write!(f, "{:04}-{:02}-{:02}", ymd.0, ymd.1, ymd.2)?,
-          'v' => write!(f, "{:2}-{}-{:04}", d.day(), d.month_abbv(), d.year())?,
+          'F' => {
+            f.write_str(year_sign(ymd.0))?;
+            write!(f, "{:04}-{:02}-{:02}", ymd.0.unsigned_abs(), ymd.1, ymd.2)?
+          },
+          'v' => {
+            write!(f, "{:2}-{}-{}", ymd.2, fields.month_abbv(), year_sign(ymd.0))?;
+            write!(f, "{:04}", ymd.0.unsigned_abs())?
+          },
           't' => f.write_char('\t')?,
           'n' => f.write_char('\n')?,
           '%' => f.write_char('%')?,
@@ -87,48 +127,192 @@ impl<'a> Display for FormattedDate<'a> {
   }
 }
 
+/// The sign that precedes a year in ISO 8601: `-` for years before 0, and `+` for years after
+/// 9999 (which have more than four digits).
+const fn year_sign(year: i32) -> &'static str {
+  match year {
+    ..=-1 => "-",
+    10_000.. => "+",
+    _ => "",
+  }
+}
+
+/// A date formatted as an ISO 8601 (`YYYY-MM-DD`) string, stored inline without allocating.
+///
+/// This is returned by [`Date::to_iso_string`], which (unlike [`Date::format`]) can be used in
+/// `const` contexts and statics.
+#[derive(Copy, Clone, Eq, Hash, PartialEq)]
+pub struct IsoDateString {
+  bytes: [u8; 12],
+  len: u8,
+}
+
+impl IsoDateString {
+  /// The formatted date, as bytes.
+  pub const fn as_bytes(&self) -> &[u8] {
+    self.bytes.split_at(self.len as usize).0
+  }
+
+  /// The formatted date, as a string slice.
+  pub fn as_str(&self) -> &str {
+    core::str::from_utf8(self.as_bytes()).expect("ISO dates are always ASCII")
+  }
+}
+
+impl AsRef<str> for IsoDateString {
+  fn as_ref(&self) -> &str {
+    self.as_str()
+  }
+}
+
+impl Debug for IsoDateString {
+  fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+    Debug::fmt(self.as_str(), f)
+  }
+}
+
+impl Display for IsoDateString {
+  fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+    f.write_str(self.as_str())
+  }
+}
+
+impl Date {
+  /// Format the date as an ISO 8601 (`YYYY-MM-DD`) string.
+  ///
+  /// The output is identical to `date.format("%Y-%m-%d")`, but this method can be used in `const`
+  /// contexts. Years before 0 are preceded by `-`, and years after 9999 by `+`, as ISO 8601
+  /// requires for years with more than four digits.
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::date;
+  /// use date::Date;
+  /// use date::IsoDateString;
+  ///
+  /// const LAUNCH: IsoDateString = date! { 2012-04-21 }.to_iso_string();
+  /// assert_eq!(LAUNCH.as_str(), "2012-04-21");
+  /// ```
+  pub const fn to_iso_string(&self) -> IsoDateString {
+    let (year, month, day) = self.ymd();
+    let mut bytes = [0; 12];
+    let mut len = 0;
+    if let [sign] = year_sign(year as i32).as_bytes() {
+      bytes[0] = *sign;
+      len = 1;
+    }
+
+    // Pad the year to four digits (not including the sign).
+    let mut abs = year.unsigned_abs();
+    let mut digits = 4;
+    if abs >= 10_000 {
+      digits = 5;
+    }
+    len += digits;
+    let mut i = len;
+    while i > len - digits {
+      i -= 1;
+      bytes[i] = b'0' + (abs % 10) as u8;
+      abs /= 10;
+    }
+
+    // Write the month and day.
+    bytes[len] = b'-';
+    bytes[len + 1] = b'0' + month / 10;
+    bytes[len + 2] = b'0' + month % 10;
+    bytes[len + 3] = b'-';
+    bytes[len + 4] = b'0' + day / 10;
+    bytes[len + 5] = b'0' + day % 10;
+    IsoDateString { bytes, len: len as u8 + 6 }
+  }
+}
+
+/// A date formatted in the ISO 8601 expanded representation (`±YYYYYY-MM-DD`), which always
+/// includes the sign of the year and pads the year to an agreed number of digits.
+///
+/// This is returned by [`Date::to_iso_expanded`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct ExpandedIsoDate {
+  date: Date,
+  year_digits: u8,
+}
+
+impl Display for ExpandedIsoDate {
+  fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+    let (year, month, day) = self.date.ymd();
+    let sign = if year < 0 { '-' } else { '+' };
+    let abs = year.unsigned_abs();
+    write!(f, "{sign}{abs:0width$}-{month:02}-{day:02}", width = self.year_digits as usize)
+  }
+}
+
+impl Date {
+  /// Format the date in the ISO 8601 expanded representation, with an explicit sign and the
+  /// year zero-padded to the given number of digits.
+  ///
+  /// Systems that exchange expanded dates agree on the number of year digits in advance (six is
+  /// common). If the year has more digits than requested, all of them are written.
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::date;
+  ///
+  /// assert_eq!(date! { 2024-04-21 }.to_iso_expanded(6).to_string(), "+002024-04-21");
+  /// assert_eq!(date! { -0044-03-15 }.to_iso_expanded(5).to_string(), "-00044-03-15");
+  /// ```
+  pub const fn to_iso_expanded(&self, year_digits: u8) -> ExpandedIsoDate {
+    ExpandedIsoDate { date: *self, year_digits }
+  }
+}
+
 impl<'a> PartialEq<&str> for FormattedDate<'a> {
   fn eq(&self, other: &&str) -> bool {
-    &self.to_string().as_str() == other
+    let mut expected = Expected { remaining: other };
+    write!(expected, "{}", self).is_ok() && expected.remaining.is_empty()
   }
 }
 
-macro_rules! month_str {
-  ($($num:literal => $short:ident ~ $long:ident)*) => {
-    impl Date {
-      /// The English name of the month.
-      const fn month_name(&self) -> &'static str {
-        match self.month() {
-          $($num => stringify!($long),)*
-          #[cfg(not(tarpaulin_include))]
-          _ => panic!("Fictitious month"),
-        }
-      }
+/// A writer that discards the output written to it.
+struct Discard;
 
-      /// The three-letter abbreviation of the month.
-      const fn month_abbv(&self) -> &'static str {
-        match self.month() {
-          $($num => stringify!($short),)*
-          #[cfg(not(tarpaulin_include))]
-          _ => panic!("Fictitious month"),
-        }
-      }
+impl Write for Discard {
+  fn write_str(&mut self, _: &str) -> Result {
+    Ok(())
+  }
+}
+
+/// A writer that compares the output written to it against an expected string, without
+/// allocating.
+///
+/// Writing fails as soon as the output diverges from the expected string.
+struct Expected<'a> {
+  remaining: &'a str,
+}
+
+impl<'a> Write for Expected<'a> {
+  fn write_str(&mut self, s: &str) -> Result {
+    match self.remaining.strip_prefix(s) {
+      Some(remaining) => {
+        self.remaining = remaining;
+        Ok(())
+      },
+      None => Err(Error),
     }
   }
 }
-month_str! {
-   1 => Jan ~ January
-   2 => Feb ~ February
-   3 => Mar ~ March
-   4 => Apr ~ April
-   5 => May ~ May
-   6 => Jun ~ June
-   7 => Jul ~ July
-   8 => Aug ~ August
-   9 => Sep ~ September
-  10 => Oct ~ October
-  11 => Nov ~ November
-  12 => Dec ~ December
+
+impl DateFields {
+  /// The English name of the month.
+  const fn month_name(&self) -> &'static str {
+    Month::from_number(self.month()).name()
+  }
+
+  /// The three-letter abbreviation of the month.
+  const fn month_abbv(&self) -> &'static str {
+    Month::from_number(self.month()).abbreviation()
+  }
 }
 
 /// A padding modifier
@@ -147,6 +331,8 @@ enum Padding {
 mod tests {
   use assert2::check;
 
+  use super::*;
+
   #[test]
   fn test_format() {
     let date = date! { 2012-04-21 };
@@ -165,6 +351,7 @@ mod tests {
       ("%w %u", "6 6"),
       ("%t %n", "\t \n"),
       ("%Y week %U", "2012 week 16"),
+      ("%Y week %W / %V", "2012 week 16 / 16"),
     ] {
       check!(date.format(fmt_string).to_string() == date_str);
       check!(date.format(fmt_string) == date_str);
@@ -172,6 +359,25 @@ mod tests {
     }
   }
 
+  #[test]
+  fn test_week_numbers() {
+    check!(date! { 2021-01-03 }.format("%U %W %V") == "01 00 53");
+    check!(date! { 2024-12-30 }.format("%U %W %V") == "52 53 01");
+    check!(date! { 2024-01-01 }.format("%-U %-W %-V") == "0 1 1");
+  }
+
+  #[test]
+  fn test_eq() {
+    let date = date! { 2012-04-21 };
+    check!(date.format("%B %-d, %Y") == "April 21, 2012");
+    check!(date.format("%B %-d, %Y") != "April 21, 201");
+    check!(date.format("%B %-d, %Y") != "April 21, 20122");
+    check!(date.format("%B %-d, %Y") != "April 22, 2012");
+    check!(date.format("%B %-d, %Y") != "");
+    check!(date.format("") == "");
+    check!(date.format("%Q") != "%Q");
+  }
+
   #[test]
   fn test_padding() {
     let date = date! { 2024-07-04 };
@@ -182,4 +388,74 @@ mod tests {
       check!(date.format(fmt_string) == date_str);
     }
   }
+
+  #[test]
+  fn test_iso_string() {
+    const DATE: IsoDateString = date! { 2012-04-21 }.to_iso_string();
+    check!(DATE.as_str() == "2012-04-21");
+    check!(DATE.as_bytes() == b"2012-04-21");
+    check!(DATE.to_string() == "2012-04-21");
+    check!(format!("{:?}", DATE) == r#""2012-04-21""#);
+    for date in [
+      Date::MIN,
+      Date::MAX,
+      Date::new(-1, 12, 31),
+      Date::new(-999, 1, 1),
+      Date::new(-1000, 1, 1),
+      Date::new(0, 2, 29),
+      Date::new(7, 6, 5),
+      Date::new(9999, 12, 31),
+      Date::new(10000, 1, 1),
+    ] {
+      check!(date.to_iso_string().as_str() == date.format("%Y-%m-%d").to_string());
+      check!(date.format("%F").to_string() == date.format("%Y-%m-%d").to_string());
+      check!(date.to_string() == date.format("%Y-%m-%d").to_string());
+      check!(Date::parse_iso8601(date.to_iso_string().as_str()) == Some(date));
+    }
+  }
+
+  #[test]
+  fn test_iso_expanded() {
+    for (date, digits, iso) in [
+      (date! { 2024-04-21 }, 6, "+002024-04-21"),
+      (date! { 2024-04-21 }, 4, "+2024-04-21"),
+      (date! { 0000-01-01 }, 5, "+00000-01-01"),
+      (Date::new(-1, 12, 31), 6, "-000001-12-31"),
+      (Date::MIN, 6, "-032768-01-01"),
+      (Date::MAX, 5, "+32767-12-31"),
+    ] {
+      let expanded = date.to_iso_expanded(digits).to_string();
+      check!(expanded == iso);
+      check!(Date::parse_iso8601_expanded(&expanded, digits) == Some(date));
+      check!(Date::parse_iso8601(&expanded) == Some(date));
+    }
+
+    // Years with more digits than requested are written in full.
+    check!(Date::MAX.to_iso_expanded(4).to_string() == "+32767-12-31");
+    check!(Date::parse_iso8601_expanded("+32767-12-31", 4) == None);
+  }
+
+  #[test]
+  fn test_year_sign() {
+    for (date, iso) in [
+      (Date::MIN, "-32768-01-01"),
+      (Date::MAX, "+32767-12-31"),
+      (Date::new(-1, 12, 31), "-0001-12-31"),
+      (Date::new(-44, 3, 15), "-0044-03-15"),
+      (Date::new(-1000, 1, 1), "-1000-01-01"),
+      (Date::new(0, 2, 29), "0000-02-29"),
+      (Date::new(7, 6, 5), "0007-06-05"),
+      (Date::new(9999, 12, 31), "9999-12-31"),
+      (Date::new(10000, 1, 1), "+10000-01-01"),
+    ] {
+      check!(date.to_iso_string().as_str() == iso);
+      check!(date.format("%Y-%m-%d") == iso);
+      check!(date.format("%F") == iso);
+    }
+    let date = Date::new(-44, 3, 15);
+    check!(date.format("%-Y") == "-44");
+    check!(date.format("%_Y") == "-  44");
+    check!(date.format("%v") == "15-Mar--0044");
+    check!(Date::new(12345, 6, 7).format("%v") == " 7-Jun-+12345");
+  }
 }
@@ -0,0 +1,120 @@
+use crate::Date;
+use crate::Weekday;
+
+/// The number of days between January 6, 1980 (the GPS epoch) and the Unix epoch.
+const GPS_EPOCH: i32 = 3_657;
+
+/// The number of weeks after which a 10-bit GPS week number rolls over.
+const ROLLOVER_WEEKS: i32 = 1_024;
+
+impl Date {
+  /// The date for the given GPS week number and day of the week.
+  ///
+  /// GPS weeks begin on Sunday, and week 0 begins on January 6, 1980. The week number is the full
+  /// week count since that date; use [`Date::from_gps_week_rollover`] for the 10-bit week numbers
+  /// broadcast by GPS satellites, which roll over every 1,024 weeks.
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::date;
+  /// use date::Date;
+  /// use date::Weekday;
+  ///
+  /// assert_eq!(Date::from_gps_week(0, Weekday::Sunday), date! { 1980-01-06 });
+  /// assert_eq!(Date::from_gps_week(2311, Weekday::Sunday), date! { 2024-04-21 });
+  /// ```
+  pub const fn from_gps_week(week: u16, day: Weekday) -> Self {
+    Self(GPS_EPOCH + week as i32 * 7 + day.number_from_sunday() as i32 - 1)
+  }
+
+  /// The date for the given 10-bit GPS week number and day of the week, choosing the rollover
+  /// period that places the date closest to `near`.
+  ///
+  /// GPS satellites broadcast the week number modulo 1,024, so a week number alone is ambiguous;
+  /// a receiver typically resolves it using a date that is known to be approximately correct,
+  /// such as the current date or the date of a firmware build. Only the low ten bits of `week`
+  /// are used.
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::date;
+  /// use date::Date;
+  /// use date::Weekday;
+  ///
+  /// let date = Date::from_gps_week_rollover(263, Weekday::Sunday, date! { 2024-01-01 });
+  /// assert_eq!(date, date! { 2024-04-21 });
+  /// let date = Date::from_gps_week_rollover(263, Weekday::Sunday, date! { 1990-01-01 });
+  /// assert_eq!(date, date! { 1985-01-20 });
+  /// ```
+  pub const fn from_gps_week_rollover(week: u16, day: Weekday, near: Date) -> Self {
+    let period = ROLLOVER_WEEKS * 7;
+    let offset = (week as i32 % ROLLOVER_WEEKS) * 7 + day.number_from_sunday() as i32 - 1;
+    let base = GPS_EPOCH + offset;
+    let rollovers = (near.0 - base + period / 2).div_euclid(period);
+    Self(base + rollovers * period)
+  }
+
+  /// The GPS week number and day of the week for this date, or `None` if the date is before
+  /// January 6, 1980 (or more than 65,535 weeks after it).
+  ///
+  /// The week number is the full week count since the GPS epoch; the 10-bit week number broadcast
+  /// by GPS satellites is this value modulo 1,024.
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::date;
+  /// use date::Weekday;
+  ///
+  /// assert_eq!(date! { 2024-04-21 }.to_gps_week(), Some((2311, Weekday::Sunday)));
+  /// assert_eq!(date! { 1980-01-05 }.to_gps_week(), None);
+  /// ```
+  pub const fn to_gps_week(&self) -> Option<(u16, Weekday)> {
+    let days = self.0 - GPS_EPOCH;
+    match days >= 0 && days / 7 <= u16::MAX as i32 {
+      true => Some(((days / 7) as u16, self.weekday())),
+      false => None,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use assert2::check;
+
+  use super::*;
+
+  #[test]
+  fn test_gps_week() {
+    check!(Date::from_gps_week(0, Weekday::Sunday) == date! { 1980-01-06 });
+    check!(Date::from_gps_week(0, Weekday::Saturday) == date! { 1980-01-12 });
+    check!(Date::from_gps_week(1024, Weekday::Sunday) == date! { 1999-08-22 });
+    check!(Date::from_gps_week(2048, Weekday::Sunday) == date! { 2019-04-07 });
+    check!(date! { 1980-01-06 }.to_gps_week() == Some((0, Weekday::Sunday)));
+    check!(date! { 2019-04-06 }.to_gps_week() == Some((2047, Weekday::Saturday)));
+    check!(date! { 1980-01-05 }.to_gps_week() == None);
+    check!(Date::MAX.to_gps_week() == None);
+    for date in date! { 1999-08-01 }.iter_through(date! { 1999-09-30 }) {
+      let (week, day) = date.to_gps_week().unwrap();
+      check!(Date::from_gps_week(week, day) == date);
+    }
+  }
+
+  #[test]
+  fn test_gps_week_rollover() {
+    let near = date! { 2024-01-01 };
+    check!(Date::from_gps_week_rollover(263, Weekday::Sunday, near) == date! { 2024-04-21 });
+    check!(Date::from_gps_week_rollover(2311, Weekday::Sunday, near) == date! { 2024-04-21 });
+    check!(Date::from_gps_week_rollover(0, Weekday::Sunday, near) == date! { 2019-04-07 });
+    check!(Date::from_gps_week_rollover(1023, Weekday::Saturday, near) == date! { 2019-04-06 });
+    let near = date! { 1970-01-01 };
+    check!(Date::from_gps_week_rollover(1000, Weekday::Sunday, near) == date! { 1979-07-22 });
+    for date in date! { 2019-03-01 }.iter_through(date! { 2019-05-31 }) {
+      let (week, day) = date.to_gps_week().unwrap();
+      check!(Date::from_gps_week_rollover(week % 1024, day, date) == date);
+      check!(Date::from_gps_week_rollover(week % 1024, day, date! { 2025-01-01 }) == date);
+    }
+  }
+}
@@ -0,0 +1,164 @@
+//! Grouping of dates by the calendar period that contains them.
+
+use alloc::vec::Vec;
+use core::iter::Peekable;
+
+use crate::Date;
+use crate::DateUnit;
+use crate::Quarter;
+use crate::Weekday;
+use crate::YearMonth;
+
+/// Adapters that group an iterator of dates by calendar period.
+///
+/// Each adapter yields the period along with the dates in it. Consecutive dates that fall in the
+/// same period are grouped together, so the dates should be sorted (as they are when iterating
+/// over a [`DateRange`](crate::DateRange) or [`DateSet`](crate::DateSet)); a period that appears
+/// more than once in unsorted input is yielded more than once.
+///
+/// This trait is implemented for every iterator of dates.
+///
+/// ## Examples
+///
+/// ```
+/// use date::date;
+/// use date::DateRange;
+/// use date::GroupByPeriod;
+/// use date::YearMonth;
+///
+/// let range = DateRange::new(date! { 2024-01-30 }, date! { 2024-02-02 });
+/// let months: Vec<_> = range.into_iter().group_by_month().collect();
+/// assert_eq!(months, [
+///   (YearMonth::new(2024, 1), vec![date! { 2024-01-30 }, date! { 2024-01-31 }]),
+///   (YearMonth::new(2024, 2), vec![date! { 2024-02-01 }, date! { 2024-02-02 }]),
+/// ]);
+/// ```
+pub trait GroupByPeriod: Iterator<Item = Date> + Sized {
+  /// Group the dates by the calendar month that contains them.
+  fn group_by_month(self) -> PeriodGroups<Self, YearMonth> {
+    PeriodGroups::new(self, DateUnit::Month, |d, _| d.year_month())
+  }
+
+  /// Group the dates by the calendar quarter that contains them.
+  fn group_by_quarter(self) -> PeriodGroups<Self, Quarter> {
+    PeriodGroups::new(self, DateUnit::Quarter, |d, _| d.quarter())
+  }
+
+  /// Group the dates by the week that contains them, where weeks begin on the given weekday.
+  ///
+  /// Each week is identified by its first day.
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::date;
+  /// use date::GroupByPeriod;
+  /// use date::Weekday;
+  ///
+  /// let dates = date! { 2024-04-19 }.iter_through(date! { 2024-04-23 });
+  /// let mut weeks = dates.group_by_week(Weekday::Monday);
+  /// assert_eq!(weeks.next().unwrap().0, date! { 2024-04-15 });
+  /// assert_eq!(
+  ///   weeks.next().unwrap(),
+  ///   (date! { 2024-04-22 }, vec![date! { 2024-04-22 }, date! { 2024-04-23 }])
+  /// );
+  /// assert_eq!(weeks.next(), None);
+  /// ```
+  fn group_by_week(self, first_day: Weekday) -> PeriodGroups<Self, Date> {
+    PeriodGroups::new(self, DateUnit::Week(first_day), |d, unit| d.start_of(unit))
+  }
+}
+
+impl<I: Iterator<Item = Date>> GroupByPeriod for I {}
+
+/// An iterator over dates grouped by calendar period.
+///
+/// This is returned by the methods on [`GroupByPeriod`].
+pub struct PeriodGroups<I: Iterator<Item = Date>, K> {
+  dates: Peekable<I>,
+  unit: DateUnit,
+  key: fn(Date, DateUnit) -> K,
+}
+
+impl<I: Iterator<Item = Date>, K> PeriodGroups<I, K> {
+  fn new(dates: I, unit: DateUnit, key: fn(Date, DateUnit) -> K) -> Self {
+    Self { dates: dates.peekable(), unit, key }
+  }
+}
+
+impl<I: Iterator<Item = Date>, K: PartialEq> Iterator for PeriodGroups<I, K> {
+  type Item = (K, Vec<Date>);
+
+  fn next(&mut self) -> Option<Self::Item> {
+    let first = self.dates.next()?;
+    let period = (self.key)(first, self.unit);
+    let mut dates = Vec::from([first]);
+    while let Some(date) = self.dates.next_if(|d| (self.key)(*d, self.unit) == period) {
+      dates.push(date);
+    }
+    Some((period, dates))
+  }
+
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    let (lower, upper) = self.dates.size_hint();
+    (lower.min(1), upper)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use assert2::check;
+
+  use super::*;
+  use crate::DateRange;
+
+  #[test]
+  fn test_group_by_month() {
+    let range = DateRange::new(date! { 2024-01-15 }, date! { 2024-03-10 });
+    let months: Vec<(YearMonth, usize)> =
+      range.into_iter().group_by_month().map(|(m, dates)| (m, dates.len())).collect();
+    check!(
+      months
+        == [
+          (YearMonth::new(2024, 1), 17),
+          (YearMonth::new(2024, 2), 29),
+          (YearMonth::new(2024, 3), 10),
+        ]
+    );
+    check!(core::iter::empty().group_by_month().next() == None);
+  }
+
+  #[test]
+  fn test_group_by_quarter() {
+    let dates = [date! { 2023-12-31 }, date! { 2024-01-01 }, date! { 2024-03-31 }, date! {
+      2024-04-01
+    }];
+    let quarters: Vec<_> = dates.into_iter().group_by_quarter().collect();
+    check!(
+      quarters
+        == [
+          (Quarter::new(2023, 4), vec![date! { 2023-12-31 }]),
+          (Quarter::new(2024, 1), vec![date! { 2024-01-01 }, date! { 2024-03-31 }]),
+          (Quarter::new(2024, 2), vec![date! { 2024-04-01 }]),
+        ]
+    );
+  }
+
+  #[test]
+  fn test_group_by_week() {
+    let dates = date! { 2024-08-01 }.iter_through(date! { 2024-08-31 });
+    let weeks: Vec<_> = dates.group_by_week(Weekday::Sunday).collect();
+    check!(weeks.len() == 5);
+    let first_week = date! { 2024-08-01 }.iter_through(date! { 2024-08-03 }).collect();
+    check!(weeks[0] == (date! { 2024-07-28 }, first_week));
+    for (start, dates) in &weeks[1..] {
+      check!(start.weekday() == Weekday::Sunday);
+      check!(dates[0] == *start);
+    }
+    check!(weeks.iter().map(|(_, dates)| dates.len()).sum::<usize>() == 31);
+
+    // Unsorted input yields a period once for each run of dates in it.
+    let dates = [date! { 2024-08-05 }, date! { 2024-08-12 }, date! { 2024-08-06 }];
+    check!(dates.into_iter().group_by_week(Weekday::Monday).count() == 3);
+  }
+}
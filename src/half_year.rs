@@ -0,0 +1,203 @@
+use core::fmt;
+use core::str::FromStr;
+
+use crate::Date;
+
+/// A half of a calendar year (H1 or H2).
+///
+/// The first half runs from January 1 through June 30, and the second half from July 1 through
+/// December 31. Half-years are written as `2024-H1`.
+///
+/// ## Examples
+///
+/// ```
+/// use date::date;
+/// use date::HalfYear;
+///
+/// let half = date! { 2024-08-15 }.half_year();
+/// assert_eq!(half, HalfYear::new(2024, 2));
+/// assert_eq!(half.to_string(), "2024-H2");
+/// assert_eq!(half.start(), date! { 2024-07-01 });
+/// assert_eq!(half.end(), date! { 2024-12-31 });
+/// assert_eq!("2024-H2".parse::<HalfYear>(), Ok(half));
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct HalfYear {
+  year: i16,
+  number: u8,
+}
+
+impl HalfYear {
+  /// Construct a new half-year from the provided year and half number (1 or 2).
+  ///
+  /// ## Panic
+  ///
+  /// This function panics if the half number is not 1 or 2.
+  pub const fn new(year: i16, number: u8) -> Self {
+    assert!(number == 1 || number == 2, "Half-year out-of-bounds");
+    Self { year, number }
+  }
+
+  /// Construct a new half-year, or return `None` if the half number is not 1 or 2.
+  ///
+  /// This is the non-panicking counterpart to [`HalfYear::new`].
+  pub const fn checked_new(year: i16, number: u8) -> Option<Self> {
+    match number == 1 || number == 2 {
+      true => Some(Self { year, number }),
+      false => None,
+    }
+  }
+
+  /// The year.
+  #[inline]
+  pub const fn year(&self) -> i16 {
+    self.year
+  }
+
+  /// The half number, either 1 or 2.
+  #[inline]
+  pub const fn number(&self) -> u8 {
+    self.number
+  }
+
+  /// The first day of the half-year.
+  pub const fn start(&self) -> Date {
+    match self.number {
+      1 => Date::new(self.year, 1, 1),
+      _ => Date::new(self.year, 7, 1),
+    }
+  }
+
+  /// The last day of the half-year.
+  pub const fn end(&self) -> Date {
+    match self.number {
+      1 => Date::new(self.year, 6, 30),
+      _ => Date::new(self.year, 12, 31),
+    }
+  }
+
+  /// Whether the given date falls within the half-year.
+  pub const fn contains(&self, date: Date) -> bool {
+    date.0 >= self.start().0 && date.0 <= self.end().0
+  }
+}
+
+impl Date {
+  /// The half-year containing this date.
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::date;
+  ///
+  /// assert_eq!(date! { 2024-06-30 }.half_year().number(), 1);
+  /// assert_eq!(date! { 2024-07-01 }.half_year().number(), 2);
+  /// ```
+  pub const fn half_year(&self) -> HalfYear {
+    HalfYear { year: self.year(), number: (self.month() - 1) / 6 + 1 }
+  }
+}
+
+impl fmt::Display for HalfYear {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self.year < 0 {
+      true => write!(f, "-{:04}-H{}", self.year.unsigned_abs(), self.number),
+      false => write!(f, "{:04}-H{}", self.year, self.number),
+    }
+  }
+}
+
+impl FromStr for HalfYear {
+  type Err = ParseHalfYearError;
+
+  /// Parse a half-year, either in the `YYYY-Hn` form or the `Hn YYYY` form.
+  ///
+  /// The `H` may be written in either case.
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let (year, half) = match s.get(..1) {
+      Some("H" | "h") => s.split_once(' ').map(|(h, y)| (y, h)),
+      _ => s.rsplit_once('-'),
+    }
+    .ok_or(ParseHalfYearError)?;
+    let number = match half {
+      "H1" | "h1" => 1,
+      "H2" | "h2" => 2,
+      _ => return Err(ParseHalfYearError),
+    };
+    if year.trim_start_matches('-').len() < 4 || year.starts_with('+') {
+      return Err(ParseHalfYearError);
+    }
+    let year: i16 = year.parse().map_err(|_| ParseHalfYearError)?;
+    Ok(Self { year, number })
+  }
+}
+
+/// An error returned when parsing a [`HalfYear`] from a string fails.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ParseHalfYearError;
+
+impl fmt::Display for ParseHalfYearError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str("Invalid half-year; expected `YYYY-Hn` or `Hn YYYY`")
+  }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseHalfYearError {}
+
+#[cfg(test)]
+mod tests {
+  use assert2::check;
+
+  use super::*;
+
+  #[test]
+  fn test_half_year() {
+    check!(date! { 2024-01-01 }.half_year() == HalfYear::new(2024, 1));
+    check!(date! { 2024-06-30 }.half_year() == HalfYear::new(2024, 1));
+    check!(date! { 2024-07-01 }.half_year() == HalfYear::new(2024, 2));
+    check!(date! { 2024-12-31 }.half_year() == HalfYear::new(2024, 2));
+  }
+
+  #[test]
+  fn test_bounds() {
+    let h1 = HalfYear::new(2024, 1);
+    check!(h1.start() == date! { 2024-01-01 });
+    check!(h1.end() == date! { 2024-06-30 });
+    check!(h1.contains(date! { 2024-03-15 }));
+    check!(!h1.contains(date! { 2024-07-01 }));
+    check!(!h1.contains(date! { 2023-12-31 }));
+    let h2 = HalfYear::new(2024, 2);
+    check!(h2.start() == date! { 2024-07-01 });
+    check!(h2.end() == date! { 2024-12-31 });
+    check!(h2.contains(date! { 2024-12-31 }));
+    check!(h1 < h2);
+  }
+
+  #[test]
+  fn test_display_parse() {
+    check!(HalfYear::new(2024, 1).to_string() == "2024-H1");
+    check!(HalfYear::new(-44, 2).to_string() == "-0044-H2");
+    check!("2024-H1".parse::<HalfYear>() == Ok(HalfYear::new(2024, 1)));
+    check!("2024-h2".parse::<HalfYear>() == Ok(HalfYear::new(2024, 2)));
+    check!("H2 2024".parse::<HalfYear>() == Ok(HalfYear::new(2024, 2)));
+    check!("-0044-H2".parse::<HalfYear>() == Ok(HalfYear::new(-44, 2)));
+    check!("2024-H3".parse::<HalfYear>() == Err(ParseHalfYearError));
+    check!("24-H1".parse::<HalfYear>().is_err());
+    check!("2024-Q1".parse::<HalfYear>().is_err());
+    check!("".parse::<HalfYear>().is_err());
+  }
+
+  #[test]
+  fn test_checked_new() {
+    check!(HalfYear::checked_new(2024, 2) == Some(HalfYear::new(2024, 2)));
+    check!(HalfYear::checked_new(2024, 0) == None);
+    check!(HalfYear::checked_new(2024, 3) == None);
+  }
+
+  #[test]
+  #[should_panic]
+  fn test_invalid_half() {
+    HalfYear::new(2024, 3);
+  }
+}
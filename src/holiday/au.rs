@@ -0,0 +1,100 @@
+//! Australian national public holidays.
+
+use alloc::vec::Vec;
+
+use super::holidays_by_year;
+use super::substitute;
+use super::HolidayCalendar;
+use crate::interval::DateInterval;
+use crate::Date;
+use crate::NthWeekdayRule;
+use crate::Weekday;
+
+/// The Australian national public holiday calendar.
+///
+/// This covers the holidays observed across Australia: New Year's Day, Australia Day, Good Friday,
+/// Easter Monday, Anzac Day, Christmas Day, and Boxing Day, plus the Sovereign's Birthday on the
+/// second Monday in June (as observed in every state except Western Australia and Queensland).
+///
+/// New Year's Day, Australia Day, Christmas Day, and Boxing Day are replaced by the following
+/// weekday that is not already a holiday when they fall on a weekend. Anzac Day is not
+/// substituted. Other state and territory holidays are not included.
+///
+/// ## Examples
+///
+/// ```
+/// use date::date;
+/// use date::holiday::au::AustraliaNational;
+/// use date::holiday::HolidayCalendar;
+///
+/// assert!(AustraliaNational.is_holiday(date! { 2024-01-26 })); // Australia Day
+/// assert!(AustraliaNational.is_holiday(date! { 2024-06-10 })); // King's Birthday
+/// ```
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct AustraliaNational;
+
+impl AustraliaNational {
+  /// The national public holidays in the given year, in chronological order.
+  pub fn holidays(year: i16) -> Vec<Date> {
+    let easter = Date::easter(year);
+    let mut holidays = substitute([
+      Date::new(year, 1, 1),
+      Date::new(year, 1, 26),
+      Date::new(year, 12, 25),
+      Date::new(year, 12, 26),
+    ]);
+    holidays.extend([
+      easter - DateInterval::new(2),
+      easter + DateInterval::new(1),
+      Date::new(year, 4, 25),
+    ]);
+    holidays.extend(SOVEREIGNS_BIRTHDAY.occurrence_in(year));
+    holidays.sort();
+    holidays.dedup();
+    holidays
+  }
+}
+
+const SOVEREIGNS_BIRTHDAY: NthWeekdayRule = NthWeekdayRule::new(2, Weekday::Monday, 6);
+
+impl HolidayCalendar for AustraliaNational {
+  fn is_holiday(&self, date: Date) -> bool {
+    Self::holidays(date.year()).contains(&date)
+  }
+
+  fn holidays_in(&self, start: Date, end: Date) -> Vec<Date> {
+    holidays_by_year(start, end, Self::holidays)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use assert2::check;
+
+  use super::*;
+
+  #[test]
+  fn test_holidays() {
+    check!(
+      AustraliaNational::holidays(2021)
+        == vec![
+          date! { 2021-01-01 },
+          date! { 2021-01-26 },
+          date! { 2021-04-02 },
+          date! { 2021-04-05 },
+          date! { 2021-04-25 },
+          date! { 2021-06-14 },
+          date! { 2021-12-27 },
+          date! { 2021-12-28 },
+        ]
+    );
+  }
+
+  #[test]
+  fn test_is_holiday() {
+    check!(AustraliaNational.is_holiday(date! { 2020-01-27 })); // Australia Day (substitute)
+    check!(!AustraliaNational.is_holiday(date! { 2020-01-26 }));
+    check!(AustraliaNational.is_holiday(date! { 2021-04-25 })); // Anzac Day (Sunday)
+    check!(!AustraliaNational.is_holiday(date! { 2021-04-26 }));
+  }
+}
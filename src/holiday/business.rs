@@ -0,0 +1,339 @@
+use super::HolidayCalendar;
+use crate::interval::DateInterval;
+use crate::Date;
+use crate::Tenor;
+use crate::Workweek;
+
+/// A calendar of business days: the working days of a [`Workweek`] that are not holidays.
+///
+/// The holidays may be any [`HolidayCalendar`], including a tuple of calendars (in which case a
+/// date is a holiday if it is a holiday in any of them).
+///
+/// ## Examples
+///
+/// ```
+/// use date::date;
+/// use date::holiday::BusinessCalendar;
+/// use date::holiday::HolidayTable;
+/// use date::Workweek;
+///
+/// static CLOSURES: HolidayTable = HolidayTable::new(&[date! { 2024-12-25 }, date! { 2024-12-26 }]);
+///
+/// let calendar = BusinessCalendar::new(Workweek::MONDAY_TO_FRIDAY, CLOSURES);
+/// assert!(calendar.is_business_day(date! { 2024-12-24 }));
+/// assert!(!calendar.is_business_day(date! { 2024-12-25 }));
+/// assert!(!calendar.is_business_day(date! { 2024-12-28 }));
+/// assert_eq!(calendar.next_business_day(date! { 2024-12-24 }), date! { 2024-12-27 });
+/// assert_eq!(calendar.prev_business_day(date! { 2024-12-30 }), date! { 2024-12-27 });
+/// ```
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct BusinessCalendar<H: HolidayCalendar> {
+  workweek: Workweek,
+  holidays: H,
+}
+
+impl<H: HolidayCalendar> BusinessCalendar<H> {
+  /// A business calendar with the given workweek and holidays.
+  pub const fn new(workweek: Workweek, holidays: H) -> Self {
+    Self { workweek, holidays }
+  }
+
+  /// The days of the week that are working days.
+  pub const fn workweek(&self) -> Workweek {
+    self.workweek
+  }
+
+  /// The holidays of this calendar.
+  pub const fn holidays(&self) -> &H {
+    &self.holidays
+  }
+
+  /// Whether the given date is a business day: a working day that is not a holiday.
+  pub fn is_business_day(&self, date: Date) -> bool {
+    self.workweek.is_workday(date) && !self.holidays.is_holiday(date)
+  }
+
+  /// The first business day after the given date.
+  pub fn next_business_day(&self, date: Date) -> Date {
+    let mut answer = date + DateInterval::new(1);
+    while !self.is_business_day(answer) {
+      answer += DateInterval::new(1);
+    }
+    answer
+  }
+
+  /// The last business day before the given date.
+  pub fn prev_business_day(&self, date: Date) -> Date {
+    let mut answer = date - DateInterval::new(1);
+    while !self.is_business_day(answer) {
+      answer -= DateInterval::new(1);
+    }
+    answer
+  }
+
+  /// The given date if it is a business day, or else the first business day after it.
+  pub fn next_business_day_or_same(&self, date: Date) -> Date {
+    match self.is_business_day(date) {
+      true => date,
+      false => self.next_business_day(date),
+    }
+  }
+
+  /// The given date if it is a business day, or else the last business day before it.
+  pub fn prev_business_day_or_same(&self, date: Date) -> Date {
+    match self.is_business_day(date) {
+      true => date,
+      false => self.prev_business_day(date),
+    }
+  }
+
+  /// The given date, rolled onto a business day according to the given convention.
+  pub fn roll(&self, date: Date, convention: RollConvention) -> Date {
+    match convention {
+      RollConvention::Unadjusted => date,
+      RollConvention::Following => self.next_business_day_or_same(date),
+      RollConvention::Preceding => self.prev_business_day_or_same(date),
+      RollConvention::ModifiedFollowing => match self.next_business_day_or_same(date) {
+        next if next.month() == date.month() => next,
+        _ => self.prev_business_day_or_same(date),
+      },
+      RollConvention::ModifiedPreceding => match self.prev_business_day_or_same(date) {
+        prev if prev.month() == date.month() => prev,
+        _ => self.next_business_day_or_same(date),
+      },
+    }
+  }
+
+  /// The date that is the given tenor after the given date, rolled onto a business day according
+  /// to the given convention.
+  ///
+  /// An overnight tenor is one business day, regardless of the convention.
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::date;
+  /// use date::holiday::BusinessCalendar;
+  /// use date::holiday::HolidayTable;
+  /// use date::holiday::RollConvention;
+  /// use date::Tenor;
+  /// use date::Workweek;
+  ///
+  /// static CLOSURES: HolidayTable =
+  ///   HolidayTable::new(&[date! { 2024-12-30 }, date! { 2024-12-31 }, date! { 2025-01-01 }]);
+  ///
+  /// let calendar = BusinessCalendar::new(Workweek::MONDAY_TO_FRIDAY, CLOSURES);
+  /// let (date, tenor) = (date! { 2024-09-30 }, Tenor::Months(3));
+  /// assert_eq!(calendar.add_tenor(date, tenor, RollConvention::Following), date! { 2025-01-02 });
+  /// assert_eq!(
+  ///   calendar.add_tenor(date, tenor, RollConvention::ModifiedFollowing),
+  ///   date! { 2024-12-27 },
+  /// );
+  /// ```
+  pub fn add_tenor(&self, date: Date, tenor: Tenor, convention: RollConvention) -> Date {
+    match tenor {
+      Tenor::Overnight => self.add_business_days(date, 1),
+      _ => self.roll(date + tenor, convention),
+    }
+  }
+
+  /// The date that is `n` business days after the given date (or before, if `n` is negative).
+  ///
+  /// If the given date is not itself a business day, counting begins from the adjacent business
+  /// day in the opposite direction, as with [`Workweek::add_workdays`].
+  ///
+  /// Rather than stepping through the calendar one day at a time, this skips ahead by working
+  /// days and then makes up for any holidays that were skipped over.
+  pub fn add_business_days(&self, date: Date, n: i32) -> Date {
+    let mut cursor = date;
+    let mut remaining = n;
+    while remaining != 0 {
+      let target = self.workweek.add_workdays(cursor, remaining);
+      let holidays = match remaining > 0 {
+        true => self.workday_holidays(cursor + DateInterval::new(1), target),
+        false => self.workday_holidays(target, cursor - DateInterval::new(1)),
+      };
+      (cursor, remaining) = (target, holidays * remaining.signum());
+    }
+    cursor
+  }
+
+  /// The number of business days after `start`, up to and including `end`.
+  ///
+  /// If `end` is before `start`, the answer is negative: the number of business days after `end`
+  /// up to and including `start`, negated. In either case, adding the answer to `start` with
+  /// [`add_business_days`](Self::add_business_days) yields `end` if `end` is a business day.
+  pub fn business_days_between(&self, start: Date, end: Date) -> i32 {
+    if end < start {
+      return -self.business_days_between(end, start);
+    }
+    if start == end {
+      return 0;
+    }
+    let weeks = (end.0 - start.0) / 7;
+    let remainder = Date(start.0 + weeks * 7 + 1).iter_through(end);
+    let workdays = weeks * self.workweek.workdays_per_week() as i32
+      + remainder.filter(|d| self.workweek.is_workday(*d)).count() as i32;
+    workdays - self.workday_holidays(start + DateInterval::new(1), end)
+  }
+
+  /// The number of holidays from `start` through `end` (inclusive) that fall on working days.
+  fn workday_holidays(&self, start: Date, end: Date) -> i32 {
+    let holidays = self.holidays.holidays_in(start, end);
+    holidays.into_iter().filter(|d| self.workweek.is_workday(*d)).count() as i32
+  }
+}
+
+impl Date {
+  /// The date that is `n` business days after this date (or before, if `n` is negative) in the
+  /// given calendar.
+  ///
+  /// See [`BusinessCalendar::add_business_days`] for details.
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::date;
+  /// use date::holiday::BusinessCalendar;
+  /// use date::holiday::HolidayTable;
+  /// use date::Workweek;
+  ///
+  /// static CHRISTMAS: HolidayTable =
+  ///   HolidayTable::new(&[date! { 2024-12-25 }, date! { 2024-12-26 }]);
+  ///
+  /// let calendar = BusinessCalendar::new(Workweek::MONDAY_TO_FRIDAY, CHRISTMAS);
+  /// assert_eq!(date! { 2024-12-23 }.add_business_days(3, &calendar), date! { 2024-12-30 });
+  /// assert_eq!(date! { 2024-12-27 }.add_business_days(-1, &calendar), date! { 2024-12-24 });
+  /// assert_eq!(calendar.business_days_between(date! { 2024-12-20 }, date! { 2025-01-03 }), 8);
+  /// ```
+  pub fn add_business_days<H: HolidayCalendar>(
+    &self, n: i32, calendar: &BusinessCalendar<H>,
+  ) -> Date {
+    calendar.add_business_days(*self, n)
+  }
+}
+
+/// A convention for rolling a date that is not a business day onto one.
+///
+/// These are the business day conventions defined by ISDA, and are used with
+/// [`BusinessCalendar::roll`] and [`BusinessCalendar::add_tenor`].
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub enum RollConvention {
+  /// The date is not adjusted.
+  #[default]
+  Unadjusted,
+  /// The date is rolled forward to the next business day.
+  Following,
+  /// The date is rolled forward to the next business day, unless that falls in the next month,
+  /// in which case it is rolled back to the previous business day instead.
+  ModifiedFollowing,
+  /// The date is rolled back to the previous business day.
+  Preceding,
+  /// The date is rolled back to the previous business day, unless that falls in the previous
+  /// month, in which case it is rolled forward to the next business day instead.
+  ModifiedPreceding,
+}
+
+impl<H: HolidayCalendar> HolidayCalendar for BusinessCalendar<H> {
+  fn is_holiday(&self, date: Date) -> bool {
+    self.holidays.is_holiday(date)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use assert2::check;
+
+  use super::*;
+  use crate::holiday::HolidayTable;
+
+  static NEW_YEAR: HolidayTable = HolidayTable::new(&[date! { 2024-01-01 }]);
+  static EID: HolidayTable = HolidayTable::new(&[date! { 2024-04-10 }, date! { 2024-04-11 }]);
+
+  #[test]
+  fn test_business_day() {
+    let calendar = BusinessCalendar::new(Workweek::MONDAY_TO_FRIDAY, NEW_YEAR);
+    check!(!calendar.is_business_day(date! { 2024-01-01 }));
+    check!(calendar.is_business_day(date! { 2024-01-02 }));
+    check!(!calendar.is_business_day(date! { 2023-12-31 }));
+    check!(calendar.next_business_day(date! { 2023-12-29 }) == date! { 2024-01-02 });
+    check!(calendar.prev_business_day(date! { 2024-01-02 }) == date! { 2023-12-29 });
+    check!(calendar.next_business_day_or_same(date! { 2024-01-02 }) == date! { 2024-01-02 });
+    check!(calendar.next_business_day_or_same(date! { 2023-12-30 }) == date! { 2024-01-02 });
+    check!(calendar.prev_business_day_or_same(date! { 2024-01-01 }) == date! { 2023-12-29 });
+    check!(calendar.holidays_in(date! { 2023-12-01 }, date! { 2024-01-31 }).len() == 1);
+  }
+
+  #[test]
+  fn test_compose() {
+    let calendar = BusinessCalendar::new(Workweek::SUNDAY_TO_THURSDAY, (NEW_YEAR, EID));
+    check!(calendar.workweek() == Workweek::SUNDAY_TO_THURSDAY);
+    check!(!calendar.is_business_day(date! { 2024-01-01 }));
+    check!(!calendar.is_business_day(date! { 2024-04-12 }));
+    check!(calendar.next_business_day(date! { 2024-04-09 }) == date! { 2024-04-14 });
+    check!(calendar.prev_business_day(date! { 2024-04-14 }) == date! { 2024-04-09 });
+  }
+
+  #[test]
+  fn test_add_business_days() {
+    let calendar = BusinessCalendar::new(Workweek::MONDAY_TO_FRIDAY, (NEW_YEAR, EID));
+    check!(calendar.add_business_days(date! { 2023-12-29 }, 1) == date! { 2024-01-02 });
+    check!(calendar.add_business_days(date! { 2024-01-02 }, -1) == date! { 2023-12-29 });
+    check!(calendar.add_business_days(date! { 2024-01-01 }, 1) == date! { 2024-01-02 });
+    check!(calendar.add_business_days(date! { 2024-01-01 }, -1) == date! { 2023-12-29 });
+    check!(calendar.add_business_days(date! { 2024-01-01 }, 0) == date! { 2024-01-01 });
+    check!(calendar.add_business_days(date! { 2024-04-09 }, 1) == date! { 2024-04-12 });
+    check!(calendar.add_business_days(date! { 2023-12-29 }, 72) == date! { 2024-04-12 });
+    check!(calendar.add_business_days(date! { 2024-04-12 }, -72) == date! { 2023-12-29 });
+    check!(date! { 2024-04-13 }.add_business_days(-2, &calendar) == date! { 2024-04-09 });
+  }
+
+  #[test]
+  fn test_roll() {
+    let calendar = BusinessCalendar::new(Workweek::MONDAY_TO_FRIDAY, NEW_YEAR);
+    let saturday = date! { 2023-12-30 };
+    check!(calendar.roll(saturday, RollConvention::Unadjusted) == saturday);
+    check!(calendar.roll(saturday, RollConvention::Following) == date! { 2024-01-02 });
+    check!(calendar.roll(saturday, RollConvention::ModifiedFollowing) == date! { 2023-12-29 });
+    check!(calendar.roll(saturday, RollConvention::Preceding) == date! { 2023-12-29 });
+    check!(calendar.roll(saturday, RollConvention::ModifiedPreceding) == date! { 2023-12-29 });
+    let sunday = date! { 2023-10-01 };
+    check!(calendar.roll(sunday, RollConvention::ModifiedFollowing) == date! { 2023-10-02 });
+    check!(calendar.roll(sunday, RollConvention::ModifiedPreceding) == date! { 2023-10-02 });
+    check!(calendar.roll(sunday, RollConvention::Preceding) == date! { 2023-09-29 });
+    let friday = date! { 2023-12-29 };
+    check!(calendar.roll(friday, RollConvention::Following) == friday);
+  }
+
+  #[test]
+  fn test_add_tenor() {
+    let calendar = BusinessCalendar::new(Workweek::MONDAY_TO_FRIDAY, NEW_YEAR);
+    let friday = date! { 2023-12-29 };
+    let unadjusted = |tenor| calendar.add_tenor(friday, tenor, RollConvention::Unadjusted);
+    check!(unadjusted(Tenor::Overnight) == date! { 2024-01-02 });
+    check!(unadjusted(Tenor::Days(1)) == date! { 2023-12-30 });
+    let preceding = calendar.add_tenor(friday, Tenor::Weeks(1), RollConvention::Preceding);
+    check!(preceding == date! { 2024-01-05 });
+    let end = date! { 2023-11-30 };
+    let following = calendar.add_tenor(end, Tenor::Months(1), RollConvention::Following);
+    check!(following == date! { 2024-01-02 });
+    let modified = calendar.add_tenor(end, Tenor::Months(1), RollConvention::ModifiedFollowing);
+    check!(modified == date! { 2023-12-29 });
+  }
+
+  #[test]
+  fn test_business_days_between() {
+    let calendar = BusinessCalendar::new(Workweek::SUNDAY_TO_THURSDAY, (NEW_YEAR, EID));
+    let start = date! { 2023-12-20 };
+    for end in start.iter_through(date! { 2024-05-01 }) {
+      let expected = start.iter_through(end).skip(1).filter(|d| calendar.is_business_day(*d));
+      let between = calendar.business_days_between(start, end);
+      check!(between == expected.count() as i32, "{end}");
+      check!(calendar.business_days_between(end, start) == -between);
+      if calendar.is_business_day(end) {
+        check!(calendar.add_business_days(start, between) == end);
+        check!(calendar.add_business_days(end, -between) == start);
+      }
+    }
+  }
+}
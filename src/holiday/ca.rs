@@ -0,0 +1,109 @@
+//! Canadian federal statutory holidays.
+
+use alloc::vec::Vec;
+
+use super::holidays_by_year;
+use super::substitute;
+use super::HolidayCalendar;
+use crate::interval::DateInterval;
+use crate::Date;
+use crate::NthWeekdayRule;
+use crate::Weekday;
+
+/// The Canadian federal statutory holiday calendar.
+///
+/// This covers the holidays observed by federally regulated employers and the federal public
+/// service: New Year's Day, Good Friday, Easter Monday, Victoria Day (the Monday preceding May
+/// 25), Canada Day, Labour Day, the National Day for Truth and Reconciliation (since 2021),
+/// Thanksgiving (second Monday in October), Remembrance Day, Christmas Day, and Boxing Day.
+///
+/// Fixed-date holidays that fall on a weekend are replaced by the following weekday that is not
+/// already a holiday. Provincial holidays (such as Family Day or the Civic Holiday) are not
+/// included.
+///
+/// ## Examples
+///
+/// ```
+/// use date::date;
+/// use date::holiday::ca::CanadaFederal;
+/// use date::holiday::HolidayCalendar;
+///
+/// assert!(CanadaFederal.is_holiday(date! { 2024-05-20 })); // Victoria Day
+/// assert!(CanadaFederal.is_holiday(date! { 2024-10-14 })); // Thanksgiving
+/// ```
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct CanadaFederal;
+
+impl CanadaFederal {
+  /// The federal statutory holidays in the given year, in chronological order.
+  pub fn holidays(year: i16) -> Vec<Date> {
+    let easter = Date::easter(year);
+    let may24 = Date::new(year, 5, 24);
+    let victoria_day =
+      may24 - DateInterval::new((may24.weekday() as i32 - Weekday::Monday as i32).rem_euclid(7));
+    let mut holidays = substitute(
+      [
+        Date::new(year, 1, 1),
+        Date::new(year, 7, 1),
+        Date::new(year, 11, 11),
+        Date::new(year, 12, 25),
+        Date::new(year, 12, 26),
+      ]
+      .into_iter()
+      .chain((year >= 2021).then(|| Date::new(year, 9, 30))),
+    );
+    holidays.extend([easter - DateInterval::new(2), easter + DateInterval::new(1), victoria_day]);
+    holidays.extend(LABOUR_DAY.occurrence_in(year));
+    holidays.extend(THANKSGIVING.occurrence_in(year));
+    holidays.sort();
+    holidays
+  }
+}
+
+const LABOUR_DAY: NthWeekdayRule = NthWeekdayRule::new(1, Weekday::Monday, 9);
+const THANKSGIVING: NthWeekdayRule = NthWeekdayRule::new(2, Weekday::Monday, 10);
+
+impl HolidayCalendar for CanadaFederal {
+  fn is_holiday(&self, date: Date) -> bool {
+    Self::holidays(date.year()).contains(&date)
+  }
+
+  fn holidays_in(&self, start: Date, end: Date) -> Vec<Date> {
+    holidays_by_year(start, end, Self::holidays)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use assert2::check;
+
+  use super::*;
+
+  #[test]
+  fn test_holidays() {
+    check!(
+      CanadaFederal::holidays(2023)
+        == vec![
+          date! { 2023-01-02 },
+          date! { 2023-04-07 },
+          date! { 2023-04-10 },
+          date! { 2023-05-22 },
+          date! { 2023-07-03 },
+          date! { 2023-09-04 },
+          date! { 2023-10-02 },
+          date! { 2023-10-09 },
+          date! { 2023-11-13 },
+          date! { 2023-12-25 },
+          date! { 2023-12-26 },
+        ]
+    );
+    check!(CanadaFederal::holidays(2020).len() == 10);
+  }
+
+  #[test]
+  fn test_is_holiday() {
+    check!(CanadaFederal.is_holiday(date! { 2025-05-19 }));
+    check!(CanadaFederal.is_holiday(date! { 2021-05-24 })); // May 24 is itself a Monday.
+    check!(!CanadaFederal.is_holiday(date! { 2024-08-05 })); // Civic Holiday (provincial)
+  }
+}
@@ -0,0 +1,234 @@
+//! Japanese national holidays.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use super::holidays_by_year;
+use super::HolidayCalendar;
+use crate::interval::DateInterval;
+use crate::Date;
+use crate::NthWeekdayRule;
+use crate::Weekday;
+
+/// The Japanese national holiday calendar (国民の祝日).
+///
+/// This implements the Act on National Holidays, including:
+///
+/// - **Substitute holidays** (振替休日): when a holiday falls on a Sunday, the next day that is
+///   not already a holiday is a holiday.
+/// - **Citizens' holidays** (国民の休日): a day sandwiched between two holidays is a holiday.
+/// - The equinox days, computed using the formula published by the National Astronomical
+///   Observatory of Japan. The formula is only published for the years 1900 through 2150, so the
+///   equinox days are omitted for other years.
+/// - The one-off holidays and rearrangements for the 2019 imperial succession and the 2020 Tokyo
+///   Olympics (held in 2021).
+///
+/// This calendar is accurate for the years 2000 through 2099.
+///
+/// ## Examples
+///
+/// ```
+/// use date::date;
+/// use date::holiday::jp::Japan;
+/// use date::holiday::HolidayCalendar;
+///
+/// assert!(Japan.is_holiday(date! { 2024-03-20 })); // Vernal Equinox Day
+/// assert!(Japan.is_holiday(date! { 2024-05-06 })); // Substitute for Children's Day
+/// ```
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct Japan;
+
+impl Japan {
+  /// The national holidays in the given year, in chronological order.
+  pub fn holidays(year: i16) -> Vec<Date> {
+    let mut base = vec![
+      Date::new(year, 1, 1),
+      Date::new(year, 2, 11),
+      Date::new(year, 4, 29),
+      Date::new(year, 5, 3),
+      Date::new(year, 5, 5),
+      Date::new(year, 11, 3),
+      Date::new(year, 11, 23),
+    ];
+    base.extend(vernal_equinox(year).map(|day| Date::new(year, 3, day)));
+    base.extend(autumnal_equinox(year).map(|day| Date::new(year, 9, day)));
+
+    // Coming of Age Day, Marine Day, Respect for the Aged Day, and Sports Day (which were moved
+    // to Mondays by the "Happy Monday" system, and rearranged for the Olympics).
+    base.extend(COMING_OF_AGE_DAY.occurrence_in(year));
+    base.extend(match year {
+      2020 => Some(date! { 2020-07-23 }),
+      2021 => Some(date! { 2021-07-22 }),
+      2003.. => MARINE_DAY.occurrence_in(year),
+      _ => Some(Date::new(year, 7, 20)),
+    });
+    base.extend(match year {
+      2003.. => RESPECT_FOR_THE_AGED_DAY.occurrence_in(year),
+      _ => Some(Date::new(year, 9, 15)),
+    });
+    base.extend(match year {
+      2020 => Some(date! { 2020-07-24 }),
+      2021 => Some(date! { 2021-07-23 }),
+      _ => SPORTS_DAY.occurrence_in(year),
+    });
+
+    // Emperor's Birthday.
+    match year {
+      2020.. => base.push(Date::new(year, 2, 23)),
+      2019 => base.extend([date! { 2019-05-01 }, date! { 2019-10-22 }]),
+      _ => base.push(Date::new(year, 12, 23)),
+    }
+
+    // Greenery Day moved to May 4 in 2007; before that, May 4 was a citizens' holiday.
+    if year >= 2007 {
+      base.push(Date::new(year, 5, 4));
+    }
+
+    // Mountain Day.
+    match year {
+      2020 => base.push(date! { 2020-08-10 }),
+      2021 => base.push(date! { 2021-08-08 }),
+      2016.. => base.push(Date::new(year, 8, 11)),
+      _ => {},
+    }
+    base.sort();
+
+    // Substitute holidays.
+    let mut holidays = base.clone();
+    for date in base.iter().filter(|d| d.weekday() == Weekday::Sunday) {
+      let mut sub = *date + DateInterval::new(1);
+      while holidays.contains(&sub) {
+        sub += DateInterval::new(1);
+      }
+      holidays.push(sub);
+    }
+
+    // Citizens' holidays.
+    for pair in base.windows(2) {
+      let between = pair[0] + DateInterval::new(1);
+      if pair[1] - pair[0] == DateInterval::new(2)
+        && between.weekday() != Weekday::Sunday
+        && !holidays.contains(&between)
+      {
+        holidays.push(between);
+      }
+    }
+    holidays.sort();
+    holidays
+  }
+}
+
+impl HolidayCalendar for Japan {
+  fn is_holiday(&self, date: Date) -> bool {
+    Self::holidays(date.year()).contains(&date)
+  }
+
+  fn holidays_in(&self, start: Date, end: Date) -> Vec<Date> {
+    holidays_by_year(start, end, Self::holidays)
+  }
+}
+
+const COMING_OF_AGE_DAY: NthWeekdayRule = NthWeekdayRule::new(2, Weekday::Monday, 1);
+const MARINE_DAY: NthWeekdayRule = NthWeekdayRule::new(3, Weekday::Monday, 7);
+const RESPECT_FOR_THE_AGED_DAY: NthWeekdayRule = NthWeekdayRule::new(3, Weekday::Monday, 9);
+const SPORTS_DAY: NthWeekdayRule = NthWeekdayRule::new(2, Weekday::Monday, 10);
+
+/// The day in March of the vernal equinox, or `None` outside of 1900 through 2150.
+const fn vernal_equinox(year: i16) -> Option<u8> {
+  equinox(year, [20_835_700, 20_843_100, 21_851_000])
+}
+
+/// The day in September of the autumnal equinox, or `None` outside of 1900 through 2150.
+const fn autumnal_equinox(year: i16) -> Option<u8> {
+  equinox(year, [23_258_800, 23_248_800, 24_248_800])
+}
+
+/// The day of an equinox, given the constants (in millionths of a day) for the years 1900–1979,
+/// 1980–2099, and 2100–2150.
+const fn equinox(year: i16, constants: [i32; 3]) -> Option<u8> {
+  let y = year as i32 - 1980;
+  let (constant, leap_years) = match year {
+    1900..=1979 => (constants[0], (year as i32 - 1983) / 4),
+    1980..=2099 => (constants[1], y / 4),
+    2100..=2150 => (constants[2], y / 4),
+    _ => return None,
+  };
+  Some(((constant + 242_194 * y) / 1_000_000 - leap_years) as u8)
+}
+
+#[cfg(test)]
+mod tests {
+  use assert2::check;
+
+  use super::*;
+
+  #[test]
+  fn test_holidays() {
+    check!(
+      Japan::holidays(2019)
+        == vec![
+          date! { 2019-01-01 },
+          date! { 2019-01-14 },
+          date! { 2019-02-11 },
+          date! { 2019-03-21 },
+          date! { 2019-04-29 },
+          date! { 2019-04-30 },
+          date! { 2019-05-01 },
+          date! { 2019-05-02 },
+          date! { 2019-05-03 },
+          date! { 2019-05-04 },
+          date! { 2019-05-05 },
+          date! { 2019-05-06 },
+          date! { 2019-07-15 },
+          date! { 2019-08-11 },
+          date! { 2019-08-12 },
+          date! { 2019-09-16 },
+          date! { 2019-09-23 },
+          date! { 2019-10-14 },
+          date! { 2019-10-22 },
+          date! { 2019-11-03 },
+          date! { 2019-11-04 },
+          date! { 2019-11-23 },
+        ]
+    );
+    check!(Japan::holidays(2024).len() == 21);
+  }
+
+  #[test]
+  fn test_is_holiday() {
+    check!(Japan.is_holiday(date! { 2015-09-22 })); // Citizens' holiday
+    check!(Japan.is_holiday(date! { 2021-07-23 })); // Sports Day (Olympics)
+    check!(!Japan.is_holiday(date! { 2021-10-11 }));
+    check!(Japan.is_holiday(date! { 2006-05-04 })); // Citizens' holiday
+    check!(Japan.is_holiday(date! { 2018-12-24 })); // Substitute for Emperor's Birthday
+    check!(!Japan.is_holiday(date! { 2019-12-23 }));
+  }
+
+  #[test]
+  fn test_equinox() {
+    check!(vernal_equinox(2024) == Some(20));
+    check!(vernal_equinox(2025) == Some(20));
+    check!(autumnal_equinox(2024) == Some(22));
+    check!(autumnal_equinox(2025) == Some(23));
+    check!(autumnal_equinox(2012) == Some(22));
+    check!(vernal_equinox(1960) == Some(20));
+    check!(vernal_equinox(1979) == Some(21));
+    check!(autumnal_equinox(1979) == Some(24));
+    check!(vernal_equinox(2100) == Some(20));
+    check!(autumnal_equinox(2150) == Some(23));
+    check!(vernal_equinox(1899) == None);
+    check!(autumnal_equinox(2151) == None);
+  }
+
+  #[test]
+  fn test_year_bounds() {
+    check!(Japan.is_holiday(Date::MIN));
+    check!(!Japan.is_holiday(Date::MAX));
+    check!(Japan.is_holiday(date! { 32767-11-23 }));
+    for year in [-32768, 1899, 2151, 32767] {
+      let holidays = Japan::holidays(year);
+      check!(!holidays.is_empty());
+      check!(holidays.iter().all(|d| d.month() != 3), "{year}");
+    }
+  }
+}
@@ -0,0 +1,223 @@
+//! Exchange trading calendars.
+
+use alloc::vec::Vec;
+
+use super::holidays_by_year;
+use super::observed;
+use super::uk::EnglandAndWales;
+use super::us;
+use super::HolidayCalendar;
+use crate::interval::DateInterval;
+use crate::Date;
+use crate::Weekday;
+use crate::Workweek;
+
+/// A calendar of the days on which an exchange is open for trading.
+///
+/// An exchange trades on every working day of its workweek that is not a holiday; the holidays of
+/// an exchange include both its regular holidays and any historical one-off closures.
+pub trait TradingCalendar: HolidayCalendar {
+  /// The days of the week on which the exchange trades (by default, Monday through Friday).
+  fn workweek(&self) -> Workweek {
+    Workweek::MONDAY_TO_FRIDAY
+  }
+
+  /// Whether the exchange is open for trading on the given date.
+  fn is_trading_day(&self, date: Date) -> bool {
+    self.workweek().is_workday(date) && !self.is_holiday(date)
+  }
+
+  /// The first trading day after the given date.
+  fn next_trading_day(&self, date: Date) -> Date {
+    let mut answer = date + DateInterval::new(1);
+    while !self.is_trading_day(answer) {
+      answer += DateInterval::new(1);
+    }
+    answer
+  }
+}
+
+/// The New York Stock Exchange.
+///
+/// The regular NYSE holidays are New Year's Day, Birthday of Martin Luther King, Jr. (since 1998),
+/// Washington's Birthday, Good Friday, Memorial Day, Juneteenth (since 2022), Independence Day,
+/// Labor Day, Thanksgiving, and Christmas. Holidays that fall on a Saturday are observed on the
+/// preceding Friday, except New Year's Day (which is not observed in that case, since the Friday
+/// ends an accounting period); holidays that fall on a Sunday are observed on the following
+/// Monday.
+///
+/// Unscheduled closures since 1985 (for weather, national days of mourning, and the September 11
+/// attacks) are also included.
+///
+/// ## Examples
+///
+/// ```
+/// use date::date;
+/// use date::holiday::market::Nyse;
+/// use date::holiday::market::TradingCalendar;
+///
+/// assert!(!Nyse.is_trading_day(date! { 2024-03-29 })); // Good Friday
+/// assert!(!Nyse.is_trading_day(date! { 2012-10-29 })); // Hurricane Sandy
+/// assert_eq!(Nyse.next_trading_day(date! { 2024-12-24 }), date! { 2024-12-26 });
+/// ```
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct Nyse;
+
+impl Nyse {
+  /// The days in the given year on which the NYSE is closed (other than weekends), in
+  /// chronological order.
+  pub fn holidays(year: i16) -> Vec<Date> {
+    let mut holidays: Vec<Date> = [
+      Some(Date::new(year, 1, 1)).filter(|d| d.weekday() != Weekday::Saturday).map(observed),
+      us::MLK_DAY.occurrence_in(year).filter(|_| year >= 1998),
+      us::WASHINGTONS_BIRTHDAY.occurrence_in(year),
+      Some(Date::easter(year) - DateInterval::new(2)),
+      us::MEMORIAL_DAY.occurrence_in(year),
+      (year >= 2022).then(|| observed(Date::new(year, 6, 19))),
+      Some(observed(Date::new(year, 7, 4))),
+      us::LABOR_DAY.occurrence_in(year),
+      us::THANKSGIVING.occurrence_in(year),
+      Some(observed(Date::new(year, 12, 25))),
+    ]
+    .into_iter()
+    .flatten()
+    .chain(NYSE_CLOSURES.iter().copied().filter(|d| d.year() == year))
+    .collect();
+    holidays.sort();
+    holidays
+  }
+}
+
+impl HolidayCalendar for Nyse {
+  fn is_holiday(&self, date: Date) -> bool {
+    Self::holidays(date.year()).contains(&date)
+  }
+
+  fn holidays_in(&self, start: Date, end: Date) -> Vec<Date> {
+    holidays_by_year(start, end, Self::holidays)
+  }
+}
+
+impl TradingCalendar for Nyse {}
+
+/// Unscheduled NYSE closures.
+const NYSE_CLOSURES: [Date; 12] = [
+  date! { 1985-09-27 }, // Hurricane Gloria
+  date! { 1994-04-27 }, // National day of mourning for Richard Nixon
+  date! { 2001-09-11 }, // September 11 attacks
+  date! { 2001-09-12 },
+  date! { 2001-09-13 },
+  date! { 2001-09-14 },
+  date! { 2004-06-11 }, // National day of mourning for Ronald Reagan
+  date! { 2007-01-02 }, // National day of mourning for Gerald Ford
+  date! { 2012-10-29 }, // Hurricane Sandy
+  date! { 2012-10-30 },
+  date! { 2018-12-05 }, // National day of mourning for George H. W. Bush
+  date! { 2025-01-09 }, // National day of mourning for Jimmy Carter
+];
+
+/// The London Stock Exchange.
+///
+/// The LSE is closed on the bank holidays of England and Wales (see [`EnglandAndWales`]),
+/// including one-off bank holidays, as well as on the day of the Great Storm of 1987.
+///
+/// ## Examples
+///
+/// ```
+/// use date::date;
+/// use date::holiday::market::Lse;
+/// use date::holiday::market::TradingCalendar;
+///
+/// assert!(!Lse.is_trading_day(date! { 2024-08-26 })); // Summer bank holiday
+/// assert!(Lse.is_trading_day(date! { 2024-07-04 }));
+/// ```
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct Lse;
+
+impl Lse {
+  /// The days in the given year on which the LSE is closed (other than weekends), in
+  /// chronological order.
+  pub fn holidays(year: i16) -> Vec<Date> {
+    let mut holidays = EnglandAndWales::holidays(year);
+    holidays.extend(LSE_CLOSURES.iter().filter(|d| d.year() == year));
+    holidays.sort();
+    holidays
+  }
+}
+
+impl HolidayCalendar for Lse {
+  fn is_holiday(&self, date: Date) -> bool {
+    Self::holidays(date.year()).contains(&date)
+  }
+
+  fn holidays_in(&self, start: Date, end: Date) -> Vec<Date> {
+    holidays_by_year(start, end, Self::holidays)
+  }
+}
+
+impl TradingCalendar for Lse {}
+
+/// Unscheduled LSE closures.
+const LSE_CLOSURES: [Date; 1] = [
+  date! { 1987-10-16 }, // Great Storm of 1987
+];
+
+#[cfg(test)]
+mod tests {
+  use assert2::check;
+
+  use super::*;
+  use crate::holiday::BusinessCalendar;
+
+  #[test]
+  fn test_nyse_holidays() {
+    check!(
+      Nyse::holidays(2022)
+        == vec![
+          date! { 2022-01-17 },
+          date! { 2022-02-21 },
+          date! { 2022-04-15 },
+          date! { 2022-05-30 },
+          date! { 2022-06-20 },
+          date! { 2022-07-04 },
+          date! { 2022-09-05 },
+          date! { 2022-11-24 },
+          date! { 2022-12-26 },
+        ]
+    );
+    check!(Nyse::holidays(2001).len() == 13);
+    check!(Nyse::holidays(2023)[0] == date! { 2023-01-02 });
+  }
+
+  #[test]
+  fn test_nyse_trading_days() {
+    check!(Nyse.is_trading_day(date! { 2021-12-31 }));
+    check!(!Nyse.is_trading_day(date! { 2021-12-24 }));
+    check!(!Nyse.is_trading_day(date! { 2024-06-15 }));
+    check!(Nyse.next_trading_day(date! { 2001-09-10 }) == date! { 2001-09-17 });
+    check!(Nyse.next_trading_day(date! { 2024-11-27 }) == date! { 2024-11-29 });
+    check!(Nyse.next_trading_day(date! { 2024-03-28 }) == date! { 2024-04-01 });
+  }
+
+  #[test]
+  fn test_nyse_business_days() {
+    let nyse = BusinessCalendar::new(Nyse.workweek(), Nyse);
+    check!(nyse.business_days_between(date! { 2021-12-31 }, date! { 2022-12-30 }) == 251);
+    check!(nyse.business_days_between(date! { 2022-12-30 }, date! { 2023-12-29 }) == 250);
+    check!(nyse.business_days_between(date! { 2023-12-29 }, date! { 2024-12-31 }) == 252);
+    check!(nyse.add_business_days(date! { 2024-06-18 }, 1) == date! { 2024-06-20 });
+    check!(nyse.add_business_days(date! { 2024-11-26 }, 2) == date! { 2024-11-29 });
+    check!(date! { 2001-09-10 }.add_business_days(1, &nyse) == date! { 2001-09-17 });
+  }
+
+  #[test]
+  fn test_lse() {
+    check!(!Lse.is_trading_day(date! { 1987-10-16 }));
+    check!(!Lse.is_trading_day(date! { 2023-05-08 }));
+    check!(Lse.next_trading_day(date! { 2024-12-24 }) == date! { 2024-12-27 });
+    check!(Lse.next_trading_day(date! { 2024-03-28 }) == date! { 2024-04-02 });
+    let lse = BusinessCalendar::new(Lse.workweek(), Lse);
+    check!(lse.business_days_between(date! { 2023-12-29 }, date! { 2024-12-31 }) == 254);
+    check!(lse.add_business_days(date! { 2024-12-23 }, 2) == date! { 2024-12-27 });
+  }
+}
@@ -0,0 +1,237 @@
+//! Holiday calendars.
+//!
+//! A [`HolidayCalendar`] answers whether a given date is a holiday. Built-in calendars for
+//! specific jurisdictions are available behind feature flags:
+//!
+//! | Feature           | Calendar                                                           |
+//! | ----------------- | ------------------------------------------------------------------ |
+//! | `holidays-au`     | [`au::AustraliaNational`]                                          |
+//! | `holidays-ca`     | [`ca::CanadaFederal`]                                              |
+//! | `holidays-jp`     | [`jp::Japan`]                                                      |
+//! | `holidays-target` | [`target::Target`] (euro area TARGET closing days)                 |
+//! | `holidays-uk`     | [`uk::EnglandAndWales`], [`uk::Scotland`], [`uk::NorthernIreland`] |
+//! | `holidays-us`     | [`us::UsFederal`]                                                  |
+//! | `markets`         | [`market::Nyse`], [`market::Lse`]                                  |
+//!
+//! Fixed lists of holidays can be expressed as a [`HolidayTable`], which can be built in a `const`
+//! context.
+//!
+//! Calendars can be composed: a tuple, array, or slice of calendars is itself a calendar, in which
+//! a date is a holiday if it is a holiday in _any_ of the constituent calendars. Custom calendars
+//! of holidays that fall on the same day every year can be built from [`FixedHoliday`] values,
+//! each observed according to an [`Observance`] rule when it falls on a weekend.
+//!
+//! ```
+//! # #[cfg(all(feature = "holidays-uk", feature = "holidays-us"))] {
+//! use date::date;
+//! use date::holiday::uk::EnglandAndWales;
+//! use date::holiday::us::UsFederal;
+//! use date::holiday::HolidayCalendar;
+//!
+//! let london_and_new_york = (EnglandAndWales, UsFederal);
+//! assert!(london_and_new_york.is_holiday(date! { 2024-07-04 }));
+//! assert!(london_and_new_york.is_holiday(date! { 2024-08-26 }));
+//! # }
+//! ```
+//!
+//! A [`BusinessCalendar`] combines a calendar of holidays with a [`Workweek`](crate::Workweek),
+//! to determine the business days on which neither applies.
+
+use alloc::vec::Vec;
+
+use crate::interval::DateInterval;
+use crate::Date;
+
+#[cfg(feature = "holidays-au")]
+pub mod au;
+mod business;
+#[cfg(feature = "holidays-ca")]
+pub mod ca;
+#[cfg(feature = "holidays-jp")]
+pub mod jp;
+#[cfg(feature = "markets")]
+pub mod market;
+mod observance;
+mod table;
+#[cfg(feature = "holidays-target")]
+pub mod target;
+#[cfg(feature = "holidays-uk")]
+pub mod uk;
+#[cfg(feature = "holidays-us")]
+pub mod us;
+
+pub use business::BusinessCalendar;
+pub use business::RollConvention;
+pub use observance::FixedHoliday;
+pub use observance::Observance;
+pub use table::HolidayTable;
+
+/// A calendar of holidays.
+pub trait HolidayCalendar {
+  /// Whether the given date is a holiday in this calendar.
+  fn is_holiday(&self, date: Date) -> bool;
+
+  /// The holidays between `start` and `end` (inclusive), in chronological order.
+  fn holidays_in(&self, start: Date, end: Date) -> Vec<Date> {
+    start.iter_through(end).filter(|d| self.is_holiday(*d)).collect()
+  }
+}
+
+impl<T: HolidayCalendar + ?Sized> HolidayCalendar for &T {
+  fn is_holiday(&self, date: Date) -> bool {
+    (**self).is_holiday(date)
+  }
+
+  fn holidays_in(&self, start: Date, end: Date) -> Vec<Date> {
+    (**self).holidays_in(start, end)
+  }
+}
+
+impl<T: HolidayCalendar> HolidayCalendar for [T] {
+  fn is_holiday(&self, date: Date) -> bool {
+    self.iter().any(|c| c.is_holiday(date))
+  }
+
+  fn holidays_in(&self, start: Date, end: Date) -> Vec<Date> {
+    merge(self.iter().map(|c| c.holidays_in(start, end)))
+  }
+}
+
+impl<T: HolidayCalendar, const N: usize> HolidayCalendar for [T; N] {
+  fn is_holiday(&self, date: Date) -> bool {
+    self.iter().any(|c| c.is_holiday(date))
+  }
+
+  fn holidays_in(&self, start: Date, end: Date) -> Vec<Date> {
+    merge(self.iter().map(|c| c.holidays_in(start, end)))
+  }
+}
+
+macro_rules! tuple_calendar {
+  ($($t:ident => $idx:tt),*) => {
+    impl<$($t: HolidayCalendar),*> HolidayCalendar for ($($t,)*) {
+      fn is_holiday(&self, date: Date) -> bool {
+        $(self.$idx.is_holiday(date))||*
+      }
+
+      fn holidays_in(&self, start: Date, end: Date) -> Vec<Date> {
+        merge([$(self.$idx.holidays_in(start, end)),*])
+      }
+    }
+  };
+}
+tuple_calendar!(A => 0, B => 1);
+tuple_calendar!(A => 0, B => 1, C => 2);
+tuple_calendar!(A => 0, B => 1, C => 2, D => 3);
+tuple_calendar!(A => 0, B => 1, C => 2, D => 3, E => 4);
+tuple_calendar!(A => 0, B => 1, C => 2, D => 3, E => 4, F => 5);
+
+/// Merge lists of holidays into a single chronological list without duplicates.
+fn merge(holidays: impl IntoIterator<Item = Vec<Date>>) -> Vec<Date> {
+  let mut answer: Vec<Date> = holidays.into_iter().flatten().collect();
+  answer.sort();
+  answer.dedup();
+  answer
+}
+
+/// The holidays between `start` and `end` (inclusive) of a calendar whose holidays are computed
+/// one year at a time.
+#[cfg_attr(
+  not(any(
+    feature = "holidays-au",
+    feature = "holidays-ca",
+    feature = "holidays-jp",
+    feature = "holidays-target",
+    feature = "holidays-uk",
+    feature = "holidays-us",
+  )),
+  allow(dead_code)
+)]
+pub(crate) fn holidays_by_year(
+  start: Date, end: Date, holidays: impl Fn(i16) -> Vec<Date>,
+) -> Vec<Date> {
+  match start <= end {
+    true =>
+      (start.year()..=end.year()).flat_map(holidays).filter(|d| start <= *d && *d <= end).collect(),
+    false => Vec::new(),
+  }
+}
+
+/// The date on which a holiday is observed if it falls on a weekend: Saturday holidays are
+/// observed on the preceding Friday, and Sunday holidays on the following Monday.
+#[cfg_attr(not(any(feature = "holidays-us", feature = "markets")), allow(dead_code))]
+pub(crate) const fn observed(date: Date) -> Date {
+  Observance::NearestWeekday.apply(date)
+}
+
+/// Move holidays that fall on a weekend to the next weekday that is not already a holiday, and
+/// return all of the holidays in chronological order.
+///
+/// This is the "substitute day" rule used (with minor variations) in the United Kingdom, Canada,
+/// and Australia. A holiday with no such weekday on or before [`Date::MAX`] is not moved.
+#[cfg_attr(
+  not(any(feature = "holidays-au", feature = "holidays-ca", feature = "holidays-uk")),
+  allow(dead_code)
+)]
+pub(crate) fn substitute(holidays: impl IntoIterator<Item = Date>) -> Vec<Date> {
+  let (weekends, mut answer): (Vec<Date>, Vec<Date>) =
+    holidays.into_iter().partition(|d| d.is_weekend());
+  for date in weekends {
+    let mut sub = Some(date);
+    while let Some(d) = sub.filter(|d| d.is_weekend() || answer.contains(d)) {
+      sub = d.checked_add_days(DateInterval::new(1));
+    }
+    answer.push(sub.unwrap_or(date));
+  }
+  answer.sort();
+  answer
+}
+
+#[cfg(test)]
+mod tests {
+  use assert2::check;
+
+  use super::*;
+
+  #[test]
+  fn test_observed() {
+    check!(observed(date! { 2021-12-25 }) == date! { 2021-12-24 });
+    check!(observed(date! { 2021-07-04 }) == date! { 2021-07-05 });
+    check!(observed(date! { 2024-07-04 }) == date! { 2024-07-04 });
+  }
+
+  #[test]
+  fn test_substitute() {
+    // Christmas on Saturday, Boxing Day on Sunday.
+    check!(
+      substitute([date! { 2021-12-25 }, date! { 2021-12-26 }])
+        == vec![date! { 2021-12-27 }, date! { 2021-12-28 }]
+    );
+    // Christmas on Sunday, Boxing Day on Monday.
+    check!(
+      substitute([date! { 2022-12-25 }, date! { 2022-12-26 }])
+        == vec![date! { 2022-12-26 }, date! { 2022-12-27 }]
+    );
+    // A holiday on the last representable Sunday has nowhere to move to.
+    check!(
+      substitute([date! { 32767-12-30 }, Date::MAX]) == vec![date! { 32767-12-30 }, Date::MAX]
+    );
+  }
+
+  #[test]
+  fn test_compose() {
+    struct Fixed(Date);
+    impl HolidayCalendar for Fixed {
+      fn is_holiday(&self, date: Date) -> bool {
+        date == self.0
+      }
+    }
+    let composed = (Fixed(date! { 2024-01-01 }), Fixed(date! { 2024-01-03 }));
+    check!(composed.is_holiday(date! { 2024-01-01 }));
+    check!(!composed.is_holiday(date! { 2024-01-02 }));
+    check!(composed.is_holiday(date! { 2024-01-03 }));
+    check!(composed.holidays_in(date! { 2023-12-31 }, date! { 2024-01-31 }).len() == 2);
+    let nested = (&composed, Fixed(date! { 2024-01-02 }));
+    check!(nested.holidays_in(date! { 2023-12-31 }, date! { 2024-01-31 }).len() == 3);
+  }
+}
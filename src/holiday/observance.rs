@@ -0,0 +1,215 @@
+use alloc::vec::Vec;
+
+use super::HolidayCalendar;
+use crate::interval::DateInterval;
+use crate::Date;
+use crate::Weekday;
+
+/// A rule for the day on which a holiday is observed when it falls on a weekend.
+///
+/// These rules assume a Saturday–Sunday weekend. For calendars in which a holiday that falls on a
+/// weekend is moved to the next day that is not _already_ a holiday (as in the United Kingdom),
+/// see the built-in calendars in the [`holiday`](crate::holiday) module.
+///
+/// ## Examples
+///
+/// ```
+/// use date::date;
+/// use date::holiday::Observance;
+///
+/// let christmas = date! { 2021-12-25 }; // Saturday
+/// assert_eq!(Observance::NearestWeekday.apply(christmas), date! { 2021-12-24 });
+/// assert_eq!(Observance::NextWeekday.apply(christmas), date! { 2021-12-27 });
+/// assert_eq!(Observance::SundayToMonday.apply(christmas), christmas);
+/// ```
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub enum Observance {
+  /// The holiday is observed on the day on which it falls, even on a weekend.
+  #[default]
+  Actual,
+  /// A holiday on Saturday is observed on the preceding Friday, and a holiday on Sunday on the
+  /// following Monday. This is the rule for United States federal holidays.
+  NearestWeekday,
+  /// A holiday on Sunday is observed on the following Monday; a holiday on Saturday is not moved.
+  SundayToMonday,
+  /// A holiday on Saturday or Sunday is observed on the following Monday.
+  NextWeekday,
+  /// A holiday on Saturday or Sunday is observed on the preceding Friday.
+  PreviousWeekday,
+}
+
+impl Observance {
+  /// The date on which a holiday falling on the given date is observed.
+  ///
+  /// A holiday whose observed date would fall outside the range of [`Date::MIN`] to
+  /// [`Date::MAX`] is observed on its actual date.
+  pub const fn apply(self, date: Date) -> Date {
+    let offset = match (self, date.weekday()) {
+      (Self::NearestWeekday, Weekday::Saturday) => -1,
+      (Self::NearestWeekday | Self::SundayToMonday, Weekday::Sunday) => 1,
+      (Self::NextWeekday, Weekday::Saturday) => 2,
+      (Self::NextWeekday, Weekday::Sunday) => 1,
+      (Self::PreviousWeekday, Weekday::Saturday) => -1,
+      (Self::PreviousWeekday, Weekday::Sunday) => -2,
+      _ => 0,
+    };
+    match date.checked_add_days(DateInterval::new(offset)) {
+      Some(observed) => observed,
+      None => date,
+    }
+  }
+}
+
+/// A holiday that falls on the same month and day every year, observed according to an
+/// [`Observance`] rule.
+///
+/// A `FixedHoliday` is itself a [`HolidayCalendar`], and a slice or array of them is a calendar
+/// in which a date is a holiday if any of them is observed on it; this makes it straightforward
+/// to build custom calendars.
+///
+/// ## Examples
+///
+/// ```
+/// use date::date;
+/// use date::holiday::FixedHoliday;
+/// use date::holiday::HolidayCalendar;
+/// use date::holiday::Observance;
+///
+/// const HOLIDAYS: [FixedHoliday; 2] = [
+///   FixedHoliday::new(1, 1).with_observance(Observance::SundayToMonday),
+///   FixedHoliday::new(7, 4).with_observance(Observance::NearestWeekday),
+/// ];
+///
+/// assert!(HOLIDAYS.is_holiday(date! { 2023-01-02 })); // New Year's Day (observed)
+/// assert!(!HOLIDAYS.is_holiday(date! { 2021-12-31 })); // New Year's Day 2022 is not moved.
+/// assert!(HOLIDAYS.is_holiday(date! { 2020-07-03 })); // Independence Day (observed)
+/// ```
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub struct FixedHoliday {
+  month: u8,
+  day: u8,
+  observance: Observance,
+}
+
+impl FixedHoliday {
+  /// A holiday falling on the given month and day every year, observed on that day.
+  ///
+  /// A holiday on February 29 occurs only in leap years.
+  ///
+  /// ## Panic
+  ///
+  /// This function panics if the month and day do not form a valid date in a leap year.
+  pub const fn new(month: u8, day: u8) -> Self {
+    assert!(Date::checked_new(2000, month, day).is_some(), "Invalid month and day");
+    Self { month, day, observance: Observance::Actual }
+  }
+
+  /// This holiday, observed according to the given rule.
+  pub const fn with_observance(self, observance: Observance) -> Self {
+    Self { observance, ..self }
+  }
+
+  /// The month in which the holiday falls.
+  pub const fn month(&self) -> u8 {
+    self.month
+  }
+
+  /// The day of the month on which the holiday falls.
+  pub const fn day(&self) -> u8 {
+    self.day
+  }
+
+  /// The rule for the day on which the holiday is observed.
+  pub const fn observance(&self) -> Observance {
+    self.observance
+  }
+
+  /// The date on which the holiday is observed for the given year, or `None` if it does not occur
+  /// in that year.
+  ///
+  /// The observed date may fall in an adjacent year: with [`Observance::NearestWeekday`], a
+  /// holiday on January 1 that falls on a Saturday is observed on December 31 of the previous
+  /// year.
+  pub const fn observed_in(&self, year: i16) -> Option<Date> {
+    match Date::checked_new(year, self.month, self.day) {
+      Some(date) => Some(self.observance.apply(date)),
+      None => None,
+    }
+  }
+}
+
+impl HolidayCalendar for FixedHoliday {
+  fn is_holiday(&self, date: Date) -> bool {
+    let year = date.year();
+    [year.checked_sub(1), Some(year), year.checked_add(1)]
+      .into_iter()
+      .flatten()
+      .any(|y| self.observed_in(y) == Some(date))
+  }
+
+  fn holidays_in(&self, start: Date, end: Date) -> Vec<Date> {
+    match start <= end {
+      true => (start.year().saturating_sub(1)..=end.year().saturating_add(1))
+        .filter_map(|y| self.observed_in(y))
+        .filter(|d| start <= *d && *d <= end)
+        .collect(),
+      false => Vec::new(),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use assert2::check;
+
+  use super::*;
+
+  #[test]
+  fn test_apply() {
+    let (friday, saturday, sunday, monday) =
+      (date! { 2024-04-19 }, date! { 2024-04-20 }, date! { 2024-04-21 }, date! { 2024-04-22 });
+    let cases = [
+      (Observance::Actual, [friday, saturday, sunday, monday]),
+      (Observance::NearestWeekday, [friday, friday, monday, monday]),
+      (Observance::SundayToMonday, [friday, saturday, monday, monday]),
+      (Observance::NextWeekday, [friday, monday, monday, monday]),
+      (Observance::PreviousWeekday, [friday, friday, friday, monday]),
+    ];
+    for (observance, expected) in cases {
+      check!([friday, saturday, sunday, monday].map(|d| observance.apply(d)) == expected);
+    }
+    check!(Date::MAX.weekday() == Weekday::Sunday);
+    check!(Observance::NextWeekday.apply(Date::MAX) == Date::MAX);
+    check!(Observance::NearestWeekday.apply(Date::MAX) == Date::MAX);
+    check!(Observance::PreviousWeekday.apply(Date::MAX) == date! { 32767-12-29 });
+  }
+
+  #[test]
+  fn test_fixed_holiday() {
+    let new_year = FixedHoliday::new(1, 1).with_observance(Observance::NearestWeekday);
+    check!(new_year.observed_in(2022) == Some(date! { 2021-12-31 }));
+    check!(new_year.is_holiday(date! { 2021-12-31 }));
+    check!(!new_year.is_holiday(date! { 2022-01-01 }));
+    check!(new_year.is_holiday(date! { 2023-01-02 }));
+    check!(new_year.is_holiday(date! { 2024-01-01 }));
+
+    let leap_day = FixedHoliday::new(2, 29);
+    check!(leap_day.observed_in(2023) == None);
+    check!(leap_day.is_holiday(date! { 2024-02-29 }));
+    check!(FixedHoliday::new(12, 31)
+      .with_observance(Observance::NextWeekday)
+      .is_holiday(date! { 2024-01-01 }));
+    check!(FixedHoliday::new(12, 31).is_holiday(Date::MAX));
+    check!(
+      new_year.holidays_in(date! { 2021-01-01 }, date! { 2023-12-31 })
+        == [date! { 2021-01-01 }, date! { 2021-12-31 }, date! { 2023-01-02 }]
+    );
+    check!(new_year.holidays_in(date! { 2024-01-02 }, date! { 2024-01-01 }).is_empty());
+  }
+
+  #[test]
+  #[should_panic]
+  fn test_fixed_holiday_invalid() {
+    FixedHoliday::new(2, 30);
+  }
+}
@@ -0,0 +1,114 @@
+use alloc::vec::Vec;
+
+use super::HolidayCalendar;
+use crate::Date;
+
+/// A holiday calendar backed by a fixed, sorted list of dates.
+///
+/// A `HolidayTable` can be built in a `const` context, so that a fixed list of holidays (such as
+/// an internal list of company closures) can live in a `static` with no allocation or startup
+/// cost. Lookups use binary search.
+///
+/// ## Examples
+///
+/// ```
+/// use date::date;
+/// use date::holiday::HolidayCalendar;
+/// use date::holiday::HolidayTable;
+///
+/// static CLOSURES: HolidayTable =
+///   HolidayTable::new(&[date! { 2024-01-01 }, date! { 2024-07-04 }, date! { 2024-12-25 }]);
+///
+/// assert!(CLOSURES.is_holiday(date! { 2024-07-04 }));
+/// assert!(!CLOSURES.is_holiday(date! { 2024-07-05 }));
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct HolidayTable {
+  dates: &'static [Date],
+}
+
+impl HolidayTable {
+  /// A holiday table consisting of the given dates.
+  ///
+  /// ## Panic
+  ///
+  /// This function panics if the dates are not in strictly ascending order. When used to
+  /// initialize a `const` or `static`, this is a compile-time error.
+  pub const fn new(dates: &'static [Date]) -> Self {
+    match Self::checked_new(dates) {
+      Some(table) => table,
+      None => panic!("Holiday table dates must be sorted and unique"),
+    }
+  }
+
+  /// A holiday table consisting of the given dates, or `None` if the dates are not in strictly
+  /// ascending order.
+  ///
+  /// This is the non-panicking counterpart to [`HolidayTable::new`].
+  pub const fn checked_new(dates: &'static [Date]) -> Option<Self> {
+    let mut i = 1;
+    while i < dates.len() {
+      if dates[i - 1].0 >= dates[i].0 {
+        return None;
+      }
+      i += 1;
+    }
+    Some(Self { dates })
+  }
+
+  /// The dates in this table, in chronological order.
+  #[inline]
+  pub const fn dates(&self) -> &'static [Date] {
+    self.dates
+  }
+}
+
+impl HolidayCalendar for HolidayTable {
+  fn is_holiday(&self, date: Date) -> bool {
+    self.dates.binary_search(&date).is_ok()
+  }
+
+  fn holidays_in(&self, start: Date, end: Date) -> Vec<Date> {
+    let first = self.dates.partition_point(|d| *d < start);
+    let last = self.dates.partition_point(|d| *d <= end);
+    self.dates[first..last.max(first)].to_vec()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use assert2::check;
+
+  use super::*;
+
+  const TABLE: HolidayTable =
+    HolidayTable::new(&[date! { 2024-01-01 }, date! { 2024-05-27 }, date! { 2024-12-25 }]);
+
+  #[test]
+  fn test_is_holiday() {
+    check!(TABLE.is_holiday(date! { 2024-05-27 }));
+    check!(!TABLE.is_holiday(date! { 2024-05-28 }));
+    check!(!HolidayTable::new(&[]).is_holiday(date! { 2024-05-28 }));
+  }
+
+  #[test]
+  fn test_holidays_in() {
+    check!(TABLE.holidays_in(date! { 2024-01-01 }, date! { 2024-05-27 }).len() == 2);
+    check!(TABLE.holidays_in(date! { 2024-01-02 }, date! { 2024-12-31 }).len() == 2);
+    check!(TABLE.holidays_in(date! { 2024-06-01 }, date! { 2024-01-01 }).is_empty());
+  }
+
+  #[test]
+  fn test_checked_new() {
+    static DUPLICATED: [Date; 2] = [date! { 2024-12-25 }, date! { 2024-12-25 }];
+    check!(HolidayTable::checked_new(&DUPLICATED) == None);
+    check!(HolidayTable::checked_new(&[]).is_some());
+  }
+
+  #[test]
+  #[should_panic]
+  fn test_unsorted() {
+    static UNSORTED: [Date; 2] = [date! { 2024-12-25 }, date! { 2024-01-01 }];
+    HolidayTable::new(&UNSORTED);
+  }
+}
@@ -0,0 +1,134 @@
+//! The TARGET closing-day calendar of the Eurosystem.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use super::holidays_by_year;
+use super::BusinessCalendar;
+use super::HolidayCalendar;
+use crate::interval::DateInterval;
+use crate::Date;
+use crate::Workweek;
+
+/// The closing days of TARGET, the Eurosystem's real-time gross settlement system.
+///
+/// This is the standard calendar for determining settlement dates for transactions denominated in
+/// euro. Since 2002, TARGET has been closed on New Year's Day, Good Friday, Easter Monday, Labour
+/// Day (May 1), Christmas Day, and December 26. In 2000 and 2001, it was additionally closed on
+/// December 31. In 1999, its first year of operation, it was closed only on New Year's Day,
+/// Christmas Day, and December 31. There is no substitution for closing days that fall on a
+/// weekend (TARGET is always closed on weekends).
+///
+/// Use [`Target::business_calendar`] to find TARGET settlement days.
+///
+/// ## Examples
+///
+/// ```
+/// use date::date;
+/// use date::holiday::target::Target;
+/// use date::holiday::HolidayCalendar;
+///
+/// assert!(Target.is_holiday(date! { 2024-05-01 }));
+/// assert!(Target.is_holiday(date! { 2024-04-01 })); // Easter Monday
+/// assert!(!Target.is_holiday(date! { 2024-05-09 })); // Ascension Day
+/// ```
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct Target;
+
+impl Target {
+  /// The TARGET closing days in the given year, in chronological order.
+  pub fn holidays(year: i16) -> Vec<Date> {
+    if year < 2000 {
+      return vec![Date::new(year, 1, 1), Date::new(year, 12, 25), Date::new(year, 12, 31)];
+    }
+    let easter = Date::easter(year);
+    let mut holidays = vec![
+      Date::new(year, 1, 1),
+      easter - DateInterval::new(2),
+      easter + DateInterval::new(1),
+      Date::new(year, 5, 1),
+      Date::new(year, 12, 25),
+      Date::new(year, 12, 26),
+    ];
+    if year == 2000 || year == 2001 {
+      holidays.push(Date::new(year, 12, 31));
+    }
+    holidays
+  }
+
+  /// The TARGET business calendar: the weekdays (Monday through Friday) on which TARGET is open,
+  /// which are the settlement days for euro-denominated transactions.
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::date;
+  /// use date::holiday::target::Target;
+  ///
+  /// let target = Target::business_calendar();
+  /// assert_eq!(target.next_business_day(date! { 2024-03-28 }), date! { 2024-04-02 });
+  /// assert_eq!(target.next_business_day(date! { 2024-12-24 }), date! { 2024-12-27 });
+  /// ```
+  pub const fn business_calendar() -> BusinessCalendar<Self> {
+    BusinessCalendar::new(Workweek::MONDAY_TO_FRIDAY, Self)
+  }
+}
+
+impl HolidayCalendar for Target {
+  fn is_holiday(&self, date: Date) -> bool {
+    Self::holidays(date.year()).contains(&date)
+  }
+
+  fn holidays_in(&self, start: Date, end: Date) -> Vec<Date> {
+    holidays_by_year(start, end, Self::holidays)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use assert2::check;
+
+  use super::*;
+
+  #[test]
+  fn test_holidays() {
+    check!(
+      Target::holidays(2025)
+        == vec![
+          date! { 2025-01-01 },
+          date! { 2025-04-18 },
+          date! { 2025-04-21 },
+          date! { 2025-05-01 },
+          date! { 2025-12-25 },
+          date! { 2025-12-26 },
+        ]
+    );
+    check!(Target::holidays(2001).len() == 7);
+    check!(
+      Target::holidays(1999)
+        == vec![date! { 1999-01-01 }, date! { 1999-12-25 }, date! { 1999-12-31 }]
+    );
+  }
+
+  #[test]
+  fn test_is_holiday() {
+    check!(Target.is_holiday(date! { 2025-04-18 }));
+    check!(Target.is_holiday(date! { 2001-12-31 }));
+    check!(!Target.is_holiday(date! { 2002-12-31 }));
+    check!(!Target.is_holiday(date! { 2025-05-02 }));
+    check!(!Target.is_holiday(date! { 1999-04-02 }));
+  }
+
+  #[test]
+  fn test_business_calendar() {
+    let target = Target::business_calendar();
+    check!(target.is_business_day(date! { 2025-05-02 }));
+    check!(!target.is_business_day(date! { 2025-05-01 }));
+    check!(!target.is_business_day(date! { 2025-05-03 }));
+    check!(target.prev_business_day(date! { 2025-04-22 }) == date! { 2025-04-17 });
+    check!(target.next_business_day(date! { 2001-12-28 }) == date! { 2002-01-02 });
+    check!(target.add_business_days(date! { 2024-03-28 }, 2) == date! { 2024-04-03 });
+    check!(target.add_business_days(date! { 2024-04-03 }, -2) == date! { 2024-03-28 });
+    check!(target.business_days_between(date! { 2023-12-29 }, date! { 2024-12-31 }) == 256);
+  }
+}
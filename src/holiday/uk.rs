@@ -0,0 +1,288 @@
+//! United Kingdom bank holidays.
+//!
+//! Bank holidays differ between England and Wales ([`EnglandAndWales`]), [`Scotland`], and
+//! [`NorthernIreland`].
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use super::holidays_by_year;
+use super::substitute;
+use super::HolidayCalendar;
+use crate::interval::DateInterval;
+use crate::Date;
+use crate::NthWeekdayRule;
+use crate::Weekday;
+
+/// The bank holiday calendar for England and Wales.
+///
+/// Bank holidays that fall on a weekend are replaced by a substitute day on the following weekday
+/// that is not already a bank holiday. This calendar reports the substitute days, which are the
+/// days on which banks are closed.
+///
+/// The regular bank holidays are New Year's Day, Good Friday, Easter Monday, the early May bank
+/// holiday (first Monday in May, since 1978), the spring bank holiday (last Monday in May), the
+/// summer bank holiday (last Monday in August), Christmas Day, and Boxing Day. One-off bank
+/// holidays proclaimed for royal and national occasions since 1999 are also included, as are the
+/// years in which a regular bank holiday was moved to accommodate one.
+///
+/// ## Examples
+///
+/// ```
+/// use date::date;
+/// use date::holiday::uk::EnglandAndWales;
+/// use date::holiday::HolidayCalendar;
+///
+/// assert!(EnglandAndWales.is_holiday(date! { 2024-03-29 })); // Good Friday
+/// assert!(EnglandAndWales.is_holiday(date! { 2023-05-08 })); // Coronation of Charles III
+/// assert!(EnglandAndWales.is_holiday(date! { 2021-12-28 })); // Boxing Day (substitute)
+/// ```
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct EnglandAndWales;
+
+impl EnglandAndWales {
+  /// The bank holidays in England and Wales in the given year, in chronological order.
+  pub fn holidays(year: i16) -> Vec<Date> {
+    let mut holidays = common(year);
+    holidays.push(Date::easter(year) + DateInterval::new(1));
+    holidays.extend(SUMMER.occurrence_in(year));
+    substitute(holidays)
+  }
+}
+
+impl HolidayCalendar for EnglandAndWales {
+  fn is_holiday(&self, date: Date) -> bool {
+    Self::holidays(date.year()).contains(&date)
+  }
+
+  fn holidays_in(&self, start: Date, end: Date) -> Vec<Date> {
+    holidays_by_year(start, end, Self::holidays)
+  }
+}
+
+/// The bank holiday calendar for Scotland.
+///
+/// Scotland shares most of its bank holidays with England and Wales (see [`EnglandAndWales`]),
+/// with these differences: January 2 is a bank holiday, Easter Monday is not, the summer bank
+/// holiday is the first (rather than the last) Monday in August, and St Andrew's Day (November
+/// 30) is a bank holiday since 2007. As in England and Wales, bank holidays that fall on a
+/// weekend are replaced by a substitute day.
+///
+/// ## Examples
+///
+/// ```
+/// use date::date;
+/// use date::holiday::uk::Scotland;
+/// use date::holiday::HolidayCalendar;
+///
+/// assert!(Scotland.is_holiday(date! { 2024-01-02 }));
+/// assert!(Scotland.is_holiday(date! { 2024-08-05 })); // Summer bank holiday
+/// assert!(!Scotland.is_holiday(date! { 2024-04-01 })); // Easter Monday
+/// assert!(Scotland.is_holiday(date! { 2024-12-02 })); // St Andrew's Day (substitute)
+/// ```
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct Scotland;
+
+impl Scotland {
+  /// The bank holidays in Scotland in the given year, in chronological order.
+  pub fn holidays(year: i16) -> Vec<Date> {
+    let mut holidays = common(year);
+    holidays.push(Date::new(year, 1, 2));
+    holidays.extend(SUMMER_SCOTLAND.occurrence_in(year));
+    if year >= 2007 {
+      holidays.push(Date::new(year, 11, 30));
+    }
+    substitute(holidays)
+  }
+}
+
+impl HolidayCalendar for Scotland {
+  fn is_holiday(&self, date: Date) -> bool {
+    Self::holidays(date.year()).contains(&date)
+  }
+
+  fn holidays_in(&self, start: Date, end: Date) -> Vec<Date> {
+    holidays_by_year(start, end, Self::holidays)
+  }
+}
+
+/// The bank holiday calendar for Northern Ireland.
+///
+/// Northern Ireland observes the bank holidays of England and Wales (see [`EnglandAndWales`]), as
+/// well as St Patrick's Day (March 17) and the Battle of the Boyne (July 12). As in England and
+/// Wales, bank holidays that fall on a weekend are replaced by a substitute day.
+///
+/// ## Examples
+///
+/// ```
+/// use date::date;
+/// use date::holiday::uk::NorthernIreland;
+/// use date::holiday::HolidayCalendar;
+///
+/// assert!(NorthernIreland.is_holiday(date! { 2025-03-17 })); // St Patrick's Day
+/// assert!(NorthernIreland.is_holiday(date! { 2026-07-13 })); // Battle of the Boyne (substitute)
+/// assert!(NorthernIreland.is_holiday(date! { 2024-04-01 })); // Easter Monday
+/// ```
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct NorthernIreland;
+
+impl NorthernIreland {
+  /// The bank holidays in Northern Ireland in the given year, in chronological order.
+  pub fn holidays(year: i16) -> Vec<Date> {
+    let mut holidays = common(year);
+    holidays.push(Date::easter(year) + DateInterval::new(1));
+    holidays.extend(SUMMER.occurrence_in(year));
+    holidays.push(Date::new(year, 3, 17));
+    holidays.push(Date::new(year, 7, 12));
+    substitute(holidays)
+  }
+}
+
+impl HolidayCalendar for NorthernIreland {
+  fn is_holiday(&self, date: Date) -> bool {
+    Self::holidays(date.year()).contains(&date)
+  }
+
+  fn holidays_in(&self, start: Date, end: Date) -> Vec<Date> {
+    holidays_by_year(start, end, Self::holidays)
+  }
+}
+
+/// The bank holidays observed throughout the United Kingdom in the given year, before weekend
+/// holidays are substituted.
+fn common(year: i16) -> Vec<Date> {
+  let mut holidays = vec![
+    Date::new(year, 1, 1),
+    Date::easter(year) - DateInterval::new(2),
+    Date::new(year, 12, 25),
+    Date::new(year, 12, 26),
+  ];
+
+  // The early May bank holiday was moved to mark the anniversary of VE Day in 1995 and 2020.
+  if year >= 1978 {
+    holidays.extend(match year {
+      1995 | 2020 => Some(Date::new(year, 5, 8)),
+      _ => EARLY_MAY.occurrence_in(year),
+    });
+  }
+
+  // The spring bank holiday was moved to accommodate the jubilees of Elizabeth II.
+  holidays.extend(match year {
+    2002 | 2012 => Some(Date::new(year, 6, 4)),
+    2022 => Some(Date::new(year, 6, 2)),
+    _ => SPRING.occurrence_in(year),
+  });
+
+  // One-off bank holidays.
+  holidays.extend(ONE_OFF.iter().filter(|d| d.year() == year));
+  holidays
+}
+
+const EARLY_MAY: NthWeekdayRule = NthWeekdayRule::new(1, Weekday::Monday, 5);
+const SPRING: NthWeekdayRule = NthWeekdayRule::last(Weekday::Monday, 5);
+const SUMMER: NthWeekdayRule = NthWeekdayRule::last(Weekday::Monday, 8);
+const SUMMER_SCOTLAND: NthWeekdayRule = NthWeekdayRule::new(1, Weekday::Monday, 8);
+
+/// Bank holidays proclaimed for a single year.
+const ONE_OFF: [Date; 7] = [
+  date! { 1999-12-31 }, // Millennium celebrations
+  date! { 2002-06-03 }, // Golden Jubilee of Elizabeth II
+  date! { 2011-04-29 }, // Wedding of Prince William and Catherine Middleton
+  date! { 2012-06-05 }, // Diamond Jubilee of Elizabeth II
+  date! { 2022-06-03 }, // Platinum Jubilee of Elizabeth II
+  date! { 2022-09-19 }, // State funeral of Elizabeth II
+  date! { 2023-05-08 }, // Coronation of Charles III
+];
+
+#[cfg(test)]
+mod tests {
+  use assert2::check;
+
+  use super::*;
+
+  #[test]
+  fn test_holidays() {
+    check!(
+      EnglandAndWales::holidays(2024)
+        == vec![
+          date! { 2024-01-01 },
+          date! { 2024-03-29 },
+          date! { 2024-04-01 },
+          date! { 2024-05-06 },
+          date! { 2024-05-27 },
+          date! { 2024-08-26 },
+          date! { 2024-12-25 },
+          date! { 2024-12-26 },
+        ]
+    );
+    check!(
+      EnglandAndWales::holidays(2022)
+        == vec![
+          date! { 2022-01-03 },
+          date! { 2022-04-15 },
+          date! { 2022-04-18 },
+          date! { 2022-05-02 },
+          date! { 2022-06-02 },
+          date! { 2022-06-03 },
+          date! { 2022-08-29 },
+          date! { 2022-09-19 },
+          date! { 2022-12-26 },
+          date! { 2022-12-27 },
+        ]
+    );
+    check!(EnglandAndWales::holidays(2020).contains(&date! { 2020-05-08 }));
+    check!(!EnglandAndWales::holidays(2020).contains(&date! { 2020-05-04 }));
+  }
+
+  #[test]
+  fn test_scotland() {
+    check!(
+      Scotland::holidays(2024)
+        == vec![
+          date! { 2024-01-01 },
+          date! { 2024-01-02 },
+          date! { 2024-03-29 },
+          date! { 2024-05-06 },
+          date! { 2024-05-27 },
+          date! { 2024-08-05 },
+          date! { 2024-12-02 },
+          date! { 2024-12-25 },
+          date! { 2024-12-26 },
+        ]
+    );
+    // New Year's Day on Sunday, followed by January 2.
+    check!(Scotland::holidays(2023)[..2] == [date! { 2023-01-02 }, date! { 2023-01-03 }]);
+    check!(Scotland::holidays(2023).contains(&date! { 2023-05-08 }));
+    check!(!Scotland::holidays(2006).contains(&date! { 2006-11-30 }));
+  }
+
+  #[test]
+  fn test_northern_ireland() {
+    check!(
+      NorthernIreland::holidays(2024)
+        == vec![
+          date! { 2024-01-01 },
+          date! { 2024-03-18 },
+          date! { 2024-03-29 },
+          date! { 2024-04-01 },
+          date! { 2024-05-06 },
+          date! { 2024-05-27 },
+          date! { 2024-07-12 },
+          date! { 2024-08-26 },
+          date! { 2024-12-25 },
+          date! { 2024-12-26 },
+        ]
+    );
+    // St Patrick's Day fell on a Sunday in 2024.
+    check!(NorthernIreland.is_holiday(date! { 2024-03-18 }));
+    check!(!NorthernIreland.is_holiday(date! { 2024-03-17 }));
+  }
+
+  #[test]
+  fn test_is_holiday() {
+    check!(EnglandAndWales.is_holiday(date! { 2021-12-27 }));
+    check!(EnglandAndWales.is_holiday(date! { 2021-12-28 }));
+    check!(!EnglandAndWales.is_holiday(date! { 2021-12-25 }));
+    check!(!EnglandAndWales.is_holiday(date! { 2024-07-04 }));
+  }
+}
@@ -0,0 +1,155 @@
+//! United States federal holidays.
+
+use alloc::vec::Vec;
+
+use super::holidays_by_year;
+use super::observed;
+use super::HolidayCalendar;
+use crate::Date;
+use crate::NthWeekdayRule;
+use crate::Weekday;
+
+/// The United States federal holiday calendar, as established by 5 U.S.C. § 6103.
+///
+/// Holidays that fall on a Saturday are observed on the preceding Friday, and holidays that fall
+/// on a Sunday are observed on the following Monday. This calendar reports the _observed_ dates,
+/// which are the dates on which federal offices (and most banks) are closed. Notably, this means
+/// that when New Year's Day falls on a Saturday, the holiday is observed on December 31 of the
+/// prior year.
+///
+/// Holidays are only reported from the year they were established: Birthday of Martin Luther
+/// King, Jr. from 1986 and Juneteenth from 2021. The Monday-holiday rules of the Uniform Monday
+/// Holiday Act are applied to all years.
+///
+/// ## Examples
+///
+/// ```
+/// use date::date;
+/// use date::holiday::us::UsFederal;
+/// use date::holiday::HolidayCalendar;
+///
+/// assert!(UsFederal.is_holiday(date! { 2024-11-28 })); // Thanksgiving
+/// assert!(UsFederal.is_holiday(date! { 2021-12-24 })); // Christmas (observed)
+/// assert!(!UsFederal.is_holiday(date! { 2021-12-25 }));
+/// ```
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct UsFederal;
+
+impl UsFederal {
+  /// The observed federal holidays that fall in the given year, in chronological order.
+  pub fn holidays(year: i16) -> Vec<Date> {
+    let mut answer: Vec<Date> = Self::actual(year)
+      .into_iter()
+      .flatten()
+      .map(observed)
+      .filter(|d| d.year() == year)
+      .collect();
+    if let Some(next_year) = year.checked_add(1) {
+      let next_new_year = observed(Date::new(next_year, 1, 1));
+      if next_new_year.year() == year {
+        answer.push(next_new_year);
+      }
+    }
+    answer
+  }
+
+  /// The actual (not observed) dates of each federal holiday in the given year.
+  const fn actual(year: i16) -> [Option<Date>; 11] {
+    [
+      Some(Date::new(year, 1, 1)),
+      match year >= 1986 {
+        true => MLK_DAY.occurrence_in(year),
+        false => None,
+      },
+      WASHINGTONS_BIRTHDAY.occurrence_in(year),
+      MEMORIAL_DAY.occurrence_in(year),
+      match year >= 2021 {
+        true => Some(Date::new(year, 6, 19)),
+        false => None,
+      },
+      Some(Date::new(year, 7, 4)),
+      LABOR_DAY.occurrence_in(year),
+      COLUMBUS_DAY.occurrence_in(year),
+      match year >= 1971 && year <= 1977 {
+        true => NthWeekdayRule::new(4, Weekday::Monday, 10).occurrence_in(year),
+        false => Some(Date::new(year, 11, 11)),
+      },
+      THANKSGIVING.occurrence_in(year),
+      Some(Date::new(year, 12, 25)),
+    ]
+  }
+}
+
+pub(crate) const MLK_DAY: NthWeekdayRule = NthWeekdayRule::new(3, Weekday::Monday, 1);
+pub(crate) const WASHINGTONS_BIRTHDAY: NthWeekdayRule = NthWeekdayRule::new(3, Weekday::Monday, 2);
+pub(crate) const MEMORIAL_DAY: NthWeekdayRule = NthWeekdayRule::last(Weekday::Monday, 5);
+pub(crate) const LABOR_DAY: NthWeekdayRule = NthWeekdayRule::new(1, Weekday::Monday, 9);
+pub(crate) const COLUMBUS_DAY: NthWeekdayRule = NthWeekdayRule::new(2, Weekday::Monday, 10);
+pub(crate) const THANKSGIVING: NthWeekdayRule = NthWeekdayRule::new(4, Weekday::Thursday, 11);
+
+impl HolidayCalendar for UsFederal {
+  fn is_holiday(&self, date: Date) -> bool {
+    match date.weekday() {
+      Weekday::Saturday | Weekday::Sunday => false,
+      _ => Self::holidays(date.year()).contains(&date),
+    }
+  }
+
+  fn holidays_in(&self, start: Date, end: Date) -> Vec<Date> {
+    holidays_by_year(start, end, Self::holidays)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use assert2::check;
+
+  use super::*;
+
+  #[test]
+  fn test_holidays() {
+    check!(
+      UsFederal::holidays(2021)
+        == vec![
+          date! { 2021-01-01 },
+          date! { 2021-01-18 },
+          date! { 2021-02-15 },
+          date! { 2021-05-31 },
+          date! { 2021-06-18 },
+          date! { 2021-07-05 },
+          date! { 2021-09-06 },
+          date! { 2021-10-11 },
+          date! { 2021-11-11 },
+          date! { 2021-11-25 },
+          date! { 2021-12-24 },
+          date! { 2021-12-31 },
+        ]
+    );
+    check!(UsFederal::holidays(2022)[0] == date! { 2022-01-17 });
+    check!(UsFederal::holidays(2022).len() == 10);
+    check!(UsFederal::holidays(1985).len() == 9);
+    check!(UsFederal::holidays(1975).contains(&date! { 1975-10-27 }));
+  }
+
+  #[test]
+  fn test_is_holiday() {
+    check!(UsFederal.is_holiday(date! { 2024-01-01 }));
+    check!(UsFederal.is_holiday(date! { 2024-05-27 }));
+    check!(UsFederal.is_holiday(date! { 2024-07-04 }));
+    check!(!UsFederal.is_holiday(date! { 2024-07-05 }));
+    check!(!UsFederal.is_holiday(date! { 2022-01-01 }));
+    check!(UsFederal.is_holiday(date! { 2023-01-02 }));
+    check!(
+      UsFederal.holidays_in(date! { 2024-11-01 }, date! { 2024-12-31 })
+        == vec![date! { 2024-11-11 }, date! { 2024-11-28 }, date! { 2024-12-25 }]
+    );
+  }
+
+  #[test]
+  fn test_year_bounds() {
+    check!(UsFederal::holidays(32767).len() == 11);
+    check!(UsFederal::holidays(-32768).iter().all(|d| d.year() == -32768));
+    check!(!UsFederal.is_holiday(Date::MAX));
+    check!(UsFederal.is_holiday(Date::MIN) == Date::MIN.is_weekday());
+  }
+}
@@ -0,0 +1,164 @@
+#[cfg(feature = "tz")]
+use crate::tz;
+use crate::Date;
+
+impl Date {
+  /// The date (in UTC) on which the given UUIDv7 was generated, or `None` if the bytes are not a
+  /// version 7 UUID.
+  ///
+  /// UUIDv7 (RFC 9562) stores the number of milliseconds since the Unix epoch in its first 48
+  /// bits. The UUID is provided as its 16 bytes in network order (for example, from
+  /// `Uuid::as_bytes` in the `uuid` crate).
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::date;
+  /// use date::Date;
+  ///
+  /// // 01965838-3200-7000-8000-000000000000
+  /// let uuid = [0x01, 0x96, 0x58, 0x38, 0x32, 0, 0x70, 0, 0x80, 0, 0, 0, 0, 0, 0, 0];
+  /// assert_eq!(Date::from_uuid_v7(&uuid), Some(date! { 2025-04-21 }));
+  /// ```
+  pub const fn from_uuid_v7(uuid: &[u8; 16]) -> Option<Self> {
+    match uuid_v7_timestamp(uuid) {
+      Some(ms) => Some(Self::from_timestamp(ms.div_euclid(1_000))),
+      None => None,
+    }
+  }
+
+  /// The date in the provided time zone on which the given UUIDv7 was generated, or `None` if the
+  /// bytes are not a version 7 UUID or the time zone lacks information for that time.
+  ///
+  /// See [`Date::from_uuid_v7`] for details.
+  #[cfg(feature = "tz")]
+  pub fn from_uuid_v7_tz(uuid: &[u8; 16], tz: tz::TimeZoneRef<'static>) -> Option<Self> {
+    Self::from_timestamp_tz(uuid_v7_timestamp(uuid)?.div_euclid(1_000), tz).ok()
+  }
+
+  /// The date (in UTC) on which the given ULID was generated, or `None` if the string is not a
+  /// valid ULID.
+  ///
+  /// A ULID is 26 characters of Crockford's base 32, the first ten of which encode the number of
+  /// milliseconds since the Unix epoch. Letters are accepted in either case.
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::date;
+  /// use date::Date;
+  ///
+  /// assert_eq!(Date::from_ulid("01JSC3GCG0ZZZZZZZZZZZZZZZZ"), Some(date! { 2025-04-21 }));
+  /// assert_eq!(Date::from_ulid("not a ulid"), None);
+  /// ```
+  pub const fn from_ulid(ulid: &str) -> Option<Self> {
+    match ulid_timestamp(ulid) {
+      Some(ms) => Some(Self::from_timestamp(ms.div_euclid(1_000))),
+      None => None,
+    }
+  }
+
+  /// The date in the provided time zone on which the given ULID was generated, or `None` if the
+  /// string is not a valid ULID or the time zone lacks information for that time.
+  ///
+  /// See [`Date::from_ulid`] for details.
+  #[cfg(feature = "tz")]
+  pub fn from_ulid_tz(ulid: &str, tz: tz::TimeZoneRef<'static>) -> Option<Self> {
+    Self::from_timestamp_tz(ulid_timestamp(ulid)?.div_euclid(1_000), tz).ok()
+  }
+}
+
+/// The Unix timestamp, in milliseconds, embedded in the given UUIDv7.
+const fn uuid_v7_timestamp(uuid: &[u8; 16]) -> Option<i64> {
+  if uuid[6] >> 4 != 7 || uuid[8] >> 6 != 0b10 {
+    return None;
+  }
+  let mut ms = 0;
+  let mut i = 0;
+  while i < 6 {
+    ms = ms << 8 | uuid[i] as i64;
+    i += 1;
+  }
+  Some(ms)
+}
+
+/// The Unix timestamp, in milliseconds, embedded in the given ULID.
+const fn ulid_timestamp(ulid: &str) -> Option<i64> {
+  let bytes = ulid.as_bytes();
+  if bytes.len() != 26 {
+    return None;
+  }
+  let mut value: u128 = 0;
+  let mut i = 0;
+  while i < 26 {
+    let digit = match bytes[i].to_ascii_uppercase() {
+      b @ b'0'..=b'9' => b - b'0',
+      b @ b'A'..=b'H' => b - b'A' + 10,
+      b'J' => 18,
+      b'K' => 19,
+      b'M' => 20,
+      b'N' => 21,
+      b @ b'P'..=b'T' => b - b'P' + 22,
+      b @ b'V'..=b'Z' => b - b'V' + 27,
+      _ => return None,
+    };
+    // The first character encodes only three bits, so the value fits in 128 bits.
+    if i == 0 && digit > 7 {
+      return None;
+    }
+    value = value << 5 | digit as u128;
+    i += 1;
+  }
+  Some((value >> 80) as i64)
+}
+
+#[cfg(test)]
+mod tests {
+  use assert2::check;
+
+  use super::*;
+
+  #[test]
+  fn test_uuid_v7() {
+    let mut uuid = [0, 0, 0, 0, 0, 0, 0x70, 0, 0x80, 0, 0, 0, 0, 0, 0, 0];
+    check!(Date::from_uuid_v7(&uuid) == Some(date! { 1970-01-01 }));
+    uuid[..6].copy_from_slice(&1_334_966_400_000_i64.to_be_bytes()[2..]);
+    check!(Date::from_uuid_v7(&uuid) == Some(date! { 2012-04-21 }));
+    uuid[..6].copy_from_slice(&1_334_966_399_999_i64.to_be_bytes()[2..]);
+    check!(Date::from_uuid_v7(&uuid) == Some(date! { 2012-04-20 }));
+    uuid[..6].copy_from_slice(&[0xff; 6]);
+    check!(Date::from_uuid_v7(&uuid) == Some(date! { 10889-08-02 }));
+
+    // Other versions and variants are rejected.
+    uuid[6] = 0x40;
+    check!(Date::from_uuid_v7(&uuid) == None);
+    uuid[6] = 0x70;
+    uuid[8] = 0xc0;
+    check!(Date::from_uuid_v7(&uuid) == None);
+  }
+
+  #[test]
+  fn test_ulid() {
+    check!(Date::from_ulid("00000000000000000000000000") == Some(date! { 1970-01-01 }));
+    check!(Date::from_ulid("01JSC3GCG0ZZZZZZZZZZZZZZZZ") == Some(date! { 2025-04-21 }));
+    check!(Date::from_ulid("01jsc3gcg0zzzzzzzzzzzzzzzz") == Some(date! { 2025-04-21 }));
+    check!(Date::from_ulid("7ZZZZZZZZZZZZZZZZZZZZZZZZZ") == Some(date! { 10889-08-02 }));
+    check!(Date::from_ulid("8ZZZZZZZZZZZZZZZZZZZZZZZZZ") == None);
+    check!(Date::from_ulid("01JSC3GCG0ZZZZZZZZZZZZZZZU") == None);
+    check!(Date::from_ulid("01JSC3GCG0ZZZZZZZZZZZZZZZ") == None);
+    check!(Date::from_ulid("") == None);
+  }
+
+  #[cfg(feature = "tz")]
+  #[test]
+  fn test_tz() {
+    // 2012-04-21 00:00:00 UTC, which was still April 20 in New York.
+    let mut uuid = [0, 0, 0, 0, 0, 0, 0x70, 0, 0x80, 0, 0, 0, 0, 0, 0, 0];
+    uuid[..6].copy_from_slice(&1_334_966_400_000_i64.to_be_bytes()[2..]);
+    check!(Date::from_uuid_v7_tz(&uuid, tz::us::EASTERN) == Some(date! { 2012-04-20 }));
+    check!(Date::from_uuid_v7_tz(&uuid, tz::asia::TOKYO) == Some(date! { 2012-04-21 }));
+    let ulid = "01JSC3GCG0ZZZZZZZZZZZZZZZZ";
+    check!(Date::from_ulid_tz(ulid, tz::asia::TOKYO) == Some(date! { 2025-04-21 }));
+    check!(Date::from_ulid_tz("bogus", tz::asia::TOKYO) == None);
+  }
+}
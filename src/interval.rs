@@ -2,14 +2,20 @@
 //!
 //! In addition, dates can be subtracted from one another, and the result is a [`DateInterval`].
 
-use std::ops::Add;
-use std::ops::AddAssign;
-use std::ops::Neg;
-use std::ops::Sub;
-use std::ops::SubAssign;
+use core::fmt;
+use core::iter::Sum;
+use core::ops::Add;
+use core::ops::AddAssign;
+use core::ops::Div;
+use core::ops::Mul;
+use core::ops::Neg;
+use core::ops::Sub;
+use core::ops::SubAssign;
+use core::str::FromStr;
 
 use crate::utils;
 use crate::Date;
+use crate::Feb29Policy;
 
 /// An interval of days.
 ///
@@ -28,6 +34,12 @@ impl DateInterval {
     Self { days }
   }
 
+  /// A representation of a given number of weeks.
+  #[inline]
+  pub const fn weeks(weeks: i32) -> Self {
+    Self { days: weeks * 7 }
+  }
+
   /// The number of days this interval represents.
   pub const fn days(&self) -> i32 {
     self.days
@@ -37,6 +49,367 @@ impl DateInterval {
   pub const fn abs(self) -> Self {
     Self { days: self.days.abs() }
   }
+
+  /// Parse a human-readable interval, such as `3 days`, `1 week`, or `2w`.
+  ///
+  /// The string is an integer (optionally negative), optional whitespace, and a unit: `d`,
+  /// `day`, or `days`, or `w`, `wk`, `week`, or `weeks`, in any case. Intervals that depend on a
+  /// calendar, such as business days or months, are rejected.
+  ///
+  /// This is intended for user-supplied offsets, such as command-line arguments; use
+  /// [`FromStr`] to parse ISO 8601 durations.
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::interval::DateInterval;
+  ///
+  /// assert_eq!(DateInterval::parse_human("3 days"), Ok(DateInterval::new(3)));
+  /// assert_eq!(DateInterval::parse_human("2w"), Ok(DateInterval::new(14)));
+  /// assert_eq!(DateInterval::parse_human("-1 Day"), Ok(DateInterval::new(-1)));
+  /// assert!(DateInterval::parse_human("10 business days").is_err());
+  /// ```
+  pub fn parse_human(s: &str) -> Result<Self, ParseDurationError> {
+    let s = s.trim();
+    let end = s.find(|c: char| !c.is_ascii_digit() && c != '-' && c != '+').unwrap_or(s.len());
+    let (number, unit) = s.split_at(end);
+    let number: i32 = number.parse().map_err(|_| ParseDurationError)?;
+    let is_unit = |names: &[&str]| names.iter().any(|n| unit.trim_start().eq_ignore_ascii_case(n));
+    let days_per_unit = if is_unit(&["d", "day", "days"]) {
+      1
+    } else if is_unit(&["w", "wk", "week", "weeks"]) {
+      7
+    } else {
+      return Err(ParseDurationError);
+    };
+    number.checked_mul(days_per_unit).map(Self::new).ok_or(ParseDurationError)
+  }
+
+  /// A human-readable representation of this interval, such as `3 days` or `1 day`.
+  ///
+  /// The result can be read back with [`DateInterval::parse_human`].
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::days;
+  ///
+  /// assert_eq!(days!(3).humanize().to_string(), "3 days");
+  /// assert_eq!(days!(-1).humanize().to_string(), "-1 day");
+  /// ```
+  pub fn humanize(&self) -> impl fmt::Display {
+    struct Humanized(i32);
+
+    impl fmt::Display for Humanized {
+      fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0.unsigned_abs() {
+          1 => write!(f, "{} day", self.0),
+          _ => write!(f, "{} days", self.0),
+        }
+      }
+    }
+
+    Humanized(self.days)
+  }
+}
+
+impl Date {
+  /// The date that is the given interval later (or earlier, if the interval is negative).
+  ///
+  /// This is equivalent to `date + interval`, but can be used in `const` contexts.
+  ///
+  /// ## Panic
+  ///
+  /// This function panics if the result would be outside the range of [`Date::MIN`] to
+  /// [`Date::MAX`]. Use [`Date::checked_add_days`] to handle this case.
+  #[inline]
+  pub const fn add_days(self, interval: DateInterval) -> Date {
+    match self.checked_add_days(interval) {
+      Some(date) => date,
+      None => panic!("Date out-of-bounds"),
+    }
+  }
+
+  /// The date that is the given interval earlier (or later, if the interval is negative).
+  ///
+  /// This is equivalent to `date - interval`, but can be used in `const` contexts.
+  ///
+  /// ## Panic
+  ///
+  /// This function panics if the result would be outside the range of [`Date::MIN`] to
+  /// [`Date::MAX`]. Use [`Date::checked_sub_days`] to handle this case.
+  #[inline]
+  pub const fn sub_days(self, interval: DateInterval) -> Date {
+    match self.checked_sub_days(interval) {
+      Some(date) => date,
+      None => panic!("Date out-of-bounds"),
+    }
+  }
+
+  /// The interval between the given date and this one.
+  ///
+  /// This is equivalent to `self - other`, but can be used in `const` contexts.
+  #[inline]
+  pub const fn days_since(self, other: Date) -> DateInterval {
+    DateInterval::new(self.0 - other.0)
+  }
+
+  /// The difference between the given date and this one, in years, months, and days.
+  ///
+  /// The result is the number of whole months that can be added to `other` without passing this
+  /// date, followed by the remaining days; adding the years and months of the result to `other`
+  /// and then adding the days produces this date. If `other` is after this date, every component
+  /// is zero or negative.
+  ///
+  /// Month arithmetic follows the same end-of-month rule as [`MonthInterval`]: a day that does
+  /// not exist in the result month becomes the last day of that month. Therefore, the difference
+  /// between `2023-01-31` and `2023-02-28` is one month, and the difference between `2023-01-31`
+  /// and `2023-03-01` is one month and one day.
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::date;
+  ///
+  /// let diff = date! { 2024-08-20 }.difference(date! { 2022-05-15 });
+  /// assert_eq!((diff.years(), diff.months(), diff.days()), (2, 3, 5));
+  ///
+  /// let diff = date! { 2022-05-15 }.difference(date! { 2024-08-20 });
+  /// assert_eq!((diff.years(), diff.months(), diff.days()), (-2, -3, -5));
+  /// ```
+  pub const fn difference(self, other: Date) -> DateDifference {
+    let (year, month, _) = self.ymd();
+    let (other_year, other_month, _) = other.ymd();
+    let mut months = (year as i32 - other_year as i32) * 12 + month as i32 - other_month as i32;
+    let mut anchor = month_offset(other, months);
+    if months > 0 && anchor.0 > self.0 {
+      months -= 1;
+      anchor = month_offset(other, months);
+    } else if months < 0 && anchor.0 < self.0 {
+      months += 1;
+      anchor = month_offset(other, months);
+    }
+    DateDifference { years: months / 12, months: months % 12, days: self.0 - anchor.0 }
+  }
+
+  /// The date that is the given number of months later (or earlier, if the interval is
+  /// negative).
+  ///
+  /// This is equivalent to `date + interval`, but can be used in `const` contexts.
+  ///
+  /// ## Panic
+  ///
+  /// This function panics if the result would be outside the range of [`Date::MIN`] to
+  /// [`Date::MAX`]. Use [`Date::checked_add_months`] to handle this case.
+  pub const fn add_months(self, interval: MonthInterval) -> Date {
+    month_offset(self, interval.months)
+  }
+
+  /// The date that is the given number of months earlier (or later, if the interval is
+  /// negative).
+  ///
+  /// This is equivalent to `date - interval`, but can be used in `const` contexts.
+  ///
+  /// ## Panic
+  ///
+  /// This function panics if the result would be outside the range of [`Date::MIN`] to
+  /// [`Date::MAX`]. Use [`Date::checked_sub_months`] to handle this case.
+  pub const fn sub_months(self, interval: MonthInterval) -> Date {
+    month_offset(self, -interval.months)
+  }
+
+  /// The date that is the given number of years later (or earlier, if `years` is negative).
+  ///
+  /// If this date is February 29 and the result year is not a leap year, the provided policy
+  /// determines the result.
+  ///
+  /// Returns `None` if the result would be outside the range of [`Date::MIN`] to [`Date::MAX`],
+  /// or if this date is February 29, the result year is not a leap year, and the policy is
+  /// [`Feb29Policy::LeapYearsOnly`].
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::date;
+  /// use date::Feb29Policy;
+  ///
+  /// let date = date! { 2024-02-29 };
+  /// assert_eq!(date.add_years(4, Feb29Policy::Feb28), Some(date! { 2028-02-29 }));
+  /// assert_eq!(date.add_years(1, Feb29Policy::Feb28), Some(date! { 2025-02-28 }));
+  /// assert_eq!(date.add_years(1, Feb29Policy::Mar1), Some(date! { 2025-03-01 }));
+  /// assert_eq!(date.add_years(1, Feb29Policy::LeapYearsOnly), None);
+  /// assert_eq!(date.sub_years(24, Feb29Policy::Feb28), Some(date! { 2000-02-29 }));
+  /// ```
+  pub const fn add_years(self, years: i32, policy: Feb29Policy) -> Option<Date> {
+    let (year, month, day) = self.ymd();
+    let year = year as i64 + years as i64;
+    if year < i16::MIN as i64 || year > i16::MAX as i64 {
+      return None;
+    }
+    policy.resolve(year as i16, month, day)
+  }
+
+  /// The date that is the given number of years earlier (or later, if `years` is negative).
+  ///
+  /// See [`Date::add_years`] for details.
+  pub const fn sub_years(self, years: i32, policy: Feb29Policy) -> Option<Date> {
+    match years.checked_neg() {
+      Some(years) => self.add_years(years, policy),
+      None => None,
+    }
+  }
+
+  /// The date that is the given number of months later (or earlier, if the interval is
+  /// negative), keeping dates at the end of a month at the end of the month.
+  ///
+  /// This follows the end-of-month convention common in financial calculations: if this date is
+  /// the last day of its month, the result is the last day of the result month. Otherwise, this
+  /// behaves like [`Date::add_months`]. Therefore, repeatedly adding one month to `2021-01-31`
+  /// produces `2021-02-28`, `2021-03-31`, `2021-04-30`, and so on, rather than remaining on the
+  /// 28th.
+  ///
+  /// ## Panic
+  ///
+  /// This function panics if the result would be outside the range of [`Date::MIN`] to
+  /// [`Date::MAX`]. Use [`Date::checked_add_months_eom`] to handle this case.
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::date;
+  /// use date::months;
+  ///
+  /// let date = date! { 2021-01-31 }.add_months_eom(months!(1));
+  /// assert_eq!(date, date! { 2021-02-28 });
+  /// assert_eq!(date.add_months_eom(months!(1)), date! { 2021-03-31 });
+  /// assert_eq!(date! { 2021-01-30 }.add_months_eom(months!(1)), date! { 2021-02-28 });
+  /// assert_eq!(date! { 2021-04-30 }.add_months_eom(months!(1)), date! { 2021-05-31 });
+  /// ```
+  pub const fn add_months_eom(self, interval: MonthInterval) -> Date {
+    match self.checked_add_months_eom(interval) {
+      Some(date) => date,
+      None => panic!("Date out-of-bounds"),
+    }
+  }
+
+  /// The date that is the given number of months later (or earlier, if the interval is
+  /// negative), keeping dates at the end of a month at the end of the month, or `None` if the
+  /// result would be outside the range of [`Date::MIN`] to [`Date::MAX`].
+  ///
+  /// See [`Date::add_months_eom`] for details.
+  pub const fn checked_add_months_eom(self, interval: MonthInterval) -> Option<Date> {
+    let date = match checked_month_offset(self, interval.months) {
+      Some(date) => date,
+      None => return None,
+    };
+    match self.0 == self.last_of_month().0 {
+      true => Some(date.last_of_month()),
+      false => Some(date),
+    }
+  }
+
+  /// The date that is the given interval later (or earlier, if the interval is negative), or
+  /// `None` if the result would be outside the range of [`Date::MIN`] to [`Date::MAX`].
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::date;
+  /// use date::days;
+  /// use date::Date;
+  ///
+  /// assert_eq!(date! { 2012-04-21 }.checked_add_days(days!(10)), Some(date! { 2012-05-01 }));
+  /// assert_eq!(Date::MAX.checked_add_days(days!(1)), None);
+  /// ```
+  pub const fn checked_add_days(self, interval: DateInterval) -> Option<Date> {
+    match self.0.checked_add(interval.days) {
+      Some(days) if days >= Date::MIN.0 && days <= Date::MAX.0 => Some(Date(days)),
+      _ => None,
+    }
+  }
+
+  /// The date that is the given interval earlier (or later, if the interval is negative), or
+  /// `None` if the result would be outside the range of [`Date::MIN`] to [`Date::MAX`].
+  pub const fn checked_sub_days(self, interval: DateInterval) -> Option<Date> {
+    match self.0.checked_sub(interval.days) {
+      Some(days) if days >= Date::MIN.0 && days <= Date::MAX.0 => Some(Date(days)),
+      _ => None,
+    }
+  }
+
+  /// The date that is the given number of months later (or earlier, if the interval is
+  /// negative), or `None` if the result would be outside the range of [`Date::MIN`] to
+  /// [`Date::MAX`].
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::date;
+  /// use date::months;
+  ///
+  /// assert_eq!(date! { 2012-04-21 }.checked_add_months(months!(3)), Some(date! { 2012-07-21 }));
+  /// assert_eq!(date! { 32767-11-30 }.checked_add_months(months!(3)), None);
+  /// ```
+  pub const fn checked_add_months(self, interval: MonthInterval) -> Option<Date> {
+    checked_month_offset(self, interval.months)
+  }
+
+  /// The date that is the given number of months earlier (or later, if the interval is negative),
+  /// or `None` if the result would be outside the range of [`Date::MIN`] to [`Date::MAX`].
+  pub const fn checked_sub_months(self, interval: MonthInterval) -> Option<Date> {
+    checked_month_offset(self, -interval.months)
+  }
+
+  /// The date that is the given interval later (or earlier, if the interval is negative), clamped
+  /// to the range of [`Date::MIN`] to [`Date::MAX`].
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::date;
+  /// use date::days;
+  /// use date::Date;
+  ///
+  /// assert_eq!(date! { 2012-04-21 }.saturating_add_days(days!(10)), date! { 2012-05-01 });
+  /// assert_eq!(Date::MAX.saturating_add_days(days!(1)), Date::MAX);
+  /// ```
+  pub const fn saturating_add_days(self, interval: DateInterval) -> Date {
+    match self.checked_add_days(interval) {
+      Some(date) => date,
+      None if interval.days > 0 => Date::MAX,
+      None => Date::MIN,
+    }
+  }
+
+  /// The date that is the given interval earlier (or later, if the interval is negative), clamped
+  /// to the range of [`Date::MIN`] to [`Date::MAX`].
+  pub const fn saturating_sub_days(self, interval: DateInterval) -> Date {
+    match self.checked_sub_days(interval) {
+      Some(date) => date,
+      None if interval.days > 0 => Date::MIN,
+      None => Date::MAX,
+    }
+  }
+
+  /// The date that is the given number of months later (or earlier, if the interval is
+  /// negative), clamped to the range of [`Date::MIN`] to [`Date::MAX`].
+  pub const fn saturating_add_months(self, interval: MonthInterval) -> Date {
+    match self.checked_add_months(interval) {
+      Some(date) => date,
+      None if interval.months > 0 => Date::MAX,
+      None => Date::MIN,
+    }
+  }
+
+  /// The date that is the given number of months earlier (or later, if the interval is
+  /// negative), clamped to the range of [`Date::MIN`] to [`Date::MAX`].
+  pub const fn saturating_sub_months(self, interval: MonthInterval) -> Date {
+    match self.checked_sub_months(interval) {
+      Some(date) => date,
+      None if interval.months > 0 => Date::MIN,
+      None => Date::MAX,
+    }
+  }
 }
 
 impl Neg for DateInterval {
@@ -47,18 +420,88 @@ impl Neg for DateInterval {
   }
 }
 
+impl fmt::Display for DateInterval {
+  /// Write the interval as an ISO 8601 duration, such as `P3D` or `-P3D`.
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self.days < 0 {
+      true => write!(f, "-P{}D", self.days.unsigned_abs()),
+      false => write!(f, "P{}D", self.days),
+    }
+  }
+}
+
+impl FromStr for DateInterval {
+  type Err = ParseDurationError;
+
+  /// Parse an ISO 8601 duration of weeks and days, such as `P3D`, `P2W`, or `-P1W2D`.
+  ///
+  /// Durations with years or months are rejected, because they do not represent a fixed number
+  /// of days; parse them as a [`CalendarDuration`] instead.
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    match parse_iso_duration(s)? {
+      (0, 0, days) => Ok(Self { days }),
+      _ => Err(ParseDurationError),
+    }
+  }
+}
+
+impl Add for DateInterval {
+  type Output = Self;
+
+  fn add(self, rhs: Self) -> Self::Output {
+    Self { days: self.days + rhs.days }
+  }
+}
+
+impl Sub for DateInterval {
+  type Output = Self;
+
+  fn sub(self, rhs: Self) -> Self::Output {
+    Self { days: self.days - rhs.days }
+  }
+}
+
+impl Mul<i32> for DateInterval {
+  type Output = Self;
+
+  fn mul(self, rhs: i32) -> Self::Output {
+    Self { days: self.days * rhs }
+  }
+}
+
+impl Div<i32> for DateInterval {
+  type Output = Self;
+
+  /// Divide the interval, rounding toward zero.
+  fn div(self, rhs: i32) -> Self::Output {
+    Self { days: self.days / rhs }
+  }
+}
+
+impl Sum for DateInterval {
+  fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+    iter.fold(Self::new(0), |a, b| a + b)
+  }
+}
+
+impl<'a> Sum<&'a DateInterval> for DateInterval {
+  fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+    iter.copied().sum()
+  }
+}
+
 impl Add<DateInterval> for Date {
   type Output = Date;
 
   /// Return a new `Date` that is the given number of days later.
   fn add(self, interval: DateInterval) -> Self::Output {
-    Date(self.0 + interval.days())
+    self.add_days(interval)
   }
 }
 
 impl AddAssign<DateInterval> for Date {
   fn add_assign(&mut self, interval: DateInterval) {
-    self.0 += interval.days();
+    *self = self.add_days(interval);
   }
 }
 
@@ -67,13 +510,13 @@ impl Sub<DateInterval> for Date {
 
   /// Return a new `Date` that is the given number of days earlier.
   fn sub(self, interval: DateInterval) -> Self::Output {
-    Date(self.0 - interval.days())
+    self.sub_days(interval)
   }
 }
 
 impl SubAssign<DateInterval> for Date {
   fn sub_assign(&mut self, interval: DateInterval) {
-    self.0 -= interval.days();
+    *self = self.sub_days(interval);
   }
 }
 
@@ -81,15 +524,56 @@ impl Sub<Date> for Date {
   type Output = DateInterval;
 
   fn sub(self, rhs: Date) -> Self::Output {
-    DateInterval::new(self.0 - rhs.0)
+    self.days_since(rhs)
+  }
+}
+
+/// A calendar-aware difference between two dates, in years, months, and days.
+///
+/// This is returned by [`Date::difference`]. All components share the same sign, the months are
+/// between -11 and 11, and the days are fewer than the number of days in a month.
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct DateDifference {
+  years: i32,
+  months: i32,
+  days: i32,
+}
+
+impl DateDifference {
+  /// The number of whole years.
+  #[inline]
+  pub const fn years(&self) -> i32 {
+    self.years
+  }
+
+  /// The number of whole months, after removing whole years.
+  #[inline]
+  pub const fn months(&self) -> i32 {
+    self.months
+  }
+
+  /// The number of days, after removing whole years and months.
+  #[inline]
+  pub const fn days(&self) -> i32 {
+    self.days
+  }
+
+  /// The total number of whole months (years and months together).
+  #[inline]
+  pub const fn total_months(&self) -> i32 {
+    self.years * 12 + self.months
+  }
+
+  /// Whether the two dates were the same.
+  pub const fn is_zero(&self) -> bool {
+    self.years == 0 && self.months == 0 && self.days == 0
   }
 }
 
 /// An interval of months.
 ///
-/// Unlike [`DateInterval`], this only represents positive numbers of months, because we never
-/// receive this object as a result of subtracting one [`Date`] from another; instead, this
-/// object's sole purpose is to create month intervals to add or subtract from dates.
+/// Like [`DateInterval`], month intervals can be positive or negative, so that an interval that
+/// was computed at runtime can be added to a date without checking its sign first.
 ///
 /// In the event that a month interval is added to a date where the day of the month exceeds the
 /// number of days in the result month, the day is set to the final day of the result month.
@@ -105,31 +589,122 @@ impl Sub<Date> for Date {
 ///
 /// assert_eq!(date! { 2012-04-21 } + MonthInterval::new(3), date! { 2012-07-21 });
 /// assert_eq!(date! { 2021-12-31 } + MonthInterval::new(2), date! { 2022-02-28 });
+/// assert_eq!(date! { 2021-12-31 } + MonthInterval::new(-2), date! { 2021-10-31 });
 /// ```
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 #[repr(transparent)]
 pub struct MonthInterval {
-  months: u8,
+  months: i32,
 }
 
 impl MonthInterval {
-  /// Create a new month interval
-  pub const fn new(months: u8) -> Self {
-    assert!(months <= 255 - 12, "MonthInterval out of bounds.");
+  /// The largest number of months (in either direction) that an interval can represent; this is
+  /// the number of months between [`Date::MIN`] and [`Date::MAX`].
+  pub const MAX_MONTHS: i32 = 65_536 * 12 - 1;
+
+  /// Create a new month interval.
+  ///
+  /// ## Panic
+  ///
+  /// This function panics if the absolute value of the interval is greater than
+  /// [`MonthInterval::MAX_MONTHS`].
+  pub const fn new(months: i32) -> Self {
+    assert!(months.unsigned_abs() <= Self::MAX_MONTHS as u32, "MonthInterval out of bounds.");
     Self { months }
   }
 
+  /// Create a new month interval, or return `None` if the absolute value of the interval is
+  /// greater than [`MonthInterval::MAX_MONTHS`].
+  ///
+  /// This is the non-panicking counterpart to [`MonthInterval::new`].
+  pub const fn checked_new(months: i32) -> Option<Self> {
+    match months.unsigned_abs() <= Self::MAX_MONTHS as u32 {
+      true => Some(Self { months }),
+      false => None,
+    }
+  }
+
+  /// Create a month interval of the given number of years.
+  ///
+  /// ## Panic
+  ///
+  /// This function panics if the interval would be greater than [`MonthInterval::MAX_MONTHS`].
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::date;
+  /// use date::interval::MonthInterval;
+  ///
+  /// assert_eq!(date! { 2012-04-21 } + MonthInterval::years(30), date! { 2042-04-21 });
+  /// assert_eq!(date! { 2024-02-29 } + MonthInterval::years(1), date! { 2025-02-28 });
+  /// ```
+  pub const fn years(years: i32) -> Self {
+    Self::from_years_months(years, 0)
+  }
+
+  /// Create a month interval of the given number of years and months.
+  ///
+  /// The two values are added together, so `from_years_months(1, -1)` is an interval of eleven
+  /// months.
+  ///
+  /// ## Panic
+  ///
+  /// This function panics if the interval would be greater than [`MonthInterval::MAX_MONTHS`].
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::date;
+  /// use date::interval::MonthInterval;
+  ///
+  /// let interval = MonthInterval::from_years_months(2, 3);
+  /// assert_eq!(interval.months(), 27);
+  /// assert_eq!(date! { 2012-04-21 } + interval, date! { 2014-07-21 });
+  /// ```
+  pub const fn from_years_months(years: i32, months: i32) -> Self {
+    match Self::checked_from_years_months(years, months) {
+      Some(interval) => interval,
+      None => panic!("MonthInterval out of bounds."),
+    }
+  }
+
+  /// Create a month interval of the given number of years and months, or return `None` if the
+  /// interval would be greater than [`MonthInterval::MAX_MONTHS`].
+  ///
+  /// This is the non-panicking counterpart to [`MonthInterval::from_years_months`].
+  pub const fn checked_from_years_months(years: i32, months: i32) -> Option<Self> {
+    let months = years as i64 * 12 + months as i64;
+    match months.unsigned_abs() <= Self::MAX_MONTHS as u64 {
+      true => Some(Self { months: months as i32 }),
+      false => None,
+    }
+  }
+
   /// The number of months this interval represents.
-  pub const fn months(&self) -> u8 {
+  pub const fn months(&self) -> i32 {
     self.months
   }
+
+  /// The absolute value of this interval.
+  pub const fn abs(self) -> Self {
+    Self { months: self.months.abs() }
+  }
+}
+
+impl Neg for MonthInterval {
+  type Output = Self;
+
+  fn neg(self) -> Self::Output {
+    Self { months: -self.months }
+  }
 }
 
 impl Add<MonthInterval> for Date {
   type Output = Self;
 
   fn add(self, interval: MonthInterval) -> Self {
-    saturated_date(self.year(), self.month() + interval.months(), self.day())
+    self.add_months(interval)
   }
 }
 
@@ -137,19 +712,362 @@ impl Sub<MonthInterval> for Date {
   type Output = Self;
 
   fn sub(self, interval: MonthInterval) -> Self {
-    let year = self.year() - interval.months().div_ceil(12) as i16;
-    saturated_date(year, self.month() + (12 - interval.months() % 12), self.day())
+    self.sub_months(interval)
+  }
+}
+
+/// A calendar duration of years, months, and days, which can be added to or subtracted from a
+/// date in one operation.
+///
+/// The years and months are applied first, as a single offset in months that follows the same
+/// end-of-month rule as [`MonthInterval`], and the days are applied afterward. Applying the years
+/// and months together means that a [`DateDifference`] converted to a calendar duration always
+/// round-trips: `other + CalendarDuration::from(date.difference(other)) == date`.
+///
+/// Subtracting a duration is the same as adding its negation, except that subtraction also
+/// accepts a component of `i32::MIN`, which cannot be negated.
+///
+/// ## Examples
+///
+/// ```
+/// use date::date;
+/// use date::interval::CalendarDuration;
+///
+/// let duration = CalendarDuration::new(1, 0, 15);
+/// assert_eq!(date! { 2023-06-20 } + duration, date! { 2024-07-05 });
+/// assert_eq!(date! { 2024-07-05 } - duration, date! { 2023-06-20 });
+/// assert_eq!(date! { 2024-01-31 } + CalendarDuration::new(0, 1, 1), date! { 2024-03-01 });
+/// ```
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct CalendarDuration {
+  years: i32,
+  months: i32,
+  days: i32,
+}
+
+impl CalendarDuration {
+  /// A duration of the given number of years, months, and days.
+  ///
+  /// The components may have different signs; for example, `new(0, 1, -1)` is one month later,
+  /// then one day earlier.
+  pub const fn new(years: i32, months: i32, days: i32) -> Self {
+    Self { years, months, days }
+  }
+
+  /// A duration of the given number of years.
+  pub const fn years(years: i32) -> Self {
+    Self::new(years, 0, 0)
+  }
+
+  /// A duration of the given number of months.
+  pub const fn months(months: i32) -> Self {
+    Self::new(0, months, 0)
+  }
+
+  /// A duration of the given number of days.
+  pub const fn days(days: i32) -> Self {
+    Self::new(0, 0, days)
+  }
+
+  /// The years component of this duration.
+  #[inline]
+  pub const fn year_part(&self) -> i32 {
+    self.years
+  }
+
+  /// The months component of this duration.
+  #[inline]
+  pub const fn month_part(&self) -> i32 {
+    self.months
+  }
+
+  /// The days component of this duration.
+  #[inline]
+  pub const fn day_part(&self) -> i32 {
+    self.days
+  }
+
+  /// The negation of this duration, or `None` if any component is `i32::MIN`.
+  ///
+  /// This is the non-panicking counterpart to `-duration`.
+  pub const fn checked_neg(self) -> Option<Self> {
+    match (self.years.checked_neg(), self.months.checked_neg(), self.days.checked_neg()) {
+      (Some(years), Some(months), Some(days)) => Some(Self { years, months, days }),
+      _ => None,
+    }
+  }
+}
+
+impl Date {
+  /// The date that is the given calendar duration later, or `None` if the result would be outside
+  /// the range of [`Date::MIN`] to [`Date::MAX`].
+  ///
+  /// This is the non-panicking counterpart to `date + duration`; see [`CalendarDuration`] for
+  /// details.
+  pub const fn checked_add_duration(self, duration: CalendarDuration) -> Option<Date> {
+    let months = duration.years as i64 * 12 + duration.months as i64;
+    checked_calendar_offset(self, months, duration.days as i64)
+  }
+
+  /// The date that is the given calendar duration earlier, or `None` if the result would be
+  /// outside the range of [`Date::MIN`] to [`Date::MAX`].
+  ///
+  /// This is the non-panicking counterpart to `date - duration`; see [`CalendarDuration`] for
+  /// details.
+  pub const fn checked_sub_duration(self, duration: CalendarDuration) -> Option<Date> {
+    let months = duration.years as i64 * 12 + duration.months as i64;
+    checked_calendar_offset(self, -months, -(duration.days as i64))
+  }
+}
+
+impl fmt::Display for CalendarDuration {
+  /// Write the duration as an ISO 8601 duration, such as `P1Y2M10D`.
+  ///
+  /// A duration whose components are all zero or negative is written with a leading sign
+  /// (`-P1Y2M`). ISO 8601 does not allow components with different signs, so these are written
+  /// with a sign on the individual component (`P1M-1D`), which [`FromStr`] also accepts.
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let (years, months, days) = (self.years, self.months, self.days);
+    let negative = years <= 0 && months <= 0 && days <= 0 && (years, months, days) != (0, 0, 0);
+    f.write_str(if negative { "-P" } else { "P" })?;
+    for (value, unit) in [(years, 'Y'), (months, 'M'), (days, 'D')] {
+      match (value, negative) {
+        (0, _) => {},
+        (_, true) => write!(f, "{}{unit}", value.unsigned_abs())?,
+        (_, false) => write!(f, "{value}{unit}")?,
+      }
+    }
+    match (years, months, days) {
+      (0, 0, 0) => f.write_str("0D"),
+      _ => Ok(()),
+    }
+  }
+}
+
+impl FromStr for CalendarDuration {
+  type Err = ParseDurationError;
+
+  /// Parse an ISO 8601 duration of years, months, weeks, and days, such as `P1Y2M10D`.
+  ///
+  /// Weeks are converted to days. A leading `-` negates the entire duration, and individual
+  /// components may also be negative (`P1M-1D`). Time components are not supported.
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let (years, months, days) = parse_iso_duration(s)?;
+    Ok(Self { years, months, days })
+  }
+}
+
+/// An error returned when parsing a duration from a string fails.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ParseDurationError;
+
+impl fmt::Display for ParseDurationError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str("Invalid duration; expected an ISO 8601 duration such as `P1Y2M10D`")
+  }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseDurationError {}
+
+impl Neg for CalendarDuration {
+  type Output = Self;
+
+  /// Return the negation of this duration.
+  ///
+  /// ## Panic
+  ///
+  /// This panics if any component is `i32::MIN`; use [`CalendarDuration::checked_neg`] to avoid
+  /// this.
+  fn neg(self) -> Self::Output {
+    self.checked_neg().expect("Duration out-of-bounds")
+  }
+}
+
+impl From<DateDifference> for CalendarDuration {
+  fn from(diff: DateDifference) -> Self {
+    Self { years: diff.years, months: diff.months, days: diff.days }
+  }
+}
+
+impl From<MonthInterval> for CalendarDuration {
+  fn from(interval: MonthInterval) -> Self {
+    Self::months(interval.months)
+  }
+}
+
+impl From<DateInterval> for CalendarDuration {
+  fn from(interval: DateInterval) -> Self {
+    Self::days(interval.days)
   }
 }
 
-/// If the provided day falls after the final day of the month, return the final day of the month.
-fn saturated_date(year: i16, month: u8, day: u8) -> Date {
-  Date::overflowing_new(year, month, match month % 12 {
-    1 | 3 | 5 | 7 | 8 | 10 | 0 => day.min(31),
-    4 | 6 | 9 | 11 => day.min(30),
-    2 => day.min(if utils::is_leap_year(year + month as i16 / 12) { 29 } else { 28 }),
-    _ => unreachable!("n % 12 is always 0..=11"),
-  })
+impl Add<CalendarDuration> for Date {
+  type Output = Self;
+
+  /// Return a new `Date` that is the given calendar duration later.
+  fn add(self, duration: CalendarDuration) -> Self {
+    self.checked_add_duration(duration).expect("Date out-of-bounds")
+  }
+}
+
+impl Sub<CalendarDuration> for Date {
+  type Output = Self;
+
+  /// Return a new `Date` that is the given calendar duration earlier.
+  fn sub(self, duration: CalendarDuration) -> Self {
+    self.checked_sub_duration(duration).expect("Date out-of-bounds")
+  }
+}
+
+/// Construct a [`DateInterval`] of the given number of days.
+///
+/// ## Examples
+///
+/// ```
+/// use date::date;
+/// use date::days;
+///
+/// assert_eq!(date! { 2012-04-21 } + days!(10), date! { 2012-05-01 });
+/// assert_eq!(date! { 2012-04-21 } - days!(21), date! { 2012-03-31 });
+/// ```
+#[macro_export]
+macro_rules! days {
+  ($days:expr) => {
+    $crate::interval::DateInterval::new($days)
+  };
+}
+
+/// Construct a [`DateInterval`] of the given number of weeks.
+///
+/// ## Examples
+///
+/// ```
+/// use date::date;
+/// use date::weeks;
+///
+/// assert_eq!(date! { 2012-04-21 } + weeks!(2), date! { 2012-05-05 });
+/// ```
+#[macro_export]
+macro_rules! weeks {
+  ($weeks:expr) => {
+    $crate::interval::DateInterval::weeks($weeks)
+  };
+}
+
+/// Construct a [`MonthInterval`] of the given number of months.
+///
+/// ## Examples
+///
+/// ```
+/// use date::date;
+/// use date::months;
+///
+/// assert_eq!(date! { 2012-04-21 } + months!(18), date! { 2013-10-21 });
+/// ```
+#[macro_export]
+macro_rules! months {
+  ($months:expr) => {
+    $crate::interval::MonthInterval::new($months)
+  };
+}
+
+/// Construct a [`MonthInterval`] of the given number of years.
+///
+/// ## Examples
+///
+/// ```
+/// use date::date;
+/// use date::years;
+///
+/// assert_eq!(date! { 2012-04-21 } + years!(30), date! { 2042-04-21 });
+/// ```
+#[macro_export]
+macro_rules! years {
+  ($years:expr) => {
+    $crate::interval::MonthInterval::years($years)
+  };
+}
+
+/// The date the given number of months from `date`, with the day saturated to the end of the
+/// month.
+///
+/// ## Panic
+///
+/// This function panics if the result is out of range.
+const fn month_offset(date: Date, months: i32) -> Date {
+  match checked_month_offset(date, months) {
+    Some(date) => date,
+    None => panic!("Date out-of-bounds"),
+  }
+}
+
+/// The date the given number of months from `date`, with the day saturated to the end of the
+/// month, or `None` if the result is out of range.
+const fn checked_month_offset(date: Date, months: i32) -> Option<Date> {
+  let (year, month, day) = date.ymd();
+  let total = year as i64 * 12 + month as i64 - 1 + months as i64;
+  let year = total.div_euclid(12);
+  if year < i16::MIN as i64 || year > i16::MAX as i64 {
+    return None;
+  }
+  let (year, month) = (year as i16, total.rem_euclid(12) as u8 + 1);
+  let last = utils::days_in_month(year, month);
+  Some(Date::new(year, month, if day > last { last } else { day }))
+}
+
+/// Parse an ISO 8601 duration (`[-]PnYnMnWnD`) into years, months, and days.
+fn parse_iso_duration(s: &str) -> Result<(i32, i32, i32), ParseDurationError> {
+  let (negative, s) = match s.strip_prefix('-') {
+    Some(s) => (true, s),
+    None => (false, s.strip_prefix('+').unwrap_or(s)),
+  };
+  let mut rest = s.strip_prefix('P').ok_or(ParseDurationError)?;
+  if rest.is_empty() {
+    return Err(ParseDurationError);
+  }
+
+  // Each component is a (possibly negative) integer followed by its unit, in this order.
+  const UNITS: [u8; 4] = [b'Y', b'M', b'W', b'D'];
+  let mut values = [0_i64; 4];
+  let mut next = 0;
+  while !rest.is_empty() {
+    let end = rest.find(|c: char| !c.is_ascii_digit() && c != '-').ok_or(ParseDurationError)?;
+    let (number, tail) = rest.split_at(end);
+    let digits = number.strip_prefix('-').unwrap_or(number);
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+      return Err(ParseDurationError);
+    }
+    let unit = tail.as_bytes()[0];
+    let index = UNITS[next..].iter().position(|u| *u == unit).ok_or(ParseDurationError)? + next;
+    values[index] = number.parse().map_err(|_| ParseDurationError)?;
+    next = index + 1;
+    rest = &tail[1..];
+  }
+
+  let sign = if negative { -1 } else { 1 };
+  let days = values[2].checked_mul(7).and_then(|w| w.checked_add(values[3]));
+  let convert = |value: Option<i64>| {
+    value.and_then(|v| i32::try_from(v.checked_mul(sign)?).ok()).ok_or(ParseDurationError)
+  };
+  Ok((convert(Some(values[0]))?, convert(Some(values[1]))?, convert(days)?))
+}
+
+/// The date the given number of months and then days from `date`, or `None` if the result is out
+/// of range.
+const fn checked_calendar_offset(date: Date, months: i64, days: i64) -> Option<Date> {
+  if months.unsigned_abs() > MonthInterval::MAX_MONTHS as u64 {
+    return None;
+  }
+  let date = match checked_month_offset(date, months as i32) {
+    Some(date) => date,
+    None => return None,
+  };
+  let days = date.0 as i64 + days;
+  match days >= Date::MIN.0 as i64 && days <= Date::MAX.0 as i64 {
+    true => Some(Date(days as i32)),
+    false => None,
+  }
 }
 
 #[cfg(test)]
@@ -212,6 +1130,211 @@ mod tests {
     prove! {2022-06-30 - 2555 == 2015-07-02}; // 2555 == 365 * 7
   }
 
+  #[test]
+  fn test_interval_arithmetic() {
+    let week = DateInterval::weeks(1);
+    check!(week == DateInterval::new(7));
+    check!(DateInterval::weeks(-2) == DateInterval::new(-14));
+    check!(week + DateInterval::new(3) == DateInterval::new(10));
+    check!(week - DateInterval::new(10) == DateInterval::new(-3));
+    check!(week * 3 == DateInterval::new(21));
+    check!(week * -1 == -week);
+    check!(DateInterval::new(10) / 3 == DateInterval::new(3));
+    check!(DateInterval::new(-10) / 3 == DateInterval::new(-3));
+    let intervals = [week, DateInterval::new(3), DateInterval::new(-1)];
+    check!(intervals.iter().sum::<DateInterval>() == DateInterval::new(9));
+    check!(intervals.into_iter().sum::<DateInterval>() == DateInterval::new(9));
+    check!(core::iter::empty::<DateInterval>().sum::<DateInterval>() == DateInterval::new(0));
+    check!(date! { 2024-01-01 } + week * 2 == date! { 2024-01-15 });
+  }
+
+  #[test]
+  fn test_date_interval_iso() {
+    check!(DateInterval::new(3).to_string() == "P3D");
+    check!(DateInterval::new(0).to_string() == "P0D");
+    check!(DateInterval::new(-3).to_string() == "-P3D");
+    check!(DateInterval::new(i32::MIN).to_string() == "-P2147483648D");
+    check!("P3D".parse::<DateInterval>() == Ok(DateInterval::new(3)));
+    check!("P2W".parse::<DateInterval>() == Ok(DateInterval::new(14)));
+    check!("P1W2D".parse::<DateInterval>() == Ok(DateInterval::new(9)));
+    check!("-P3D".parse::<DateInterval>() == Ok(DateInterval::new(-3)));
+    check!("+P3D".parse::<DateInterval>() == Ok(DateInterval::new(3)));
+    check!("P-3D".parse::<DateInterval>() == Ok(DateInterval::new(-3)));
+    check!("-P2147483648D".parse::<DateInterval>() == Ok(DateInterval::new(i32::MIN)));
+    for days in [-400, -1, 0, 1, 365] {
+      let interval = DateInterval::new(days);
+      check!(interval.to_string().parse::<DateInterval>() == Ok(interval));
+    }
+    for s in ["P1M", "P1Y2D", "P", "", "3D", "P3", "PD", "P3D4D", "P3DT1H", "P--3D", "p3d"] {
+      check!(s.parse::<DateInterval>() == Err(ParseDurationError), "{s}");
+    }
+    check!("P2147483648D".parse::<DateInterval>().is_err());
+    check!("P306783379W".parse::<DateInterval>().is_err());
+    check!("-P-9223372036854775808D".parse::<DateInterval>().is_err());
+  }
+
+  #[test]
+  fn test_human() {
+    let cases = [
+      ("3 days", 3),
+      ("1 day", 1),
+      ("3d", 3),
+      ("3 D", 3),
+      ("  3days  ", 3),
+      ("2w", 14),
+      ("2 wk", 14),
+      ("1 Week", 7),
+      ("-2 weeks", -14),
+      ("+4 days", 4),
+      ("0 days", 0),
+    ];
+    for (s, days) in cases {
+      check!(DateInterval::parse_human(s) == Ok(DateInterval::new(days)), "{s}");
+    }
+    for s in ["3", "days", "3 months", "10 business days", "3 dayz", "3.5 days", "", "- 3 days"] {
+      check!(DateInterval::parse_human(s) == Err(ParseDurationError), "{s}");
+    }
+    check!(DateInterval::parse_human("306783379 weeks").is_err());
+    for days in [-2, -1, 0, 1, 2, 365] {
+      let interval = DateInterval::new(days);
+      check!(DateInterval::parse_human(&interval.humanize().to_string()) == Ok(interval));
+    }
+    check!(DateInterval::new(1).humanize().to_string() == "1 day");
+    check!(DateInterval::new(0).humanize().to_string() == "0 days");
+  }
+
+  #[test]
+  fn test_calendar_duration_iso() {
+    let cases = [
+      (CalendarDuration::new(1, 2, 10), "P1Y2M10D"),
+      (CalendarDuration::new(0, 0, 0), "P0D"),
+      (CalendarDuration::new(1, 0, 0), "P1Y"),
+      (CalendarDuration::new(0, 3, 0), "P3M"),
+      (CalendarDuration::new(-1, -2, 0), "-P1Y2M"),
+      (CalendarDuration::new(0, 1, -1), "P1M-1D"),
+    ];
+    for (duration, s) in cases {
+      check!(duration.to_string() == s);
+      check!(s.parse::<CalendarDuration>() == Ok(duration));
+    }
+    check!("P2W".parse::<CalendarDuration>() == Ok(CalendarDuration::days(14)));
+    check!("P1Y1W1D".parse::<CalendarDuration>() == Ok(CalendarDuration::new(1, 0, 8)));
+    check!("-P1Y-1M".parse::<CalendarDuration>() == Ok(CalendarDuration::new(-1, 1, 0)));
+    for s in ["P1D1Y", "P1M1M", "P1H", "P1Y2M10DT2H", "1Y", "P1.5Y"] {
+      check!(s.parse::<CalendarDuration>() == Err(ParseDurationError), "{s}");
+    }
+    check!(ParseDurationError.to_string().starts_with("Invalid duration"));
+  }
+
+  #[test]
+  fn test_checked_days() {
+    let date = date! { 2012-04-21 };
+    check!(date.checked_add_days(DateInterval::new(10)) == Some(date! { 2012-05-01 }));
+    check!(date.checked_sub_days(DateInterval::new(21)) == Some(date! { 2012-03-31 }));
+    check!(Date::MAX.checked_add_days(DateInterval::new(0)) == Some(Date::MAX));
+    check!(Date::MAX.checked_add_days(DateInterval::new(1)) == None);
+    check!(Date::MAX.checked_sub_days(DateInterval::new(-1)) == None);
+    check!(Date::MIN.checked_sub_days(DateInterval::new(1)) == None);
+    check!(Date::MIN.checked_add_days(DateInterval::new(-1)) == None);
+    check!(Date::MIN.checked_add_days(DateInterval::new(i32::MAX)) == None);
+    check!(Date::MAX.checked_sub_days(DateInterval::new(i32::MIN)) == None);
+  }
+
+  #[test]
+  #[should_panic]
+  fn test_add_days_overflow() {
+    let _ = Date::MAX + DateInterval::new(1);
+  }
+
+  #[test]
+  #[should_panic]
+  fn test_sub_days_overflow() {
+    let mut date = Date::MIN;
+    date -= DateInterval::new(1);
+  }
+
+  #[test]
+  fn test_checked_months() {
+    let date = date! { 2021-12-31 };
+    for months in -243..=243 {
+      let interval = MonthInterval::new(months);
+      check!(date.checked_add_months(interval) == Some(date + interval));
+      check!(date.checked_sub_months(interval) == Some(date - interval));
+    }
+    check!(date! { 2021-12-31 } - MonthInterval::new(12) == date! { 2020-12-31 });
+    check!(date! { 2021-03-31 } - MonthInterval::new(24) == date! { 2019-03-31 });
+    let date = date! { 32767-12-01 };
+    check!(date.checked_add_months(MonthInterval::new(0)) == Some(date));
+    check!(date.checked_add_months(MonthInterval::new(1)) == None);
+    check!(Date::MIN.checked_sub_months(MonthInterval::new(1)) == None);
+    let date = Date::new(-32767, 1, 31);
+    check!(date.checked_sub_months(MonthInterval::new(11)) == Some(Date::new(-32768, 2, 29)));
+    check!(date.checked_add_months(MonthInterval::new(-11)) == Some(Date::new(-32768, 2, 29)));
+    let max = MonthInterval::new(MonthInterval::MAX_MONTHS);
+    check!(date! { -32768-01-31 }.checked_add_months(max) == Some(date! { 32767-12-31 }));
+    check!(date! { 32767-12-15 }.checked_sub_months(max) == Some(date! { -32768-01-15 }));
+    check!(date! { 32767-12-15 }.checked_add_months(-max) == Some(date! { -32768-01-15 }));
+    check!(date! { 2024-01-01 }.checked_add_months(max) == None);
+    check!(date! { 2024-01-01 }.checked_sub_months(max) == None);
+  }
+
+  #[test]
+  #[should_panic]
+  fn test_add_months_overflow() {
+    let _ = date! { 32767-12-01 } + MonthInterval::new(1);
+  }
+
+  #[test]
+  fn test_negative_months() {
+    let date = date! { 2024-03-31 };
+    check!(date + MonthInterval::new(-1) == date! { 2024-02-29 });
+    check!(date - MonthInterval::new(-1) == date! { 2024-04-30 });
+    check!(date + -MonthInterval::new(13) == date! { 2023-02-28 });
+    check!(date + MonthInterval::new(-13) == date - MonthInterval::new(13));
+    check!(MonthInterval::new(-5).abs() == MonthInterval::new(5));
+    check!(-MonthInterval::new(-5) == MonthInterval::new(5));
+    check!(MonthInterval::new(-5).months() == -5);
+  }
+
+  #[test]
+  fn test_saturating() {
+    let date = date! { 2012-04-21 };
+    check!(date.saturating_add_days(DateInterval::new(10)) == date! { 2012-05-01 });
+    check!(date.saturating_sub_days(DateInterval::new(21)) == date! { 2012-03-31 });
+    check!(date.saturating_add_months(MonthInterval::new(3)) == date! { 2012-07-21 });
+    check!(date.saturating_sub_months(MonthInterval::new(3)) == date! { 2012-01-21 });
+    check!(date.saturating_add_days(DateInterval::new(i32::MAX)) == Date::MAX);
+    check!(date.saturating_add_days(DateInterval::new(i32::MIN)) == Date::MIN);
+    check!(date.saturating_sub_days(DateInterval::new(i32::MAX)) == Date::MIN);
+    check!(date.saturating_sub_days(DateInterval::new(i32::MIN)) == Date::MAX);
+    check!(Date::MAX.saturating_add_days(DateInterval::new(1)) == Date::MAX);
+    check!(Date::MAX.saturating_sub_days(DateInterval::new(-1)) == Date::MAX);
+    check!(Date::MIN.saturating_sub_days(DateInterval::new(1)) == Date::MIN);
+    check!(Date::MIN.saturating_add_days(DateInterval::new(-1)) == Date::MIN);
+    check!(Date::MAX.saturating_sub_days(DateInterval::new(1)) == date! { 32767-12-30 });
+    check!(Date::MAX.saturating_add_months(MonthInterval::new(1)) == Date::MAX);
+    check!(Date::MIN.saturating_sub_months(MonthInterval::new(1)) == Date::MIN);
+    check!(Date::MAX.saturating_sub_months(MonthInterval::new(-1)) == Date::MAX);
+    check!(Date::MIN.saturating_add_months(MonthInterval::new(-1)) == Date::MIN);
+    let date = date! { 32767-11-15 };
+    check!(date.saturating_add_months(MonthInterval::new(1)) == date! { 32767-12-15 });
+  }
+
+  #[test]
+  fn test_const() {
+    const START: Date = date! { 2024-01-31 };
+    const LATER: Date = START.add_days(DateInterval::new(30));
+    const EARLIER: Date = START.sub_days(DateInterval::new(31));
+    const NEXT_MONTH: Date = START.add_months(MonthInterval::new(1));
+    const LAST_MONTH: Date = START.sub_months(MonthInterval::new(2));
+    const DIFF: DateInterval = LATER.days_since(EARLIER);
+    check!(LATER == date! { 2024-03-01 });
+    check!(EARLIER == date! { 2023-12-31 });
+    check!(NEXT_MONTH == date! { 2024-02-29 });
+    check!(LAST_MONTH == date! { 2023-11-30 });
+    check!(DIFF == DateInterval::new(61));
+  }
+
   #[test]
   fn test_sub_dates() {
     check!(date! { 2012-04-21 } - date! { 2012-04-21 } == DateInterval::new(0));
@@ -251,4 +1374,206 @@ mod tests {
     // Coercsion of days (non-communicative).
     check!(date! { 2020-01-31 } + MonthInterval::new(1) == date! { 2020-02-29 });
   }
+
+  #[test]
+  fn test_difference() {
+    macro_rules! prove {
+      ($a:expr, $b:expr => $y:literal, $m:literal, $d:literal) => {
+        let diff = $a.difference($b);
+        check!((diff.years(), diff.months(), diff.days()) == ($y, $m, $d));
+      };
+    }
+    prove!(date! { 2024-08-20 }, date! { 2022-05-15 } => 2, 3, 5);
+    prove!(date! { 2022-05-15 }, date! { 2024-08-20 } => -2, -3, -5);
+    prove!(date! { 2024-08-20 }, date! { 2024-08-20 } => 0, 0, 0);
+    prove!(date! { 2024-08-20 }, date! { 2024-08-10 } => 0, 0, 10);
+    prove!(date! { 2024-08-10 }, date! { 2024-07-20 } => 0, 0, 21);
+    prove!(date! { 2025-01-05 }, date! { 2024-12-10 } => 0, 0, 26);
+    prove!(date! { 2025-01-10 }, date! { 2023-12-10 } => 1, 1, 0);
+
+    // End of month.
+    prove!(date! { 2023-02-28 }, date! { 2023-01-31 } => 0, 1, 0);
+    prove!(date! { 2023-03-01 }, date! { 2023-01-31 } => 0, 1, 1);
+    prove!(date! { 2023-02-27 }, date! { 2023-01-31 } => 0, 0, 27);
+    prove!(date! { 2023-02-28 }, date! { 2023-03-31 } => 0, -1, 0);
+    prove!(date! { 2023-02-27 }, date! { 2023-03-31 } => 0, -1, -1);
+    prove!(date! { 2025-02-28 }, date! { 2024-02-29 } => 1, 0, 0);
+
+    // Extremes.
+    prove!(Date::MAX, Date::MIN => 65_535, 11, 30);
+    prove!(Date::MIN, Date::MAX => -65_535, -11, -30);
+    check!(date! { 2024-01-01 }.difference(date! { 2024-01-01 }).is_zero());
+    check!(date! { 2024-08-20 }.difference(date! { 2022-05-15 }).total_months() == 27);
+  }
+
+  #[test]
+  fn test_difference_round_trip() {
+    let start = date! { 2023-01-25 };
+    for end in start.iter_through(date! { 2024-04-05 }) {
+      for (a, b) in [(start, end), (end, start)] {
+        let diff = b.difference(a);
+        let anchor = checked_month_offset(a, diff.total_months()).unwrap();
+        check!(anchor + DateInterval::new(diff.days()) == b);
+        check!(diff.days().abs() < 31);
+        check!(diff.months().abs() < 12);
+      }
+    }
+  }
+
+  #[test]
+  fn test_add_sub_years() {
+    let date = date! { 2012-04-21 };
+    for policy in [Feb29Policy::Feb28, Feb29Policy::Mar1, Feb29Policy::LeapYearsOnly] {
+      check!(date.add_years(0, policy) == Some(date));
+      check!(date.add_years(30, policy) == Some(date! { 2042-04-21 }));
+      check!(date.add_years(-30, policy) == Some(date! { 1982-04-21 }));
+      check!(date.sub_years(30, policy) == Some(date! { 1982-04-21 }));
+      check!(date.add_years(30_755, policy) == Some(date! { 32767-04-21 }));
+      check!(date.add_years(30_756, policy) == None);
+      check!(date.sub_years(34_780, policy) == Some(date! { -32768-04-21 }));
+      check!(date.sub_years(34_781, policy) == None);
+      check!(date.add_years(i32::MAX, policy) == None);
+      check!(date.add_years(i32::MIN, policy) == None);
+      check!(date.sub_years(i32::MIN, policy) == None);
+    }
+    let leap_day = date! { 2000-02-29 };
+    check!(leap_day.add_years(100, Feb29Policy::Feb28) == Some(date! { 2100-02-28 }));
+    check!(leap_day.add_years(100, Feb29Policy::Mar1) == Some(date! { 2100-03-01 }));
+    check!(leap_day.add_years(100, Feb29Policy::LeapYearsOnly) == None);
+    check!(leap_day.sub_years(400, Feb29Policy::LeapYearsOnly) == Some(date! { 1600-02-29 }));
+    check!(leap_day.sub_years(1, Feb29Policy::Mar1) == Some(date! { 1999-03-01 }));
+  }
+
+  #[test]
+  fn test_macros() {
+    const MONTH: DateInterval = days!(30);
+    const FORTNIGHT: DateInterval = weeks!(2);
+    const YEAR_AND_HALF: MonthInterval = months!(18);
+    check!(MONTH == DateInterval::new(30));
+    check!(FORTNIGHT == DateInterval::new(14));
+    check!(YEAR_AND_HALF == MonthInterval::new(18));
+    check!(days!(-1 - 1) == DateInterval::new(-2));
+    check!(weeks!(1 + 1) == DateInterval::new(14));
+  }
+
+  #[test]
+  fn test_add_months_eom() {
+    let mut date = date! { 2023-12-31 };
+    for expected in [
+      date! { 2024-01-31 },
+      date! { 2024-02-29 },
+      date! { 2024-03-31 },
+      date! { 2024-04-30 },
+      date! { 2024-05-31 },
+    ] {
+      date = date.add_months_eom(MonthInterval::new(1));
+      check!(date == expected);
+    }
+    check!(date! { 2024-02-29 }.add_months_eom(MonthInterval::new(-1)) == date! { 2024-01-31 });
+    check!(date! { 2024-02-29 }.add_months_eom(MonthInterval::new(12)) == date! { 2025-02-28 });
+    check!(date! { 2023-02-28 }.add_months_eom(MonthInterval::new(12)) == date! { 2024-02-29 });
+    check!(date! { 2024-02-28 }.add_months_eom(MonthInterval::new(1)) == date! { 2024-03-28 });
+    check!(date! { 2024-04-15 }.add_months_eom(MonthInterval::new(1)) == date! { 2024-05-15 });
+    check!(Date::MAX.checked_add_months_eom(MonthInterval::new(0)) == Some(Date::MAX));
+    check!(Date::MAX.checked_add_months_eom(MonthInterval::new(1)) == None);
+    check!(Date::MIN.checked_add_months_eom(MonthInterval::new(-1)) == None);
+    let date = date! { -32768-01-31 };
+    check!(date.checked_add_months_eom(MonthInterval::new(1)) == Some(date! { -32768-02-29 }));
+  }
+
+  #[test]
+  fn test_calendar_duration() {
+    let date = date! { 2024-01-31 };
+    check!(date + CalendarDuration::years(1) == date! { 2025-01-31 });
+    check!(date + CalendarDuration::months(1) == date! { 2024-02-29 });
+    check!(date + CalendarDuration::days(1) == date! { 2024-02-01 });
+    check!(date + CalendarDuration::new(0, 1, 1) == date! { 2024-03-01 });
+    check!(date + CalendarDuration::new(1, 1, 0) == date! { 2025-02-28 });
+    check!(date + CalendarDuration::new(1, -1, 0) == date! { 2024-12-31 });
+    check!(date - CalendarDuration::new(0, 1, 1) == date! { 2023-12-30 });
+    check!(date - CalendarDuration::new(0, 1, 1) == date + -CalendarDuration::new(0, 1, 1));
+    check!(date + CalendarDuration::default() == date);
+    check!(CalendarDuration::from(MonthInterval::new(3)) == CalendarDuration::months(3));
+    check!(CalendarDuration::from(DateInterval::new(3)) == CalendarDuration::days(3));
+    let duration = CalendarDuration::new(2, 3, 5);
+    check!((duration.year_part(), duration.month_part(), duration.day_part()) == (2, 3, 5));
+  }
+
+  #[test]
+  fn test_calendar_duration_checked() {
+    let date = date! { 2024-01-31 };
+    check!(Date::MAX.checked_add_duration(CalendarDuration::days(1)) == None);
+    check!(Date::MAX.checked_add_duration(CalendarDuration::new(0, 1, -31)) == None);
+    check!(Date::MIN.checked_sub_duration(CalendarDuration::months(1)) == None);
+    check!(date.checked_add_duration(CalendarDuration::years(i32::MAX)) == None);
+    check!(date.checked_sub_duration(CalendarDuration::years(i32::MIN)) == None);
+    check!(date.checked_sub_duration(CalendarDuration::days(i32::MIN)) == None);
+    check!(date.checked_add_duration(CalendarDuration::new(10_000, -120_000, 0)) == Some(date));
+    let duration = CalendarDuration::new(65_535, 11, 30);
+    check!(Date::MIN.checked_add_duration(duration) == Some(Date::MAX));
+  }
+
+  #[test]
+  #[should_panic]
+  fn test_calendar_duration_overflow() {
+    let _ = Date::MAX + CalendarDuration::days(1);
+  }
+
+  #[test]
+  fn test_calendar_duration_neg() {
+    let duration = CalendarDuration::new(1, -2, 3);
+    check!(-duration == CalendarDuration::new(-1, 2, -3));
+    check!(duration.checked_neg() == Some(-duration));
+    let max = CalendarDuration::years(i32::MAX);
+    check!(max.checked_neg() == Some(CalendarDuration::years(-i32::MAX)));
+    check!(CalendarDuration::years(i32::MIN).checked_neg() == None);
+    check!(CalendarDuration::months(i32::MIN).checked_neg() == None);
+    check!(CalendarDuration::days(i32::MIN).checked_neg() == None);
+  }
+
+  #[test]
+  #[should_panic]
+  fn test_calendar_duration_neg_overflow() {
+    let _ = -CalendarDuration::days(i32::MIN);
+  }
+
+  #[test]
+  fn test_calendar_duration_round_trip() {
+    let start = date! { 2023-01-25 };
+    for end in start.iter_through(date! { 2024-04-05 }) {
+      for (a, b) in [(start, end), (end, start)] {
+        check!(a + CalendarDuration::from(b.difference(a)) == b);
+      }
+    }
+  }
+
+  #[test]
+  fn test_years() {
+    check!(MonthInterval::years(30).months() == 360);
+    check!(MonthInterval::years(-2) == MonthInterval::new(-24));
+    check!(MonthInterval::from_years_months(1, -1) == MonthInterval::new(11));
+    check!(MonthInterval::from_years_months(-1, -6) == MonthInterval::new(-18));
+    check!(MonthInterval::checked_from_years_months(65_535, 11).is_some());
+    check!(MonthInterval::checked_from_years_months(65_536, 0) == None);
+    check!(MonthInterval::checked_from_years_months(-65_535, -11).is_some());
+    check!(MonthInterval::checked_from_years_months(i32::MAX, i32::MAX) == None);
+    check!(MonthInterval::checked_from_years_months(i32::MIN, i32::MIN) == None);
+    check!(years!(2) == MonthInterval::new(24));
+    check!(Date::MIN + years!(65_535) == date! { 32767-01-01 });
+  }
+
+  #[test]
+  #[should_panic]
+  fn test_years_out_of_bounds() {
+    MonthInterval::years(65_536);
+  }
+
+  #[test]
+  fn test_month_interval_checked_new() {
+    check!(MonthInterval::checked_new(786_431) == Some(MonthInterval::new(786_431)));
+    check!(MonthInterval::checked_new(-786_431) == Some(MonthInterval::new(-786_431)));
+    check!(MonthInterval::checked_new(786_432) == None);
+    check!(MonthInterval::checked_new(-786_432) == None);
+    check!(MonthInterval::checked_new(i32::MIN) == None);
+  }
 }
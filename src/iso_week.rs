@@ -0,0 +1,313 @@
+use core::cmp::Ordering;
+use core::fmt;
+use core::str::FromStr;
+
+use crate::Date;
+use crate::Weekday;
+
+/// A date in the ISO 8601 week-date system.
+///
+/// ISO weeks begin on Monday, and week 1 of a year is the week containing the year's first
+/// Thursday. Every ISO year therefore has either 52 or 53 whole weeks, and the first or last few
+/// days of a calendar year may belong to an adjacent ISO year.
+///
+/// Conversion to and from [`Date`] is lossless.
+///
+/// ## Examples
+///
+/// ```
+/// use date::date;
+/// use date::IsoWeekDate;
+/// use date::Weekday;
+///
+/// let week_date = date! { 2024-12-30 }.iso_week_date();
+/// assert_eq!(week_date, IsoWeekDate::new(2025, 1, Weekday::Monday));
+/// assert_eq!(week_date.to_string(), "2025-W01-1");
+/// assert_eq!(week_date.to_date(), date! { 2024-12-30 });
+/// assert_eq!("2025-W01-1".parse::<IsoWeekDate>(), Ok(week_date));
+/// ```
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub struct IsoWeekDate {
+  year: i16,
+  week: u8,
+  weekday: Weekday,
+}
+
+impl IsoWeekDate {
+  /// Construct a new ISO week date from the provided ISO year, week, and weekday.
+  ///
+  /// ## Panic
+  ///
+  /// This function panics if the week is not within the given ISO year (that is, if it is zero,
+  /// or is greater than [`IsoWeekDate::weeks_in_year`]).
+  pub const fn new(year: i16, week: u8, weekday: Weekday) -> Self {
+    assert!(week >= 1 && week <= Self::weeks_in_year(year), "Week out-of-bounds");
+    Self { year, week, weekday }
+  }
+
+  /// Construct a new ISO week date, or return `None` if the week is not within the given ISO year.
+  ///
+  /// This is the non-panicking counterpart to [`IsoWeekDate::new`].
+  pub const fn checked_new(year: i16, week: u8, weekday: Weekday) -> Option<Self> {
+    match week >= 1 && week <= Self::weeks_in_year(year) {
+      true => Some(Self { year, week, weekday }),
+      false => None,
+    }
+  }
+
+  /// The ISO year, which may differ from the calendar year for dates near the beginning or end of
+  /// the year.
+  #[inline]
+  pub const fn year(&self) -> i16 {
+    self.year
+  }
+
+  /// The ISO week number, between 1 and 53.
+  #[inline]
+  pub const fn week(&self) -> u8 {
+    self.week
+  }
+
+  /// The day of the week.
+  #[inline]
+  pub const fn weekday(&self) -> Weekday {
+    self.weekday
+  }
+
+  /// The number of ISO weeks in the given ISO year (either 52 or 53).
+  pub const fn weeks_in_year(year: i16) -> u8 {
+    // December 28 is always in the last week of the ISO year.
+    Date::new(year, 12, 28).iso_week_date().week
+  }
+
+  /// The date corresponding to this ISO week date.
+  pub const fn to_date(&self) -> Date {
+    let jan4 = Date::new(self.year, 1, 4);
+    let week1 = jan4.0 - jan4.weekday().number_from_monday() as i32 + 1;
+    Date(week1 + (self.week as i32 - 1) * 7 + self.weekday.number_from_monday() as i32 - 1)
+  }
+}
+
+impl Date {
+  /// The ISO 8601 week date for this date.
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::date;
+  /// use date::Weekday;
+  ///
+  /// let week_date = date! { 2021-01-03 }.iso_week_date();
+  /// assert_eq!(week_date.year(), 2020);
+  /// assert_eq!(week_date.week(), 53);
+  /// assert_eq!(week_date.weekday(), Weekday::Sunday);
+  /// ```
+  pub const fn iso_week_date(&self) -> IsoWeekDate {
+    // The ISO year and week are those of the Thursday in the same ISO week.
+    let weekday = self.weekday();
+    let thursday = Date(self.0 - weekday.number_from_monday() as i32 + 4);
+    let year = thursday.year();
+    let week = (thursday.0 - Date::new(year, 1, 1).0) / 7 + 1;
+    IsoWeekDate { year, week: week as u8, weekday }
+  }
+
+  /// The ISO 8601 week number (between 1 and 53) of this date.
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::date;
+  ///
+  /// assert_eq!(date! { 2021-01-03 }.iso_week(), 53);
+  /// assert_eq!(date! { 2021-01-04 }.iso_week(), 1);
+  /// ```
+  #[inline]
+  pub const fn iso_week(&self) -> u8 {
+    self.iso_week_date().week
+  }
+
+  /// The ISO 8601 week-numbering year of this date, which may differ from the calendar year for
+  /// dates near the beginning or end of the year.
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::date;
+  ///
+  /// assert_eq!(date! { 2021-01-03 }.iso_week_year(), 2020);
+  /// assert_eq!(date! { 2024-12-30 }.iso_week_year(), 2025);
+  /// ```
+  #[inline]
+  pub const fn iso_week_year(&self) -> i16 {
+    self.iso_week_date().year
+  }
+
+  /// The date for the given ISO 8601 year, week, and weekday, or `None` if the week is not within
+  /// the given ISO year.
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::date;
+  /// use date::Date;
+  /// use date::Weekday;
+  ///
+  /// assert_eq!(Date::from_iso_week_date(2025, 1, Weekday::Monday), Some(date! { 2024-12-30 }));
+  /// assert_eq!(Date::from_iso_week_date(2021, 53, Weekday::Monday), None);
+  /// ```
+  pub const fn from_iso_week_date(iso_year: i16, week: u8, weekday: Weekday) -> Option<Date> {
+    match IsoWeekDate::checked_new(iso_year, week, weekday) {
+      Some(week_date) => Some(week_date.to_date()),
+      None => None,
+    }
+  }
+}
+
+impl From<Date> for IsoWeekDate {
+  fn from(date: Date) -> Self {
+    date.iso_week_date()
+  }
+}
+
+impl From<IsoWeekDate> for Date {
+  fn from(week_date: IsoWeekDate) -> Self {
+    week_date.to_date()
+  }
+}
+
+impl PartialOrd for IsoWeekDate {
+  fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+impl Ord for IsoWeekDate {
+  fn cmp(&self, other: &Self) -> Ordering {
+    self.to_date().cmp(&other.to_date())
+  }
+}
+
+impl fmt::Display for IsoWeekDate {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{:04}-W{:02}-{}", self.year, self.week, self.weekday.number_from_monday())
+  }
+}
+
+impl FromStr for IsoWeekDate {
+  type Err = ParseIsoWeekDateError;
+
+  /// Parse an ISO week date in the extended format (`YYYY-Www-D`).
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let (year, rest) = s.rsplit_once("-W").ok_or(ParseIsoWeekDateError)?;
+    let (week, weekday) = rest.split_once('-').ok_or(ParseIsoWeekDateError)?;
+    if year.len() < 4 || week.len() != 2 || weekday.len() != 1 {
+      return Err(ParseIsoWeekDateError);
+    }
+    let year: i16 = year.parse().map_err(|_| ParseIsoWeekDateError)?;
+    let week: u8 = week.parse().map_err(|_| ParseIsoWeekDateError)?;
+    let weekday = match weekday {
+      "1" => Weekday::Monday,
+      "2" => Weekday::Tuesday,
+      "3" => Weekday::Wednesday,
+      "4" => Weekday::Thursday,
+      "5" => Weekday::Friday,
+      "6" => Weekday::Saturday,
+      "7" => Weekday::Sunday,
+      _ => return Err(ParseIsoWeekDateError),
+    };
+    match week >= 1 && week <= IsoWeekDate::weeks_in_year(year) {
+      true => Ok(Self { year, week, weekday }),
+      false => Err(ParseIsoWeekDateError),
+    }
+  }
+}
+
+/// An error returned when parsing an [`IsoWeekDate`] from a string fails.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseIsoWeekDateError;
+
+impl fmt::Display for ParseIsoWeekDateError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str("Invalid ISO week date; expected `YYYY-Www-D`")
+  }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseIsoWeekDateError {}
+
+#[cfg(test)]
+mod tests {
+  use assert2::check;
+
+  use super::*;
+
+  #[test]
+  fn test_iso_week_date() {
+    check!(date! { 2021-01-03 }.iso_week_date() == IsoWeekDate::new(2020, 53, Weekday::Sunday));
+    check!(date! { 2021-01-04 }.iso_week_date() == IsoWeekDate::new(2021, 1, Weekday::Monday));
+    check!(date! { 2024-12-30 }.iso_week_date() == IsoWeekDate::new(2025, 1, Weekday::Monday));
+    check!(date! { 2024-04-21 }.iso_week_date() == IsoWeekDate::new(2024, 16, Weekday::Sunday));
+    check!(date! { 1969-12-31 }.iso_week_date() == IsoWeekDate::new(1970, 1, Weekday::Wednesday));
+  }
+
+  #[test]
+  fn test_date_helpers() {
+    for date in date! { 2020-12-20 }.iter_through(date! { 2021-01-10 }) {
+      let week_date = date.iso_week_date();
+      check!(date.iso_week() == week_date.week());
+      check!(date.iso_week_year() == week_date.year());
+      let (year, week) = (date.iso_week_year(), date.iso_week());
+      check!(Date::from_iso_week_date(year, week, date.weekday()) == Some(date));
+    }
+    check!(Date::from_iso_week_date(2021, 0, Weekday::Monday) == None);
+  }
+
+  #[test]
+  fn test_weeks_in_year() {
+    check!(IsoWeekDate::weeks_in_year(2020) == 53);
+    check!(IsoWeekDate::weeks_in_year(2021) == 52);
+    check!(IsoWeekDate::weeks_in_year(2026) == 53);
+  }
+
+  #[test]
+  fn test_round_trip() {
+    for date in date! { 1999-01-01 }.iter_through(date! { 2031-12-31 }) {
+      check!(date.iso_week_date().to_date() == date);
+      check!(Date::from(IsoWeekDate::from(date)) == date);
+    }
+  }
+
+  #[test]
+  fn test_ord() {
+    let a = IsoWeekDate::new(2024, 16, Weekday::Saturday);
+    let b = IsoWeekDate::new(2024, 16, Weekday::Sunday);
+    let c = IsoWeekDate::new(2024, 17, Weekday::Monday);
+    check!(a < b);
+    check!(b < c);
+  }
+
+  #[test]
+  fn test_display_parse() {
+    let week_date = IsoWeekDate::new(2020, 53, Weekday::Sunday);
+    check!(week_date.to_string() == "2020-W53-7");
+    check!("2020-W53-7".parse::<IsoWeekDate>() == Ok(week_date));
+    check!("2021-W53-1".parse::<IsoWeekDate>() == Err(ParseIsoWeekDateError));
+    check!("2021-W00-1".parse::<IsoWeekDate>().is_err());
+    check!("2021-W1-1".parse::<IsoWeekDate>().is_err());
+    check!("2021-W01-8".parse::<IsoWeekDate>().is_err());
+    check!("2021-01-01".parse::<IsoWeekDate>().is_err());
+  }
+
+  #[test]
+  fn test_checked_new() {
+    check!(IsoWeekDate::checked_new(2020, 53, Weekday::Sunday).is_some());
+    check!(IsoWeekDate::checked_new(2021, 53, Weekday::Monday) == None);
+    check!(IsoWeekDate::checked_new(2021, 0, Weekday::Monday) == None);
+  }
+
+  #[test]
+  #[should_panic]
+  fn test_invalid_week() {
+    IsoWeekDate::new(2021, 53, Weekday::Monday);
+  }
+}
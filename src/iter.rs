@@ -1,11 +1,26 @@
 //! Iterator over dates
 
-use std::iter::Iterator;
+use core::iter::Iterator;
 
 use crate::interval::DateInterval;
+use crate::interval::MonthInterval;
 use crate::Date;
+use crate::Weekday;
+use crate::Workweek;
 
-/// An iterator that will yield dates indefinitely.
+/// An iterator over a span of consecutive dates.
+///
+/// The iterator can be consumed from either end, and knows its exact length.
+///
+/// ## Examples
+///
+/// ```
+/// use date::date;
+///
+/// let dates = date! { 2024-02-27 }.iter_through(date! { 2024-03-01 });
+/// assert_eq!(dates.len(), 4);
+/// assert_eq!(dates.rev().next(), Some(date! { 2024-03-01 }));
+/// ```
 pub struct DateIterator {
   cursor: Date,
   end: Date,
@@ -25,11 +40,293 @@ impl Iterator for DateIterator {
       true => None,
       false => {
         let answer = Some(self.cursor);
-        self.cursor += DateInterval::new(1);
+        match self.cursor < self.end {
+          true => self.cursor += DateInterval::new(1),
+          false => (self.cursor, self.end) = (Date::MAX, Date::MIN),
+        }
         answer
       },
     }
   }
+
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    let len = self.len();
+    (len, Some(len))
+  }
+}
+
+impl DoubleEndedIterator for DateIterator {
+  fn next_back(&mut self) -> Option<Self::Item> {
+    match self.cursor > self.end {
+      true => None,
+      false => {
+        let answer = Some(self.end);
+        match self.cursor < self.end {
+          true => self.end -= DateInterval::new(1),
+          false => (self.cursor, self.end) = (Date::MAX, Date::MIN),
+        }
+        answer
+      },
+    }
+  }
+}
+
+impl ExactSizeIterator for DateIterator {
+  fn len(&self) -> usize {
+    match self.cursor > self.end {
+      true => 0,
+      false => (self.end.0 - self.cursor.0) as usize + 1,
+    }
+  }
+}
+
+impl Date {
+  /// An iterator of dates beginning with this date and advancing by the given interval, ending no
+  /// later than the provided end date (inclusive).
+  ///
+  /// A negative interval iterates backward, ending no earlier than the end date.
+  ///
+  /// ## Panic
+  ///
+  /// This function panics if the interval is zero.
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::date;
+  /// use date::weeks;
+  ///
+  /// let start = date! { 2024-01-05 };
+  /// let paydays: Vec<_> = start.iter_step(date! { 2024-02-29 }, weeks!(2)).collect();
+  /// assert_eq!(paydays, [
+  ///   date! { 2024-01-05 },
+  ///   date! { 2024-01-19 },
+  ///   date! { 2024-02-02 },
+  ///   date! { 2024-02-16 },
+  /// ]);
+  /// ```
+  pub const fn iter_step(&self, end: Date, step: DateInterval) -> DateStepIterator {
+    DateStepIterator::new(*self, end, step.days())
+  }
+
+  /// An iterator of dates beginning with this date and advancing by the given number of months,
+  /// ending no later than the provided end date (inclusive).
+  ///
+  /// Each date is offset from this date (rather than from the previous date yielded), so a day
+  /// that does not exist in a shorter month is clamped to the end of that month without
+  /// affecting later months: stepping monthly from January 31 yields February 29 (or 28), then
+  /// March 31, then April 30. A negative interval iterates backward, ending no earlier than the
+  /// end date.
+  ///
+  /// ## Panic
+  ///
+  /// This function panics if the interval is zero.
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::date;
+  /// use date::months;
+  ///
+  /// let mut dates = date! { 2024-01-31 }.iter_step_months(date! { 2024-12-31 }, months!(1));
+  /// assert_eq!(dates.next(), Some(date! { 2024-01-31 }));
+  /// assert_eq!(dates.next(), Some(date! { 2024-02-29 }));
+  /// assert_eq!(dates.next(), Some(date! { 2024-03-31 }));
+  /// assert_eq!(dates.next(), Some(date! { 2024-04-30 }));
+  /// ```
+  pub const fn iter_step_months(&self, end: Date, step: MonthInterval) -> MonthStepIterator {
+    MonthStepIterator::new(*self, end, step.months())
+  }
+
+  /// An iterator of dates beginning with this date and advancing one week at a time, ending no
+  /// later than the provided end date (inclusive).
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::date;
+  ///
+  /// let start = date! { 2024-04-01 };
+  /// let mondays: Vec<_> = start.iter_weeks_through(date! { 2024-04-30 }).collect();
+  /// assert_eq!(mondays, [
+  ///   date! { 2024-04-01 },
+  ///   date! { 2024-04-08 },
+  ///   date! { 2024-04-15 },
+  ///   date! { 2024-04-22 },
+  ///   date! { 2024-04-29 },
+  /// ]);
+  /// ```
+  pub const fn iter_weeks_through(&self, end: Date) -> DateStepIterator {
+    DateStepIterator::new(*self, end, 7)
+  }
+
+  /// An iterator of dates beginning with this date and advancing one month at a time, ending no
+  /// later than the provided end date (inclusive).
+  ///
+  /// Days that do not exist in a shorter month are clamped to the end of that month, as with
+  /// [`Date::iter_step_months`].
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::date;
+  ///
+  /// let mut dates = date! { 2023-12-31 }.iter_months_through(date! { 2024-03-31 });
+  /// assert_eq!(dates.next(), Some(date! { 2023-12-31 }));
+  /// assert_eq!(dates.next(), Some(date! { 2024-01-31 }));
+  /// assert_eq!(dates.next(), Some(date! { 2024-02-29 }));
+  /// assert_eq!(dates.next(), Some(date! { 2024-03-31 }));
+  /// assert_eq!(dates.next(), None);
+  /// ```
+  pub const fn iter_months_through(&self, end: Date) -> MonthStepIterator {
+    MonthStepIterator::new(*self, end, 1)
+  }
+
+  /// An iterator of dates beginning with this date and advancing one year at a time, ending no
+  /// later than the provided end date (inclusive).
+  ///
+  /// Beginning on February 29 yields February 28 in common years, and February 29 again in leap
+  /// years.
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::date;
+  ///
+  /// let dates: Vec<_> = date! { 2024-02-29 }.iter_years_through(date! { 2028-12-31 }).collect();
+  /// assert_eq!(dates, [
+  ///   date! { 2024-02-29 },
+  ///   date! { 2025-02-28 },
+  ///   date! { 2026-02-28 },
+  ///   date! { 2027-02-28 },
+  ///   date! { 2028-02-29 },
+  /// ]);
+  /// ```
+  pub const fn iter_years_through(&self, end: Date) -> MonthStepIterator {
+    MonthStepIterator::new(*self, end, 12)
+  }
+
+  /// An iterator of the dates falling on the given weekday, beginning on or after this date and
+  /// ending no later than the provided end date (inclusive).
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::date;
+  /// use date::Weekday;
+  ///
+  /// let start = date! { 2024-04-01 };
+  /// let fridays = start.iter_weekday_through(Weekday::Friday, date! { 2024-04-30 });
+  /// assert_eq!(fridays.len(), 4);
+  /// assert_eq!(fridays.last(), Some(date! { 2024-04-26 }));
+  /// ```
+  pub const fn iter_weekday_through(&self, weekday: Weekday, end: Date) -> DateStepIterator {
+    DateStepIterator::new(self.next_weekday_or_same(weekday), end, 7)
+  }
+}
+
+/// An iterator over dates separated by a fixed number of days.
+///
+/// This is returned by [`Date::iter_step`], [`Date::iter_weeks_through`], and
+/// [`Date::iter_weekday_through`].
+pub struct DateStepIterator {
+  cursor: Date,
+  step: i32,
+  remaining: u32,
+}
+
+impl DateStepIterator {
+  const fn new(start: Date, end: Date, step: i32) -> Self {
+    assert!(step != 0, "Step must be non-zero");
+    let span = end.0 as i64 - start.0 as i64;
+    let remaining = match span == 0 || (span > 0) == (step > 0) {
+      true => (span / step as i64) as u32 + 1,
+      false => 0,
+    };
+    Self { cursor: start, step, remaining }
+  }
+}
+
+impl Iterator for DateStepIterator {
+  type Item = Date;
+
+  fn next(&mut self) -> Option<Self::Item> {
+    if self.remaining == 0 {
+      return None;
+    }
+    let answer = self.cursor;
+    self.remaining -= 1;
+    if self.remaining > 0 {
+      self.cursor = Date(self.cursor.0 + self.step);
+    }
+    Some(answer)
+  }
+
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    let len = self.remaining as usize;
+    (len, Some(len))
+  }
+}
+
+impl ExactSizeIterator for DateStepIterator {}
+
+/// An iterator over dates separated by a fixed number of months.
+///
+/// This is returned by [`Date::iter_step_months`], [`Date::iter_months_through`], and
+/// [`Date::iter_years_through`].
+pub struct MonthStepIterator {
+  start: Date,
+  end: Date,
+  step: i32,
+  index: i64,
+}
+
+impl MonthStepIterator {
+  const fn new(start: Date, end: Date, step: i32) -> Self {
+    assert!(step != 0, "Step must be non-zero");
+    Self { start, end, step, index: 0 }
+  }
+}
+
+impl Iterator for MonthStepIterator {
+  type Item = Date;
+
+  fn next(&mut self) -> Option<Self::Item> {
+    let months = i32::try_from(self.index * self.step as i64).ok()?;
+    let date = self.start.checked_add_months(MonthInterval::checked_new(months)?)?;
+    let done = match self.step > 0 {
+      true => date > self.end,
+      false => date < self.end,
+    };
+    match done {
+      true => None,
+      false => {
+        self.index += 1;
+        Some(date)
+      },
+    }
+  }
+}
+
+/// An iterator that yields only the working days of a [`Workweek`].
+pub struct WorkdayIterator {
+  dates: DateIterator,
+  workweek: Workweek,
+}
+
+impl WorkdayIterator {
+  pub(crate) const fn new(dates: DateIterator, workweek: Workweek) -> Self {
+    Self { dates, workweek }
+  }
+}
+
+impl Iterator for WorkdayIterator {
+  type Item = Date;
+
+  fn next(&mut self) -> Option<Self::Item> {
+    let workweek = self.workweek;
+    self.dates.find(|d| workweek.is_workday(*d))
+  }
 }
 
 #[cfg(test)]
@@ -46,4 +343,207 @@ mod tests {
     check!(start.iter_through(date! { 2012-04-20 }).collect::<Vec<Date>>().is_empty());
     check!(start.iter_through(Date::MAX).next().unwrap() == date! { 2012-04-21 });
   }
+
+  #[test]
+  fn test_iter_rev() {
+    let (start, end) = (date! { 2024-02-27 }, date! { 2024-03-01 });
+    let forward: Vec<Date> = start.iter_through(end).collect();
+    let mut backward: Vec<Date> = start.iter_through(end).rev().collect();
+    backward.reverse();
+    check!(forward == backward);
+    check!(start.iter_through(date! { 2024-02-26 }).next_back() == None);
+    check!(Date::MIN.iter_through(Date::MIN).rev().collect::<Vec<_>>() == [Date::MIN]);
+
+    // Consuming from both ends meets in the middle.
+    let mut dates = start.iter_through(end);
+    check!(dates.next() == Some(date! { 2024-02-27 }));
+    check!(dates.next_back() == Some(date! { 2024-03-01 }));
+    check!(dates.next_back() == Some(date! { 2024-02-29 }));
+    check!(dates.next() == Some(date! { 2024-02-28 }));
+    check!(dates.next() == None);
+    check!(dates.next_back() == None);
+  }
+
+  #[test]
+  fn test_iter_back_through() {
+    let (start, end) = (date! { 2024-02-27 }, date! { 2024-03-01 });
+    let mut forward: Vec<Date> = start.iter_through(end).collect();
+    forward.reverse();
+    check!(end.iter_back_through(start).collect::<Vec<_>>() == forward);
+    check!(end.iter_back_through(end).collect::<Vec<_>>() == [end]);
+    check!(start.iter_back_through(end).next() == None);
+    check!(end.iter_back_through(start).len() == 4);
+    check!(Date::MIN.iter_back_through(Date::MIN).next() == Some(Date::MIN));
+    let mut dates = date! { -32768-01-02 }.iter_back_through(Date::MIN);
+    check!(dates.next() == Some(date! { -32768-01-02 }));
+    check!(dates.next() == Some(Date::MIN));
+    check!(dates.next() == None);
+  }
+
+  #[test]
+  fn test_iter_from() {
+    let start = date! { 2024-12-30 };
+    let dates: Vec<Date> = start.iter_from().take(3).collect();
+    check!(dates == [date! { 2024-12-30 }, date! { 2024-12-31 }, date! { 2025-01-01 }]);
+    check!(start.iter_from().take_while(|d| d.year() == 2024).count() == 2);
+    check!(Date::MAX.iter_from().collect::<Vec<_>>() == [Date::MAX]);
+    let mut dates = Date::MAX.iter_from();
+    check!(dates.next() == Some(Date::MAX));
+    check!(dates.next() == None);
+    check!(dates.next_back() == None);
+    check!(dates.len() == 0);
+  }
+
+  #[test]
+  fn test_iter_weekday_through() {
+    let (start, end) = (date! { 2024-04-19 }, date! { 2024-05-03 });
+    let fridays: Vec<Date> = start.iter_weekday_through(Weekday::Friday, end).collect();
+    check!(fridays == [date! { 2024-04-19 }, date! { 2024-04-26 }, date! { 2024-05-03 }]);
+    let mondays: Vec<Date> = start.iter_weekday_through(Weekday::Monday, end).collect();
+    check!(mondays == [date! { 2024-04-22 }, date! { 2024-04-29 }]);
+    check!(start.iter_weekday_through(Weekday::Monday, date! { 2024-04-21 }).len() == 0);
+    check!(Date::MAX.iter_weekday_through(Weekday::Monday, Date::MAX).len() <= 1);
+    for weekday in Weekday::all(Weekday::Sunday) {
+      let expected = start.iter_through(end).filter(|d| d.weekday() == weekday);
+      check!(start.iter_weekday_through(weekday, end).eq(expected));
+    }
+  }
+
+  #[test]
+  fn test_iter_len() {
+    let mut dates = date! { 2024-01-01 }.iter_through(date! { 2024-12-31 });
+    check!(dates.len() == 366);
+    check!(dates.size_hint() == (366, Some(366)));
+    dates.next();
+    dates.next_back();
+    check!(dates.len() == 364);
+    check!(dates.collect::<Vec<_>>().capacity() >= 364);
+    check!(date! { 2024-01-02 }.iter_through(date! { 2024-01-01 }).len() == 0);
+    check!(Date::MIN.iter_through(Date::MAX).len() == 23_936_532);
+  }
+
+  #[test]
+  fn test_iter_step() {
+    let start = date! { 2024-01-01 };
+    let dates: Vec<Date> = start.iter_step(date! { 2024-01-31 }, DateInterval::new(10)).collect();
+    check!(dates.iter().map(Date::day).collect::<Vec<_>>() == [1, 11, 21, 31]);
+    let dates = start.iter_step(date! { 2024-01-30 }, DateInterval::new(10));
+    check!(dates.len() == 3);
+    check!(start.iter_step(start, DateInterval::new(7)).collect::<Vec<_>>() == [start]);
+    check!(start.iter_step(date! { 2023-12-31 }, DateInterval::new(1)).next() == None);
+
+    // Negative steps iterate backward.
+    let dates: Vec<Date> = start.iter_step(date! { 2023-12-25 }, DateInterval::new(-3)).collect();
+    check!(dates == [date! { 2024-01-01 }, date! { 2023-12-29 }, date! { 2023-12-26 }]);
+    check!(start.iter_step(date! { 2024-01-02 }, DateInterval::new(-1)).next() == None);
+
+    // Steps that overshoot the range of dates stop cleanly.
+    let mut dates = Date::MAX.iter_step(Date::MIN, DateInterval::new(i32::MIN));
+    check!(dates.next() == Some(Date::MAX));
+    check!(dates.next() == None);
+    check!(Date::MIN.iter_step(Date::MAX, DateInterval::new(1)).len() == 23_936_532);
+    let dates = date! { 2024-01-01 }.iter_step(date! { 2024-12-31 }, DateInterval::new(1));
+    check!(dates.eq(date! { 2024-01-01 }.iter_through(date! { 2024-12-31 })));
+  }
+
+  #[test]
+  #[should_panic]
+  fn test_iter_step_zero() {
+    date! { 2024-01-01 }.iter_step(date! { 2024-01-31 }, DateInterval::new(0));
+  }
+
+  #[test]
+  fn test_iter_step_months() {
+    let dates: Vec<Date> =
+      date! { 2024-01-31 }.iter_step_months(date! { 2024-06-30 }, MonthInterval::new(1)).collect();
+    check!(
+      dates
+        == [
+          date! { 2024-01-31 },
+          date! { 2024-02-29 },
+          date! { 2024-03-31 },
+          date! { 2024-04-30 },
+          date! { 2024-05-31 },
+          date! { 2024-06-30 },
+        ]
+    );
+    let dates: Vec<Date> =
+      date! { 2024-01-15 }.iter_step_months(date! { 2025-01-14 }, MonthInterval::new(3)).collect();
+    check!(
+      dates
+        == [
+          date! { 2024-01-15 },
+          date! { 2024-04-15 },
+          date! { 2024-07-15 },
+          date! { 2024-10-15 },
+        ]
+    );
+    let start = date! { 2024-03-31 };
+    let dates: Vec<Date> =
+      start.iter_step_months(date! { 2023-12-01 }, MonthInterval::new(-1)).collect();
+    check!(
+      dates
+        == [
+          date! { 2024-03-31 },
+          date! { 2024-02-29 },
+          date! { 2024-01-31 },
+          date! { 2023-12-31 },
+        ]
+    );
+    let start = date! { 2024-01-01 };
+    check!(start.iter_step_months(date! { 2023-12-31 }, MonthInterval::new(1)).next() == None);
+
+    // Iteration stops at the end of the range of dates.
+    let months = MonthInterval::new(MonthInterval::MAX_MONTHS);
+    check!(start.iter_step_months(Date::MAX, months).count() == 1);
+    let start = date! { 32767-10-31 };
+    check!(start.iter_step_months(Date::MAX, MonthInterval::new(1)).count() == 3);
+  }
+
+  #[test]
+  #[should_panic]
+  fn test_iter_step_months_zero() {
+    date! { 2024-01-01 }.iter_step_months(date! { 2024-12-31 }, MonthInterval::new(0));
+  }
+
+  #[test]
+  fn test_iter_units() {
+    let start = date! { 2024-01-31 };
+    let weeks: Vec<Date> = start.iter_weeks_through(date! { 2024-02-20 }).collect();
+    check!(weeks == [date! { 2024-01-31 }, date! { 2024-02-07 }, date! { 2024-02-14 }]);
+    check!(start.iter_weeks_through(date! { 2024-01-30 }).len() == 0);
+
+    let months: Vec<Date> = start.iter_months_through(date! { 2024-05-30 }).collect();
+    check!(
+      months
+        == [
+          date! { 2024-01-31 },
+          date! { 2024-02-29 },
+          date! { 2024-03-31 },
+          date! { 2024-04-30 },
+        ]
+    );
+    check!(start.iter_months_through(start).collect::<Vec<_>>() == [start]);
+
+    let years: Vec<Date> = date! { 2023-02-28 }.iter_years_through(date! { 2026-02-27 }).collect();
+    check!(years == [date! { 2023-02-28 }, date! { 2024-02-28 }, date! { 2025-02-28 }]);
+    check!(date! { 32767-06-30 }.iter_years_through(Date::MAX).count() == 1);
+  }
+
+  #[test]
+  fn test_iter_workdays() {
+    let (start, end) = (date! { 2024-04-18 }, date! { 2024-04-23 });
+    check!(
+      Workweek::MONDAY_TO_FRIDAY.iter_workdays(start, end).collect::<Vec<Date>>()
+        == vec![date! { 2024-04-18 }, date! { 2024-04-19 }, date! { 2024-04-22 }, date! {
+          2024-04-23
+        }]
+    );
+    check!(
+      Workweek::SUNDAY_TO_THURSDAY.iter_workdays(start, end).collect::<Vec<Date>>()
+        == vec![date! { 2024-04-18 }, date! { 2024-04-21 }, date! { 2024-04-22 }, date! {
+          2024-04-23
+        }]
+    );
+  }
 }
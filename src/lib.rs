@@ -21,14 +21,67 @@
 //!
 //! let date = date! { 2012-04-21 };
 //! ```
+//!
+//! ## `no_std`
+//!
+//! This crate supports `no_std` environments by disabling the default `std` feature. The core
+//! `Date` type, its arithmetic, formatting (which writes to any [`core::fmt::Write`]), and
+//! ISO 8601 parsing (using [`Date::parse_iso8601`]) remain available. The `alloc` feature
+//! additionally enables the `holiday` and `recurrence` modules, `DateMap`, `DateSet`, and
+//! `GroupByPeriod`.
+//!
+//! Reading the system clock, parsing with `strptime` format strings, and time zone support require
+//! the `std` feature.
+//!
+//! ## Panics
+//!
+//! Constructors that are commonly called with literal values panic when given invalid input, so
+//! that mistakes are caught at compile time in `const` contexts. Each has a non-panicking
+//! counterpart for validating untrusted input:
+//!
+//! | Panicking                          | Non-panicking                              |
+//! | ---------------------------------- | ------------------------------------------ |
+//! | [`Date::new`]                      | [`Date::checked_new`], [`Date::try_new`]   |
+//! | [`Date::from_unix_days`]           | [`Date::checked_from_unix_days`]           |
+//! | [`Date::from_filetime`]            | [`Date::checked_from_filetime`]            |
+//! | `Date::from_ymd_batch`             | `Date::checked_from_ymd_batch`             |
+//! | `Date::today`                      | `Date::try_today`                          |
+//! | `Date::yesterday`                  | `Date::try_yesterday`                      |
+//! | `Date::tomorrow`                   | `Date::try_tomorrow`                       |
+//! | `date.format(..).to_string()`      | [`Date::try_format`]                       |
+//! | [`IsoWeekDate::new`]               | [`IsoWeekDate::checked_new`]               |
+//! | [`NthWeekdayRule::new`]            | [`NthWeekdayRule::checked_new`]            |
+//! | [`Workweek::new`]                  | [`Workweek::checked_new`]                  |
+//! | [`interval::MonthInterval::new`]   | [`interval::MonthInterval::checked_new`]   |
+//! | `holiday::HolidayTable::new`       | `holiday::HolidayTable::checked_new`       |
+//! | `Date::from_julian_calendar`       | `calendar::Julian::to_gregorian`           |
+//! | `Date::from_hijri`                 | `calendar::Islamic::to_gregorian`          |
+//!
+//! Formatting a date with an unrecognized token returns [`fmt::Error`] rather than panicking, but
+//! methods such as `to_string` panic when a `Display` implementation returns an error.
+//!
+//! Arithmetic that overflows the range of dates between [`Date::MIN`] and [`Date::MAX`] also
+//! panics. This covers the operators and their `const` equivalents alike: adding or subtracting a
+//! [`DateInterval`](interval::DateInterval) ([`Date::add_days`]), a
+//! [`MonthInterval`](interval::MonthInterval) ([`Date::add_months`]), or a
+//! [`CalendarDuration`](interval::CalendarDuration). The corresponding `checked_` methods (such as
+//! [`Date::checked_add_days`]) return `None` instead. [`Date::from_timestamp`] does not check its
+//! input, so a timestamp outside that range yields an unspecified date rather than a panic.
+
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 
-use std::fmt;
-use std::str::FromStr;
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+use core::fmt;
+#[cfg(feature = "std")]
+use core::str::FromStr;
+#[cfg(feature = "std")]
 use std::time::SystemTime;
+#[cfg(feature = "std")]
 use std::time::UNIX_EPOCH;
 
-use strptime::ParseError;
-use strptime::ParseResult;
+#[cfg(feature = "std")]
 use strptime::Parser;
 
 /// Time zone compnents (re-exported from `tzdb` crate).
@@ -39,9 +92,9 @@ pub mod tz {
 
   /// The result type for evaluating a specific timestamp against a time zone.
   ///
-  /// Errors occur primarily when the timestamp in question is for a time when the time zone did
-  /// not exist.
-  pub type TzResult<T> = Result<T, ::tz::error::FindLocalTimeTypeError>;
+  /// Errors ([`DateError::NoLocalTime`](crate::DateError::NoLocalTime)) occur primarily when the
+  /// timestamp in question is for a time when the time zone did not exist.
+  pub type TzResult<T> = Result<T, crate::DateError>;
 
   pub use tzdb::time_zone::africa;
   pub use tzdb::time_zone::america;
@@ -66,27 +119,151 @@ pub mod tz {
 /// assert_eq!(d.month(), 1);
 /// assert_eq!(d.day(), 1);
 /// ```
+///
+/// Years before 1 CE are written with a leading minus sign, and any component can be a constant
+/// expression in parentheses:
+///
+/// ```
+/// # use date::date;
+/// let ides = date! { -0044-03-15 };
+/// assert_eq!(ides.year(), -44);
+///
+/// const YEAR: i16 = 2024;
+/// let d = date! { (YEAR)-(1 + 1)-29 };
+/// assert_eq!(d, date! { 2024-02-29 });
+/// ```
+///
+/// The date is constructed at compile time, so an invalid date is a compile error rather than a
+/// runtime panic:
+///
+/// ```compile_fail
+/// # use date::date;
+/// let d = date! { 2023-02-29 };
+/// ```
 #[macro_export]
 macro_rules! date {
-  ($y:literal-$m:literal-$d:literal) => {{
-    #[allow(clippy::zero_prefixed_literal)]
-    {
-      $crate::Date::new($y, $m, $d)
-    }
+  (-$y:tt-$m:tt-$d:tt) => {{
+    #[allow(clippy::zero_prefixed_literal, unused_parens)]
+    const DATE: $crate::Date = $crate::Date::new(-$y, $m, $d);
+    DATE
+  }};
+  ($y:tt-$m:tt-$d:tt) => {{
+    #[allow(clippy::zero_prefixed_literal, unused_parens)]
+    const DATE: $crate::Date = $crate::Date::new($y, $m, $d);
+    DATE
   }};
 }
 
+mod anniversary;
+#[cfg(feature = "astro")]
+mod astro;
+#[cfg(feature = "alloc")]
+mod batch;
+#[cfg(feature = "calendars")]
+pub mod calendar;
+mod cron;
+#[cfg(feature = "alloc")]
+mod date_map;
+#[cfg(feature = "alloc")]
+mod date_set;
+pub mod day_count;
 #[cfg(feature = "diesel-pg")]
 mod db;
+#[cfg(feature = "tz")]
+mod default_tz;
+mod error;
+mod fields;
 mod format;
+mod gps;
+#[cfg(feature = "alloc")]
+mod group;
+mod half_year;
+#[cfg(feature = "alloc")]
+pub mod holiday;
+#[cfg(feature = "ids")]
+mod ids;
 pub mod interval;
+mod iso_week;
 pub mod iter;
+#[cfg(feature = "i18n")]
+mod locale;
+mod month;
+mod month_day;
+mod quarter;
+mod range;
+#[cfg(feature = "alloc")]
+pub mod recurrence;
+mod rule;
+mod season;
 #[cfg(feature = "serde")]
 mod serde;
+mod tenor;
+#[cfg(all(feature = "std", any(test, feature = "test-util")))]
+pub mod test;
+mod unit;
 mod utils;
+mod week;
 mod weekday;
+mod windows;
+mod workweek;
+mod year;
+mod year_month;
 
+pub use anniversary::Feb29Policy;
+#[cfg(feature = "astro")]
+pub use astro::LunarPhase;
+#[cfg(feature = "astro")]
+pub use astro::MoonPhase;
+pub use cron::CronSchedule;
+pub use cron::ParseCronError;
+#[cfg(feature = "alloc")]
+pub use date_map::DateMap;
+#[cfg(feature = "alloc")]
+pub use date_map::MissingDay;
+#[cfg(feature = "alloc")]
+pub use date_set::DateSet;
+#[cfg(feature = "tz")]
+pub use default_tz::clear_default_timezone;
+#[cfg(feature = "tz")]
+pub use default_tz::default_timezone;
+#[cfg(feature = "tz")]
+pub use default_tz::set_default_timezone;
+#[cfg(feature = "tz")]
+pub use default_tz::UnknownTimeZoneError;
+pub use error::DateError;
+pub use error::ParseDetail;
+pub use fields::DateFields;
+pub use format::ExpandedIsoDate;
+pub use format::IsoDateString;
+#[cfg(feature = "alloc")]
+pub use group::GroupByPeriod;
+#[cfg(feature = "alloc")]
+pub use group::PeriodGroups;
+pub use half_year::HalfYear;
+pub use half_year::ParseHalfYearError;
+pub use iso_week::IsoWeekDate;
+pub use iso_week::ParseIsoWeekDateError;
+#[cfg(feature = "i18n")]
+pub use locale::Locale;
+pub use month::InvalidMonthError;
+pub use month::Month;
+pub use month_day::MonthDay;
+pub use quarter::ParseQuarterError;
+pub use quarter::Quarter;
+pub use range::DateRange;
+pub use rule::NthWeekdayRule;
+pub use season::Hemisphere;
+pub use season::Season;
+pub use tenor::ParseTenorError;
+pub use tenor::Tenor;
+pub use unit::DateUnit;
+pub use week::WeekPolicy;
+pub use weekday::InvalidWeekdayError;
 pub use weekday::Weekday;
+pub use workweek::Weekend;
+pub use workweek::Workweek;
+pub use year::Year;
+pub use year_month::YearMonth;
 
 /// A representation of a single date.
 #[derive(Copy, Clone, Eq, PartialEq, PartialOrd, Ord)]
@@ -120,18 +297,84 @@ impl Date {
     if month == 2 && day == 29 {
       assert!(utils::is_leap_year(year), "February 29 only occurs on leap years")
     }
+    Self(utils::days_from_civil(year, month, day))
+  }
+
+  /// Construct a new `Date` from the provided year, month, and day, or return `None` if they do
+  /// not form a valid date.
+  ///
+  /// This is the non-panicking counterpart to [`Date::new`].
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::date;
+  /// use date::Date;
+  ///
+  /// assert_eq!(Date::checked_new(2024, 2, 29), Some(date! { 2024-02-29 }));
+  /// assert_eq!(Date::checked_new(2023, 2, 29), None);
+  /// assert_eq!(Date::checked_new(2023, 13, 1), None);
+  /// ```
+  pub const fn checked_new(year: i16, month: u8, day: u8) -> Option<Self> {
+    match utils::is_valid_date(year, month, day) {
+      true => Some(Self(utils::days_from_civil(year, month, day))),
+      false => None,
+    }
+  }
+
+  /// Construct a new `Date` from the provided year, month, and day, or return a [`DateError`]
+  /// describing which component is out of range.
+  ///
+  /// This is the same as [`Date::checked_new`], but reports why the date is invalid.
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::date;
+  /// use date::Date;
+  /// use date::DateError;
+  ///
+  /// assert_eq!(Date::try_new(2024, 2, 29), Ok(date! { 2024-02-29 }));
+  /// assert_eq!(Date::try_new(2023, 13, 1), Err(DateError::MonthOutOfRange { month: 13 }));
+  /// assert_eq!(
+  ///   Date::try_new(2023, 2, 29),
+  ///   Err(DateError::DayOutOfRange { year: 2023, month: 2, day: 29 }),
+  /// );
+  /// ```
+  pub const fn try_new(year: i16, month: u8, day: u8) -> Result<Self, DateError> {
+    if month < 1 || month > 12 {
+      return Err(DateError::MonthOutOfRange { month });
+    }
+    match day >= 1 && day <= utils::days_in_month(year, month) {
+      true => Ok(Self(utils::days_from_civil(year, month, day))),
+      false => Err(DateError::DayOutOfRange { year, month, day }),
+    }
+  }
 
-    // The algorithm to convert from a civil year/month/day to the number of days that have elapsed
-    // since the epoch is taken from here:
-    // https://howardhinnant.github.io/date_algorithms.html#days_from_civil
-    let year = year as i32 - if month <= 2 { 1 } else { 0 };
-    let month = month as i32;
-    let day = day as i32;
-    let era: i32 = if year >= 0 { year } else { year - 399 } / 400;
-    let year_of_era = year - era * 400;
-    let day_of_year = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day - 1;
-    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
-    Self(era * 146097 + day_of_era - 719468)
+  /// Construct a new `Date` from the provided year and day of the year (between 1 and 365, or 366
+  /// in leap years).
+  ///
+  /// This is the inverse of [`Date::day_of_year`].
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::date;
+  /// use date::Date;
+  /// use date::DateError;
+  ///
+  /// assert_eq!(Date::from_ordinal(2012, 112), Ok(date! { 2012-04-21 }));
+  /// assert_eq!(Date::from_ordinal(2024, 366), Ok(date! { 2024-12-31 }));
+  /// assert_eq!(
+  ///   Date::from_ordinal(2023, 366),
+  ///   Err(DateError::OrdinalOutOfRange { year: 2023, ordinal: 366 }),
+  /// );
+  /// ```
+  pub const fn from_ordinal(year: i16, ordinal: u16) -> Result<Self, DateError> {
+    match ordinal >= 1 && ordinal <= utils::days_in_year(year) {
+      true => Ok(Self(utils::days_from_civil(year, 1, 1) + ordinal as i32 - 1)),
+      false => Err(DateError::OrdinalOutOfRange { year, ordinal }),
+    }
   }
 
   /// Construct a new `Date` based on the Unix timestamp.
@@ -163,6 +406,53 @@ impl Date {
     Self(day_count)
   }
 
+  /// Construct a new `Date` from the number of days since the Unix epoch (January 1, 1970).
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::date;
+  /// use date::Date;
+  ///
+  /// assert_eq!(Date::from_unix_days(15_451), date! { 2012-04-21 });
+  /// assert_eq!(Date::from_unix_days(-1), date! { 1969-12-31 });
+  /// ```
+  ///
+  /// ## Panic
+  ///
+  /// This function panics if the resulting date would fall outside the range between
+  /// [`Date::MIN`] and [`Date::MAX`]; use [`Date::checked_from_unix_days`] to validate untrusted
+  /// input.
+  #[inline]
+  pub const fn from_unix_days(days: i32) -> Self {
+    match Self::checked_from_unix_days(days) {
+      Some(date) => date,
+      None => panic!("Date out-of-bounds"),
+    }
+  }
+
+  /// Construct a new `Date` from the number of days since the Unix epoch (January 1, 1970), or
+  /// return `None` if the resulting date would fall outside the range between [`Date::MIN`] and
+  /// [`Date::MAX`].
+  ///
+  /// This is the non-panicking counterpart to [`Date::from_unix_days`].
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::date;
+  /// use date::Date;
+  ///
+  /// assert_eq!(Date::checked_from_unix_days(15_451), Some(date! { 2012-04-21 }));
+  /// assert_eq!(Date::checked_from_unix_days(i32::MAX), None);
+  /// ```
+  pub const fn checked_from_unix_days(days: i32) -> Option<Self> {
+    match days >= Self::MIN.0 && days <= Self::MAX.0 {
+      true => Some(Self(days)),
+      false => None,
+    }
+  }
+
   /// The date on which the given timestamp occurred in the provided time zone.
   #[cfg(feature = "tz")]
   pub const fn from_timestamp_tz(
@@ -170,7 +460,7 @@ impl Date {
   ) -> tz::TzResult<Self> {
     match tz.find_local_time_type(unix_timestamp) {
       Ok(tz) => Ok(Self::from_timestamp(unix_timestamp + tz.ut_offset() as i64)),
-      Err(e) => Err(e),
+      Err(_) => Err(DateError::NoLocalTime),
     }
   }
 
@@ -220,29 +510,89 @@ impl Date {
   }
 
   /// Parse a date from a string, according to the provided format string.
-  pub fn parse(date_str: impl AsRef<str>, date_fmt: &'static str) -> ParseResult<Date> {
+  ///
+  /// Returns [`DateError::Parse`] if the string does not match the format string, or a
+  /// [`DateError`] describing the out-of-range component if it does not form a valid date.
+  #[cfg(feature = "std")]
+  pub fn parse(date_str: impl AsRef<str>, date_fmt: &'static str) -> Result<Date, DateError> {
     let parser = Parser::new(date_fmt);
     let raw_date = parser.parse(date_str)?.date()?;
-    Ok(raw_date.into())
+    Self::try_new(raw_date.year(), raw_date.month(), raw_date.day())
+  }
+
+  /// Parse a date from an ISO 8601 (`YYYY-MM-DD`) string.
+  ///
+  /// The year may be preceded by a sign, and must be at least four characters long (including the
+  /// sign), which accepts the output of [`Date::to_iso_string`]. Returns `None` if the string is
+  /// not a valid date. Unlike [`Date::parse`], this does not require the `std` feature.
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::date;
+  /// use date::Date;
+  ///
+  /// assert_eq!(Date::parse_iso8601("2012-04-21"), Some(date! { 2012-04-21 }));
+  /// assert_eq!(Date::parse_iso8601("2012-02-30"), None);
+  /// ```
+  pub const fn parse_iso8601(date_str: &str) -> Option<Date> {
+    let bytes = date_str.as_bytes();
+    let (negative, start) = match bytes {
+      [b'-', ..] => (true, 1),
+      [b'+', ..] => (false, 1),
+      _ => (false, 0),
+    };
+    let len = bytes.len();
+    if len < 10 || len - 6 <= start || bytes[len - 6] != b'-' || bytes[len - 3] != b'-' {
+      return None;
+    }
+    let (Some(year), Some(month), Some(day)) = (
+      utils::parse_digits(bytes, start, len - 6),
+      utils::parse_digits(bytes, len - 5, len - 3),
+      utils::parse_digits(bytes, len - 2, len),
+    ) else {
+      return None;
+    };
+    let year = if negative { -year } else { year };
+    if year < i16::MIN as i32 || year > i16::MAX as i32 || month < 1 || month > 12 {
+      return None;
+    }
+    match day >= 1 && day <= utils::days_in_month(year as i16, month as u8) as i32 {
+      true => Some(Date::new(year as i16, month as u8, day as u8)),
+      false => None,
+    }
+  }
+
+  /// Parse a date from an ISO 8601 expanded representation (`±YYYYYY-MM-DD`), in which the year
+  /// has an explicit sign and exactly the given number of digits.
+  ///
+  /// This is the counterpart to [`Date::to_iso_expanded`]. Returns `None` if the string is not a
+  /// valid date, lacks a sign, or has a different number of year digits.
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::date;
+  /// use date::Date;
+  ///
+  /// assert_eq!(Date::parse_iso8601_expanded("+002024-04-21", 6), Some(date! { 2024-04-21 }));
+  /// assert_eq!(Date::parse_iso8601_expanded("-000044-03-15", 6), Some(date! { -0044-03-15 }));
+  /// assert_eq!(Date::parse_iso8601_expanded("2024-04-21", 6), None);
+  /// assert_eq!(Date::parse_iso8601_expanded("+2024-04-21", 6), None);
+  /// ```
+  pub const fn parse_iso8601_expanded(date_str: &str, year_digits: u8) -> Option<Date> {
+    let bytes = date_str.as_bytes();
+    if bytes.len() != year_digits as usize + 7 || !matches!(bytes[0], b'+' | b'-') {
+      return None;
+    }
+    Self::parse_iso8601(date_str)
   }
 }
 
 impl Date {
   /// The year, month, and day for the given date.
   pub(crate) const fn ymd(&self) -> (i16, u8, u8) {
-    // The algorithm to convert from a civil year/month/day to the number of days that have elapsed
-    // since the epoch is taken from here:
-    // https://howardhinnant.github.io/date_algorithms.html#civil_from_days
-    let shifted = self.0 + 719468; // Days from March 1, 0 A.D.
-    let era = if shifted >= 0 { shifted } else { shifted - 146_096 } / 146_097;
-    let doe = shifted - era * 146_097; // day of era: [0, 146_097)
-    let year_of_era = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
-    let year = year_of_era + era * 400;
-    let day_of_year = doe - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
-    let mp = (5 * day_of_year + 2) / 153;
-    let day = day_of_year - (153 * mp + 2) / 5 + 1;
-    let month = if mp < 10 { mp + 3 } else { mp - 9 };
-    (year as i16 + if month <= 2 { 1 } else { 0 }, month as u8, day as u8)
+    utils::civil_from_days(self.0)
   }
 
   /// Returns the year number in the calendar date.
@@ -270,17 +620,37 @@ impl Date {
   /// The day of the current year. Range: `[1, 366]`
   #[inline]
   pub const fn day_of_year(&self) -> u16 {
-    (self.0 - Date::new(self.year() - 1, 12, 31).0) as u16
+    (self.0 - Date::new(self.year(), 1, 1).0 + 1) as u16
   }
 
   /// The week number of the year (between 0 and 53, inclusive), with a new week starting each
   /// Sunday.
   ///
   /// Week 1 begins on the first Sunday of the year; leading days before that are part of week 0.
+  /// This is the same as [`WeekPolicy::FIRST_SUNDAY`]; see [`Date::week_number`] for other
+  /// numbering conventions.
   pub const fn week(&self) -> u16 {
-    let jan1 = Date::new(self.year(), 1, 1);
-    let first_sunday = jan1.0 + if self.0 % 7 == 3 { 0 } else { 7 } - (self.0 + 4) % 7;
-    ((self.0 - first_sunday).div_euclid(7) + 1) as u16
+    self.fields().week()
+  }
+
+  /// The week number of the year (between 0 and 53, inclusive), with a new week starting on the
+  /// given day.
+  ///
+  /// Week 1 begins on the first occurrence of that day in the year; leading days before that are
+  /// part of week 0. [`Date::week`] is the same as `week_starting(Weekday::Sunday)`.
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::date;
+  /// use date::Weekday;
+  ///
+  /// let date = date! { 2024-01-08 }; // Monday
+  /// assert_eq!(date.week_starting(Weekday::Sunday), 1);
+  /// assert_eq!(date.week_starting(Weekday::Monday), 2);
+  /// ```
+  pub const fn week_starting(&self, first_day: Weekday) -> u16 {
+    self.week_number(WeekPolicy::calendar_year(first_day, 7)) as u16
   }
 
   /// Return the weekday corresponding to the given date.
@@ -298,6 +668,120 @@ impl Date {
       _ => panic!("Unreachable: Anything % 7 must be within -6 to 6"),
     }
   }
+
+  /// This date with the year replaced, or a [`DateError`] if the result is not a valid date.
+  ///
+  /// The month and day are never adjusted: replacing the year of February 29 with a year that is
+  /// not a leap year returns [`DateError::DayOutOfRange`]. (To move February 29 onto February 28
+  /// or March 1 instead, see [`Feb29Policy`].)
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::date;
+  /// use date::DateError;
+  ///
+  /// assert_eq!(date! { 2012-04-21 }.with_year(2024), Ok(date! { 2024-04-21 }));
+  /// assert_eq!(
+  ///   date! { 2024-02-29 }.with_year(2023),
+  ///   Err(DateError::DayOutOfRange { year: 2023, month: 2, day: 29 }),
+  /// );
+  /// ```
+  pub const fn with_year(&self, year: i16) -> Result<Self, DateError> {
+    let (_, month, day) = self.ymd();
+    Self::try_new(year, month, day)
+  }
+
+  /// This date with the month replaced, or a [`DateError`] if the result is not a valid date.
+  ///
+  /// The day is never adjusted, so replacing the month of January 31 with April returns
+  /// [`DateError::DayOutOfRange`].
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::date;
+  /// use date::DateError;
+  ///
+  /// assert_eq!(date! { 2012-04-21 }.with_month(7), Ok(date! { 2012-07-21 }));
+  /// let err = DateError::MonthOutOfRange { month: 13 };
+  /// assert_eq!(date! { 2012-04-21 }.with_month(13), Err(err));
+  /// assert!(date! { 2012-01-31 }.with_month(4).is_err());
+  /// ```
+  pub const fn with_month(&self, month: u8) -> Result<Self, DateError> {
+    let (year, _, day) = self.ymd();
+    Self::try_new(year, month, day)
+  }
+
+  /// This date with the day of the month replaced, or a [`DateError`] if the result is not a valid
+  /// date.
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::date;
+  ///
+  /// assert_eq!(date! { 2012-04-21 }.with_day(1), Ok(date! { 2012-04-01 }));
+  /// assert!(date! { 2012-04-21 }.with_day(31).is_err());
+  /// ```
+  pub const fn with_day(&self, day: u8) -> Result<Self, DateError> {
+    let (year, month, _) = self.ymd();
+    Self::try_new(year, month, day)
+  }
+
+  /// The first day of this date's month.
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::date;
+  ///
+  /// assert_eq!(date! { 2012-04-21 }.first_of_month(), date! { 2012-04-01 });
+  /// ```
+  pub const fn first_of_month(&self) -> Self {
+    Self(self.0 - self.day() as i32 + 1)
+  }
+
+  /// The last day of this date's month.
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::date;
+  ///
+  /// assert_eq!(date! { 2012-04-21 }.last_of_month(), date! { 2012-04-30 });
+  /// assert_eq!(date! { 2012-02-01 }.last_of_month(), date! { 2012-02-29 });
+  /// ```
+  pub const fn last_of_month(&self) -> Self {
+    let (year, month, day) = self.ymd();
+    Self(self.0 + (utils::days_in_month(year, month) - day) as i32)
+  }
+
+  /// The first day of this date's year.
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::date;
+  ///
+  /// assert_eq!(date! { 2012-04-21 }.first_of_year(), date! { 2012-01-01 });
+  /// ```
+  pub const fn first_of_year(&self) -> Self {
+    Self(self.0 - self.day_of_year() as i32 + 1)
+  }
+
+  /// The last day of this date's year.
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::date;
+  ///
+  /// assert_eq!(date! { 2012-04-21 }.last_of_year(), date! { 2012-12-31 });
+  /// ```
+  pub const fn last_of_year(&self) -> Self {
+    Self::new(self.year(), 12, 31)
+  }
 }
 
 impl Date {
@@ -316,12 +800,26 @@ impl Date {
     self.0 as i64 * 86_400
   }
 
+  /// The number of days since the Unix epoch (January 1, 1970).
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// # use date::date;
+  /// assert_eq!(date! { 1969-12-31 }.unix_days(), -1);
+  /// assert_eq!(date! { 2012-04-21 }.unix_days(), 15_451);
+  /// ```
+  #[inline]
+  pub const fn unix_days(&self) -> i32 {
+    self.0
+  }
+
   /// The Unix timestamp for this date at midnight in the given time zone.
   #[cfg(feature = "tz")]
   pub const fn timestamp_tz(&self, tz: tz::TimeZoneRef<'static>) -> tz::TzResult<i64> {
     match tz.find_local_time_type(self.timestamp()) {
       Ok(ts) => Ok(self.timestamp() - ts.ut_offset() as i64),
-      Err(e) => Err(e),
+      Err(_) => Err(DateError::NoLocalTime),
     }
   }
 }
@@ -329,39 +827,119 @@ impl Date {
 impl Date {
   /// The date representing today, according to the system local clock.
   ///
+  /// The date is determined in the process-wide default time zone if one has been set with
+  /// [`set_default_timezone`], and otherwise in the system's local time zone.
+  ///
   /// ## Panic
   ///
-  /// This function will panic if the system clock is set to a time prior to January 1, 1970, or if
-  /// the local time zone can not be determined.
+  /// This function will panic if the local time zone can not be determined, or lacks information
+  /// for the current time. Use [`Date::try_today`] to handle this case.
   #[cfg(feature = "tz")]
   pub fn today() -> Self {
-    let tz = tzdb::local_tz().expect("Could not determine local time zone");
-    let now =
-      now().duration_since(UNIX_EPOCH).expect("system time set prior to 1970").as_secs() as i64;
-    let offset = tz
-      .find_local_time_type(now)
-      .expect("Local time zone lacks information for this timestamp")
-      .ut_offset() as i64;
-    Self::from_timestamp(now + offset)
+    Self::try_today().expect("Could not determine the date in the local time zone")
+  }
+
+  /// The date representing today, according to the system local clock, or `None` if the local
+  /// time zone can not be determined.
+  ///
+  /// As with [`Date::today`], the process-wide default time zone is used if one has been set.
+  #[cfg(feature = "tz")]
+  pub fn try_today() -> Option<Self> {
+    Self::today_tz(default_timezone().or_else(tzdb::local_tz)?).ok()
   }
 
   /// The date representing today, in the provided time zone.
   #[cfg(feature = "tz")]
   pub fn today_tz(tz: tz::TimeZoneRef<'static>) -> tz::TzResult<Self> {
-    let now =
-      now().duration_since(UNIX_EPOCH).expect("system time set prior to 1970").as_secs() as i64;
-    let offset = tz.find_local_time_type(now)?.ut_offset() as i64;
-    Ok(Self::from_timestamp(now + offset))
+    Self::today_at(unix_now(), tz)
+  }
+
+  /// The date representing "today" at the given Unix timestamp, in the provided time zone.
+  ///
+  /// This is useful for code that takes the current time from an injected clock rather than
+  /// reading the system clock, so that it can be tested with fixed times. (Alternatively, tests
+  /// can freeze the system clock with [`test::freeze_time`], using the `test-util` feature.)
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::date;
+  /// use date::tz;
+  /// use date::Date;
+  ///
+  /// let now = 1_334_966_400; // 2012-04-21 00:00:00 UTC
+  /// assert_eq!(Date::today_at(now, tz::asia::TOKYO).unwrap(), date! { 2012-04-21 });
+  /// assert_eq!(Date::today_at(now, tz::us::EASTERN).unwrap(), date! { 2012-04-20 });
+  /// ```
+  #[cfg(feature = "tz")]
+  pub const fn today_at(unix_timestamp: i64, tz: tz::TimeZoneRef<'static>) -> tz::TzResult<Self> {
+    Self::from_timestamp_tz(unix_timestamp, tz)
   }
 
   /// The date representing today, in UTC.
+  #[cfg(feature = "std")]
+  pub fn today_utc() -> Self {
+    Self::from_timestamp(unix_now())
+  }
+
+  /// The date representing yesterday, according to the system local clock.
   ///
   /// ## Panic
   ///
-  /// This function will panic if the system clock is set to a time prior to January 1, 1970.
-  pub fn today_utc() -> Self {
-    let now = now().duration_since(UNIX_EPOCH).expect("system time set prior to 1970").as_secs();
-    Self::from_timestamp(now as i64)
+  /// This function panics under the same conditions as [`Date::today`]. Use
+  /// [`Date::try_yesterday`] to handle this case.
+  #[cfg(feature = "tz")]
+  pub fn yesterday() -> Self {
+    Self(Self::today().0 - 1)
+  }
+
+  /// The date representing yesterday, according to the system local clock, or `None` if the local
+  /// time zone can not be determined.
+  #[cfg(feature = "tz")]
+  pub fn try_yesterday() -> Option<Self> {
+    Self::try_today().map(|today| Self(today.0 - 1))
+  }
+
+  /// The date representing yesterday, in the provided time zone.
+  #[cfg(feature = "tz")]
+  pub fn yesterday_tz(tz: tz::TimeZoneRef<'static>) -> tz::TzResult<Self> {
+    Self::today_tz(tz).map(|today| Self(today.0 - 1))
+  }
+
+  /// The date representing yesterday, in UTC.
+  #[cfg(feature = "std")]
+  pub fn yesterday_utc() -> Self {
+    Self(Self::today_utc().0 - 1)
+  }
+
+  /// The date representing tomorrow, according to the system local clock.
+  ///
+  /// ## Panic
+  ///
+  /// This function panics under the same conditions as [`Date::today`]. Use
+  /// [`Date::try_tomorrow`] to handle this case.
+  #[cfg(feature = "tz")]
+  pub fn tomorrow() -> Self {
+    Self(Self::today().0 + 1)
+  }
+
+  /// The date representing tomorrow, according to the system local clock, or `None` if the local
+  /// time zone can not be determined.
+  #[cfg(feature = "tz")]
+  pub fn try_tomorrow() -> Option<Self> {
+    Self::try_today().map(|today| Self(today.0 + 1))
+  }
+
+  /// The date representing tomorrow, in the provided time zone.
+  #[cfg(feature = "tz")]
+  pub fn tomorrow_tz(tz: tz::TimeZoneRef<'static>) -> tz::TzResult<Self> {
+    Self::today_tz(tz).map(|today| Self(today.0 + 1))
+  }
+
+  /// The date representing tomorrow, in UTC.
+  #[cfg(feature = "std")]
+  pub fn tomorrow_utc() -> Self {
+    Self(Self::today_utc().0 + 1)
   }
 }
 
@@ -372,17 +950,72 @@ impl Date {
   #[doc = include_str!("../support/padding.md")]
   ///
   #[doc = include_str!("../support/plain-characters.md")]
-  pub fn format<'a>(&'a self, format_str: &'a str) -> format::FormattedDate {
-    format::FormattedDate { date: self, format: format_str }
+  pub fn format<'a>(&'a self, format_str: &'a str) -> format::FormattedDate<'a> {
+    format::FormattedDate { date: self, format: format_str, year_offset: 0 }
+  }
+
+  /// Format the date according to the provided `strftime` specifier, or return `None` if the
+  /// specifier contains an unrecognized token.
+  ///
+  /// Formatting a date with an invalid specifier returns [`fmt::Error`], which causes methods such
+  /// as `to_string` to panic; this method checks for that ahead of time. See [`Date::format`] for
+  /// the supported tokens.
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// # use date::date;
+  /// let date = date! { 2012-04-21 };
+  /// assert_eq!(date.try_format("%B %-d, %Y").unwrap().to_string(), "April 21, 2012");
+  /// assert!(date.try_format("%Q").is_none());
+  /// ```
+  pub fn try_format<'a>(&'a self, format_str: &'a str) -> Option<format::FormattedDate<'a>> {
+    let formatted = self.format(format_str);
+    formatted.is_valid().then_some(formatted)
   }
 }
 
 impl Date {
   /// An iterator of dates beginning with this date, and ending with the provided end date
   /// (inclusive).
-  pub fn iter_through(&self, end: Date) -> iter::DateIterator {
+  pub const fn iter_through(&self, end: Date) -> iter::DateIterator {
     iter::DateIterator::new(self, end)
   }
+
+  /// An iterator of dates beginning with this date and moving backward, ending with the provided
+  /// earlier date (inclusive).
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::date;
+  ///
+  /// let mut dates = date! { 2024-03-01 }.iter_back_through(date! { 2024-02-28 });
+  /// assert_eq!(dates.next(), Some(date! { 2024-03-01 }));
+  /// assert_eq!(dates.next(), Some(date! { 2024-02-29 }));
+  /// assert_eq!(dates.next(), Some(date! { 2024-02-28 }));
+  /// assert_eq!(dates.next(), None);
+  /// ```
+  pub fn iter_back_through(&self, earlier: Date) -> core::iter::Rev<iter::DateIterator> {
+    iter::DateIterator::new(&earlier, *self).rev()
+  }
+
+  /// An iterator of dates beginning with this date, continuing indefinitely.
+  ///
+  /// The iterator ends after yielding [`Date::MAX`].
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::date;
+  /// use date::Weekday;
+  ///
+  /// let friday = date! { 2024-04-21 }.iter_from().find(|d| d.weekday() == Weekday::Friday);
+  /// assert_eq!(friday, Some(date! { 2024-04-26 }));
+  /// ```
+  pub const fn iter_from(&self) -> iter::DateIterator {
+    iter::DateIterator::new(self, Date::MAX)
+  }
 }
 
 impl Date {
@@ -395,19 +1028,23 @@ impl Date {
 #[cfg(feature = "easter")]
 impl Date {
   /// The date of Easter in the Gregorian calendar for the given year.
+  ///
+  /// The Gregorian computus is applied proleptically to years before the calendar's adoption in
+  /// 1583, so this is defined (and does not panic) for every year.
   pub const fn easter(year: i16) -> Self {
-    assert!(year >= 1583 || year <= 9999, "Year out of bounds");
-    let a = year % 19;
-    let b = year / 100;
-    let c = year % 100;
-    let d = b / 4;
-    let e = b % 4;
-    let f = (b + 8) / 25;
-    let g = (b - f + 1) / 3;
-    let h = (19 * a + b - d - g + 15) % 30;
+    // Use Euclidean division so that the computus remains valid for years before 1 CE.
+    let y = year as i32;
+    let a = y.rem_euclid(19);
+    let b = y.div_euclid(100);
+    let c = y.rem_euclid(100);
+    let d = b.div_euclid(4);
+    let e = b.rem_euclid(4);
+    let f = (b + 8).div_euclid(25);
+    let g = (b - f + 1).div_euclid(3);
+    let h = (19 * a + b - d - g + 15).rem_euclid(30);
     let i = c / 4;
     let j = c % 4;
-    let k = (32 + 2 * e + 2 * i - h - j) % 7;
+    let k = (32 + 2 * e + 2 * i - h - j).rem_euclid(7);
     let l = (a + 11 * h + 22 * k) / 451;
     let month = (h + k - 7 * l + 114) / 31;
     let day = (h + k - 7 * l + 114) % 31 + 1;
@@ -417,60 +1054,178 @@ impl Date {
 
 impl fmt::Debug for Date {
   fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-    write!(f, "{}", self.format("%Y-%m-%d"))
+    f.write_str(self.to_iso_string().as_str())
   }
 }
 
 impl fmt::Display for Date {
   fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-    write!(f, "{}", self.format("%Y-%m-%d"))
+    f.write_str(self.to_iso_string().as_str())
   }
 }
 
+#[cfg(feature = "std")]
 impl FromStr for Date {
-  type Err = ParseError;
+  type Err = DateError;
+
+  /// Parse a date in the ISO 8601 form written by [`Display`](fmt::Display), including a sign
+  /// for years before 0 or after 9999.
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    match Self::parse_iso8601(s) {
+      Some(date) => Ok(date),
+      None => Self::parse(s, "%Y-%m-%d"),
+    }
+  }
+}
+
+impl TryFrom<(i16, u8, u8)> for Date {
+  type Error = DateError;
 
-  fn from_str(s: &str) -> ParseResult<Self> {
-    Self::parse(s, "%Y-%m-%d")
+  fn try_from((year, month, day): (i16, u8, u8)) -> Result<Self, Self::Error> {
+    Self::try_new(year, month, day)
   }
 }
 
+impl From<Date> for (i16, u8, u8) {
+  fn from(date: Date) -> Self {
+    date.ymd()
+  }
+}
+
+#[cfg(feature = "std")]
 impl From<strptime::RawDate> for Date {
   fn from(value: strptime::RawDate) -> Self {
     Self::new(value.year(), value.month(), value.day())
   }
 }
 
-#[cfg(not(test))]
+/// The current Unix timestamp, in whole seconds (rounded toward negative infinity).
+#[cfg(feature = "std")]
+fn unix_now() -> i64 {
+  match now().duration_since(UNIX_EPOCH) {
+    Ok(duration) => duration.as_secs() as i64,
+    Err(err) => -(err.duration().as_nanos().div_ceil(1_000_000_000) as i64),
+  }
+}
+
+#[cfg(feature = "std")]
 fn now() -> SystemTime {
+  #[cfg(any(test, feature = "test-util"))]
+  if let Some(time) = test::frozen_time() {
+    return time;
+  }
   SystemTime::now()
 }
 
-#[cfg(test)]
-use tests::now;
-
 #[cfg(test)]
 mod tests {
-  use std::cell::RefCell;
-
   use assert2::check;
 
   use super::*;
+  #[cfg(feature = "std")]
+  use crate::test::freeze_system_time;
+  #[cfg(feature = "std")]
+  use crate::test::freeze_time;
+
+  /// Serializes tests that set the process-wide default time zone with tests that read it.
+  #[cfg(feature = "tz")]
+  static DEFAULT_TZ_LOCK: std::sync::Mutex<()> = std::sync::Mutex::new(());
+
+  #[cfg(feature = "tz")]
+  fn lock_default_tz() -> std::sync::MutexGuard<'static, ()> {
+    DEFAULT_TZ_LOCK.lock().unwrap_or_else(|err| err.into_inner())
+  }
+
+  #[test]
+  fn test_macro() {
+    const YEAR: i16 = 1999;
+    check!(date! { 2012-04-21 } == Date::new(2012, 4, 21));
+    check!(date! { -0044-03-15 } == Date::new(-44, 3, 15));
+    check!(date! { -32768-01-01 } == Date::MIN);
+    check!(date! { (YEAR)-12-31 } == Date::new(1999, 12, 31));
+    check!(date! { (YEAR + 1)-(2)-(30 - 1) } == Date::new(2000, 2, 29));
+    check!(date! { -(YEAR)-01-01 } == Date::new(-1999, 1, 1));
+  }
 
-  thread_local! {
-    static MOCK_TIME: RefCell<Option<SystemTime>> = const { RefCell::new(None) };
+  #[test]
+  fn test_checked_new() {
+    check!(Date::checked_new(2024, 2, 29) == Some(date! { 2024-02-29 }));
+    check!(Date::checked_new(-32768, 1, 1) == Some(Date::MIN));
+    check!(Date::checked_new(2023, 2, 29) == None);
+    check!(Date::checked_new(2024, 4, 31) == None);
+    check!(Date::checked_new(2024, 0, 1) == None);
+    check!(Date::checked_new(2024, 1, 0) == None);
   }
 
-  fn set_now(time: SystemTime) {
-    MOCK_TIME.with(|cell| *cell.borrow_mut() = Some(time));
+  #[test]
+  fn test_with() {
+    let date = date! { 2024-02-29 };
+    check!(date.with_year(2028) == Ok(date! { 2028-02-29 }));
+    let err = DateError::DayOutOfRange { year: 2100, month: 2, day: 29 };
+    check!(date.with_year(2100) == Err(err));
+    check!(date.with_month(3) == Ok(date! { 2024-03-29 }));
+    check!(date.with_month(0) == Err(DateError::MonthOutOfRange { month: 0 }));
+    check!(date.with_day(1) == Ok(date! { 2024-02-01 }));
+    check!(date.with_day(30) == Err(DateError::DayOutOfRange { year: 2024, month: 2, day: 30 }));
+    check!(date.with_day(0).is_err());
+    check!(Date::MAX.with_year(i16::MIN) == Ok(date! { -32768-12-31 }));
   }
 
-  fn clear_now() {
-    MOCK_TIME.with(|cell| *cell.borrow_mut() = None);
+  #[test]
+  fn test_first_last() {
+    for date in date! { 2023-12-25 }.iter_through(date! { 2025-01-05 }) {
+      let (year, month, _) = date.ymd();
+      check!(date.first_of_month() == Date::new(year, month, 1));
+      check!(date.last_of_month() == Date::new(year, month, utils::days_in_month(year, month)));
+      check!(date.first_of_year() == Date::new(year, 1, 1));
+      check!(date.last_of_year() == Date::new(year, 12, 31));
+    }
+    check!(Date::MIN.first_of_month() == Date::MIN);
+    check!(Date::MIN.first_of_year() == Date::MIN);
+    check!(Date::MAX.last_of_month() == Date::MAX);
+    check!(Date::MAX.last_of_year() == Date::MAX);
   }
 
-  pub(super) fn now() -> SystemTime {
-    MOCK_TIME.with(|cell| cell.borrow().as_ref().cloned().unwrap_or_else(SystemTime::now))
+  #[test]
+  fn test_from_ordinal() {
+    for date in date! { 2023-12-25 }.iter_through(date! { 2025-01-05 }) {
+      check!(Date::from_ordinal(date.year(), date.day_of_year()) == Ok(date));
+    }
+    check!(Date::from_ordinal(-32768, 1) == Ok(Date::MIN));
+    check!(Date::from_ordinal(32767, 365) == Ok(Date::MAX));
+    let err = DateError::OrdinalOutOfRange { year: 2024, ordinal: 0 };
+    check!(Date::from_ordinal(2024, 0) == Err(err));
+    check!(Date::from_ordinal(2024, 367).is_err());
+    let err = Date::from_ordinal(2023, 366).unwrap_err();
+    check!(err.to_string() == "Day of year out-of-bounds: 2023-366");
+  }
+
+  #[test]
+  fn test_try_new() {
+    check!(Date::try_new(2024, 2, 29) == Ok(date! { 2024-02-29 }));
+    check!(Date::try_new(32767, 12, 31) == Ok(Date::MAX));
+    check!(Date::try_new(2024, 0, 1) == Err(DateError::MonthOutOfRange { month: 0 }));
+    check!(
+      Date::try_new(2024, 4, 31)
+        == Err(DateError::DayOutOfRange { year: 2024, month: 4, day: 31 })
+    );
+    check!(Date::try_new(2024, 1, 0).is_err());
+  }
+
+  #[test]
+  fn test_tuple() {
+    check!(Date::try_from((2024, 2, 29)) == Ok(date! { 2024-02-29 }));
+    check!(Date::try_from((-32768, 1, 1)) == Ok(Date::MIN));
+    check!(Date::try_from((2024, 13, 1)) == Err(DateError::MonthOutOfRange { month: 13 }));
+    check!(
+      Date::try_from((2023, 2, 29))
+        == Err(DateError::DayOutOfRange { year: 2023, month: 2, day: 29 })
+    );
+    check!(<(i16, u8, u8)>::from(date! { 2012-04-21 }) == (2012, 4, 21));
+    for date in [Date::MIN, date! { 1969-12-31 }, Date::MAX] {
+      let ymd: (i16, u8, u8) = date.into();
+      check!(Date::try_from(ymd) == Ok(date));
+    }
   }
 
   #[test]
@@ -480,6 +1235,22 @@ mod tests {
     check!(date! { 1970-01-02 }.0 == 1);
   }
 
+  #[test]
+  fn test_from_unix_days() {
+    check!(Date::from_unix_days(Date::MIN.unix_days()) == Date::MIN);
+    check!(Date::from_unix_days(Date::MAX.unix_days()) == Date::MAX);
+    check!(Date::checked_from_unix_days(Date::MIN.unix_days() - 1).is_none());
+    check!(Date::checked_from_unix_days(Date::MAX.unix_days() + 1).is_none());
+    check!(Date::checked_from_unix_days(i32::MIN).is_none());
+    check!(Date::checked_from_unix_days(i32::MAX).is_none());
+  }
+
+  #[test]
+  #[should_panic]
+  fn test_from_unix_days_panic() {
+    Date::from_unix_days(Date::MAX.unix_days() + 1);
+  }
+
   #[test]
   fn test_ymd_readback() {
     for year in [2020, 2022, 2100] {
@@ -502,6 +1273,45 @@ mod tests {
         }
       }
     }
+    check!(Date::MIN.ymd() == (-32768, 1, 1));
+    check!(Date::MAX.ymd() == (32767, 12, 31));
+  }
+
+  #[test]
+  fn test_ymd_extreme_timestamps() {
+    // Timestamps far outside the range of representable dates must not overflow (the year wraps,
+    // but the month and day still follow the 400-year cycle of the calendar).
+    let timestamps = [1_100_000_000 * 86_400, i32::MAX as i64 * 86_400, i32::MIN as i64 * 86_400];
+    for timestamp in timestamps {
+      let date = Date::from_timestamp(timestamp);
+      let (_, month, day) = date.ymd();
+      check!(Date(date.0 - date.0.signum() * 146_097 * 5_000).ymd().1 == month);
+      check!(Date(date.0 - date.0.signum() * 146_097 * 5_000).ymd().2 == day);
+    }
+    for timestamp in [i64::MAX, i64::MIN] {
+      let (_, month, day) = Date::from_timestamp(timestamp).ymd();
+      check!((1..=12).contains(&month));
+      check!((1..=31).contains(&day));
+    }
+  }
+
+  #[test]
+  fn test_ymd_sequential() {
+    // Count forward one day at a time, and ensure the conversions agree in both directions.
+    let (mut year, mut month, mut day) = (-1000, 1, 1);
+    let mut date = Date::new(year, month, day);
+    while year < 3000 {
+      check!(date.ymd() == (year, month, day));
+      check!(Date::new(year, month, day) == date);
+      date = Date(date.0 + 1);
+      day += 1;
+      if day > utils::days_in_month(year, month) {
+        (month, day) = (month + 1, 1);
+      }
+      if month > 12 {
+        (year, month) = (year + 1, 1);
+      }
+    }
   }
 
   #[test]
@@ -581,25 +1391,84 @@ mod tests {
     check!(date! { 2024-01-07 }.week() == 1); // Sunday
     check!(date! { 2024-01-08 }.week() == 1); // Monday
     check!(date! { 2024-01-14 }.week() == 2); // Sunday
+    check!(date! { 2000-01-03 }.week() == 1); // Monday
+    check!(date! { 1999-12-11 }.week() == 49); // Saturday
   }
 
+  #[test]
+  fn test_week_starting() {
+    for date in date! { 2023-12-01 }.iter_through(date! { 2025-01-31 }) {
+      check!(date.week_starting(Weekday::Sunday) == date.week());
+      let week = date.week_number(WeekPolicy::FIRST_MONDAY);
+      check!(date.week_starting(Weekday::Monday) == week as u16);
+    }
+    check!(date! { 2024-01-03 }.week_starting(Weekday::Thursday) == 0);
+    check!(date! { 2024-01-04 }.week_starting(Weekday::Thursday) == 1);
+  }
+
+  #[cfg(feature = "std")]
   #[test]
   fn test_today() {
-    set_now(SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(86_400));
+    let _guard = freeze_time(86_400);
     check!(Date::today_utc() == date! { 1970-01-02 });
-    clear_now();
+    let _guard = freeze_system_time(UNIX_EPOCH - std::time::Duration::from_millis(1));
+    check!(Date::today_utc() == date! { 1969-12-31 });
+    let _guard = freeze_time(-86_400);
+    check!(Date::today_utc() == date! { 1969-12-31 });
   }
 
   #[cfg(feature = "tz")]
   #[test]
   fn test_today_tz() -> tz::TzResult<()> {
-    set_now(SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(86_400));
+    let _lock = lock_default_tz();
+    let _guard = freeze_time(86_400);
     check!([date! { 1970-01-01 }, date! { 1970-01-02 }].contains(&Date::today()));
     check!(Date::today_tz(tz::us::EASTERN)? == date! { 1970-01-01 });
-    clear_now();
+    check!(Date::today_at(86_400, tz::asia::TOKYO)? == date! { 1970-01-02 });
+    check!(Date::today_at(86_400, tz::us::EASTERN)? == date! { 1970-01-01 });
     Ok(())
   }
 
+  #[cfg(feature = "std")]
+  #[test]
+  fn test_yesterday_tomorrow_utc() {
+    let _guard = freeze_time(86_400);
+    check!(Date::yesterday_utc() == date! { 1970-01-01 });
+    check!(Date::tomorrow_utc() == date! { 1970-01-03 });
+  }
+
+  #[cfg(feature = "tz")]
+  #[test]
+  fn test_yesterday_tomorrow_tz() -> tz::TzResult<()> {
+    let _lock = lock_default_tz();
+    let _guard = freeze_time(86_400);
+    check!(Date::yesterday_tz(tz::us::EASTERN)? == date! { 1969-12-31 });
+    check!(Date::tomorrow_tz(tz::us::EASTERN)? == date! { 1970-01-02 });
+    check!(Date::yesterday_tz(tz::asia::TOKYO)? == date! { 1970-01-01 });
+    check!(Date::tomorrow_tz(tz::asia::TOKYO)? == date! { 1970-01-03 });
+    check!(Date::try_yesterday() == Date::try_today().map(|d| Date(d.0 - 1)));
+    check!(Date::try_tomorrow() == Date::try_today().map(|d| Date(d.0 + 1)));
+    check!(Date::yesterday() < Date::tomorrow());
+    Ok(())
+  }
+
+  #[cfg(feature = "tz")]
+  #[test]
+  fn test_default_timezone() {
+    let _lock = lock_default_tz();
+    let _guard = freeze_time(86_400);
+    check!(set_default_timezone("America/New_York").is_ok());
+    check!(Date::today() == date! { 1970-01-01 });
+    check!(set_default_timezone("Asia/Tokyo").is_ok());
+    check!(Date::today() == date! { 1970-01-02 });
+    let err = set_default_timezone("Nowhere/Special").unwrap_err();
+    check!(err.name() == "Nowhere/Special");
+    check!(err.to_string() == "Unknown time zone: Nowhere/Special");
+    check!(default_timezone() == Some(tz::asia::TOKYO));
+    clear_default_timezone();
+    check!(default_timezone() == None);
+  }
+
   #[cfg(feature = "tz")]
   #[test]
   fn test_timestamp_tz() -> tz::TzResult<()> {
@@ -635,20 +1504,62 @@ mod tests {
     check!(Date::easter(2033) == date! { 2033-04-17 });
     check!(Date::easter(2034) == date! { 2034-04-09 });
     check!(Date::easter(2035) == date! { 2035-03-25 });
+    for year in i16::MIN..=i16::MAX {
+      let easter = Date::easter(year);
+      check!(easter.weekday() == Weekday::Sunday);
+      check!((Date::new(year, 3, 22)..=Date::new(year, 4, 25)).contains(&easter));
+    }
   }
 
   #[test]
-  fn test_from_str() -> ParseResult<()> {
+  fn test_parse_iso8601() {
+    check!(Date::parse_iso8601("2012-04-21") == Some(date! { 2012-04-21 }));
+    check!(Date::parse_iso8601("2024-02-29") == Some(date! { 2024-02-29 }));
+    check!(Date::parse_iso8601("+2012-04-21") == Some(date! { 2012-04-21 }));
+    check!(Date::parse_iso8601("-0001-12-31") == Some(Date::new(-1, 12, 31)));
+    check!(Date::parse_iso8601("-001-12-31") == Some(Date::new(-1, 12, 31)));
+    check!(Date::parse_iso8601("-32768-01-01") == Some(Date::MIN));
+    check!(Date::parse_iso8601("32767-12-31") == Some(Date::MAX));
+    check!(Date::parse_iso8601("32768-01-01") == None);
+    check!(Date::parse_iso8601("2023-02-29") == None);
+    check!(Date::parse_iso8601("2012-13-01") == None);
+    check!(Date::parse_iso8601("2012-4-21") == None);
+    check!(Date::parse_iso8601("12-04-21") == None);
+    check!(Date::parse_iso8601("-12-04-21") == None);
+    check!(Date::parse_iso8601("2012/04/21") == None);
+    check!(Date::parse_iso8601("--2012-04-21") == None);
+    check!(Date::parse_iso8601("") == None);
+  }
+
+  #[cfg(feature = "std")]
+  #[test]
+  fn test_from_str() -> Result<(), DateError> {
     check!("2012-04-21".parse::<Date>()? == date! { 2012-04-21 });
     check!("2012-4-21".parse::<Date>().is_err());
     check!("04/21/2012".parse::<Date>().is_err());
     check!("12-04-21".parse::<Date>().is_err());
+    check!(matches!("foo".parse::<Date>(), Err(DateError::Parse(_))));
     check!("foo".parse::<Date>().map_err(|e| e.to_string()).unwrap_err().contains("foo"));
+    check!(
+      "2023-02-29".parse::<Date>()
+        == Err(DateError::DayOutOfRange { year: 2023, month: 2, day: 29 })
+    );
+    for date in [
+      Date::MIN,
+      date! { -0044-03-15 },
+      date! { 0000-01-01 },
+      date! { 9999-12-31 },
+      date! { 10000-01-01 },
+      Date::MAX,
+    ] {
+      check!(date.to_string().parse::<Date>()? == date);
+    }
     Ok(())
   }
 
+  #[cfg(feature = "std")]
   #[test]
-  fn test_parse() -> ParseResult<()> {
+  fn test_parse() -> Result<(), DateError> {
     check!(Date::parse("04/21/12", "%m/%d/%y")? == date! { 2012-04-21 });
     check!(Date::parse("Saturday, April 21, 2012", "%A, %B %-d, %Y")? == date! { 2012-04-21 });
     Ok(())
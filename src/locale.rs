@@ -0,0 +1,130 @@
+//! Localized names for weekdays and months.
+
+/// A language in which names of weekdays and months can be displayed.
+///
+/// Names follow the conventions of the Unicode Common Locale Data Repository (CLDR), including
+/// its capitalization: in most of these languages, weekday and month names are not capitalized.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum Locale {
+  /// English
+  English,
+  /// German (Deutsch)
+  German,
+  /// French (français)
+  French,
+  /// Spanish (español)
+  Spanish,
+  /// Italian (italiano)
+  Italian,
+  /// Portuguese (português)
+  Portuguese,
+  /// Dutch (Nederlands)
+  Dutch,
+}
+
+impl Locale {
+  /// Every supported locale.
+  pub(crate) const ALL: [Locale; 7] = [
+    Self::English,
+    Self::German,
+    Self::French,
+    Self::Spanish,
+    Self::Italian,
+    Self::Portuguese,
+    Self::Dutch,
+  ];
+
+  /// The names of the weekdays in this locale, beginning with Sunday.
+  pub(crate) const fn weekday_names(self) -> &'static [&'static str; 7] {
+    match self {
+      Self::English =>
+        &["Sunday", "Monday", "Tuesday", "Wednesday", "Thursday", "Friday", "Saturday"],
+      Self::German =>
+        &["Sonntag", "Montag", "Dienstag", "Mittwoch", "Donnerstag", "Freitag", "Samstag"],
+      Self::French => &["dimanche", "lundi", "mardi", "mercredi", "jeudi", "vendredi", "samedi"],
+      Self::Spanish => &["domingo", "lunes", "martes", "miércoles", "jueves", "viernes", "sábado"],
+      Self::Italian =>
+        &["domenica", "lunedì", "martedì", "mercoledì", "giovedì", "venerdì", "sabato"],
+      Self::Portuguese => &[
+        "domingo", "segunda-feira", "terça-feira", "quarta-feira", "quinta-feira", "sexta-feira",
+        "sábado",
+      ],
+      Self::Dutch =>
+        &["zondag", "maandag", "dinsdag", "woensdag", "donderdag", "vrijdag", "zaterdag"],
+    }
+  }
+
+  /// The abbreviated names of the weekdays in this locale, beginning with Sunday.
+  pub(crate) const fn weekday_abbvs(self) -> &'static [&'static str; 7] {
+    match self {
+      Self::English => &["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"],
+      Self::German => &["So.", "Mo.", "Di.", "Mi.", "Do.", "Fr.", "Sa."],
+      Self::French => &["dim.", "lun.", "mar.", "mer.", "jeu.", "ven.", "sam."],
+      Self::Spanish => &["dom", "lun", "mar", "mié", "jue", "vie", "sáb"],
+      Self::Italian => &["dom", "lun", "mar", "mer", "gio", "ven", "sab"],
+      Self::Portuguese => &["dom.", "seg.", "ter.", "qua.", "qui.", "sex.", "sáb."],
+      Self::Dutch => &["zo", "ma", "di", "wo", "do", "vr", "za"],
+    }
+  }
+
+  /// The names of the months in this locale, beginning with January.
+  pub(crate) const fn month_names(self) -> &'static [&'static str; 12] {
+    match self {
+      Self::English => &[
+        "January", "February", "March", "April", "May", "June", "July", "August", "September",
+        "October", "November", "December",
+      ],
+      Self::German => &[
+        "Januar", "Februar", "März", "April", "Mai", "Juni", "Juli", "August", "September",
+        "Oktober", "November", "Dezember",
+      ],
+      Self::French => &[
+        "janvier", "février", "mars", "avril", "mai", "juin", "juillet", "août", "septembre",
+        "octobre", "novembre", "décembre",
+      ],
+      Self::Spanish => &[
+        "enero", "febrero", "marzo", "abril", "mayo", "junio", "julio", "agosto", "septiembre",
+        "octubre", "noviembre", "diciembre",
+      ],
+      Self::Italian => &[
+        "gennaio", "febbraio", "marzo", "aprile", "maggio", "giugno", "luglio", "agosto",
+        "settembre", "ottobre", "novembre", "dicembre",
+      ],
+      Self::Portuguese => &[
+        "janeiro", "fevereiro", "março", "abril", "maio", "junho", "julho", "agosto", "setembro",
+        "outubro", "novembro", "dezembro",
+      ],
+      Self::Dutch => &[
+        "januari", "februari", "maart", "april", "mei", "juni", "juli", "augustus", "september",
+        "oktober", "november", "december",
+      ],
+    }
+  }
+
+  /// The abbreviated names of the months in this locale, beginning with January.
+  pub(crate) const fn month_abbvs(self) -> &'static [&'static str; 12] {
+    match self {
+      Self::English =>
+        &["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec"],
+      Self::German => &[
+        "Jan.", "Feb.", "März", "Apr.", "Mai", "Juni", "Juli", "Aug.", "Sept.", "Okt.", "Nov.",
+        "Dez.",
+      ],
+      Self::French => &[
+        "janv.", "févr.", "mars", "avr.", "mai", "juin", "juil.", "août", "sept.", "oct.", "nov.",
+        "déc.",
+      ],
+      Self::Spanish =>
+        &["ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "sept", "oct", "nov", "dic"],
+      Self::Italian =>
+        &["gen", "feb", "mar", "apr", "mag", "giu", "lug", "ago", "set", "ott", "nov", "dic"],
+      Self::Portuguese => &[
+        "jan.", "fev.", "mar.", "abr.", "mai.", "jun.", "jul.", "ago.", "set.", "out.", "nov.",
+        "dez.",
+      ],
+      Self::Dutch =>
+        &["jan", "feb", "mrt", "apr", "mei", "jun", "jul", "aug", "sep", "okt", "nov", "dec"],
+    }
+  }
+}
@@ -0,0 +1,376 @@
+use core::fmt;
+use core::ops::Add;
+use core::ops::Sub;
+use core::str::FromStr;
+
+use crate::utils;
+use crate::Date;
+#[cfg(feature = "i18n")]
+use crate::Locale;
+
+/// A month of the year.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[repr(u8)]
+pub enum Month {
+  January = 1,
+  February = 2,
+  March = 3,
+  April = 4,
+  May = 5,
+  June = 6,
+  July = 7,
+  August = 8,
+  September = 9,
+  October = 10,
+  November = 11,
+  December = 12,
+}
+
+impl Month {
+  /// All twelve months, in order, beginning with January.
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::Month;
+  ///
+  /// let months: Vec<_> = Month::all().collect();
+  /// assert_eq!(months.len(), 12);
+  /// assert_eq!(months[0], Month::January);
+  /// assert_eq!(months[11], Month::December);
+  /// ```
+  pub fn all() -> impl DoubleEndedIterator<Item = Month> + ExactSizeIterator {
+    (1..=12).map(Month::from_number)
+  }
+
+  /// The following month, and whether the year changes (which is only the case for December).
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::Month;
+  ///
+  /// assert_eq!(Month::April.succ(), (Month::May, false));
+  /// assert_eq!(Month::December.succ(), (Month::January, true));
+  /// ```
+  pub const fn succ(self) -> (Month, bool) {
+    match self {
+      Self::December => (Self::January, true),
+      _ => (Self::from_number(self as u8 + 1), false),
+    }
+  }
+
+  /// The preceding month, and whether the year changes (which is only the case for January).
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::Month;
+  ///
+  /// assert_eq!(Month::April.pred(), (Month::March, false));
+  /// assert_eq!(Month::January.pred(), (Month::December, true));
+  /// ```
+  pub const fn pred(self) -> (Month, bool) {
+    match self {
+      Self::January => (Self::December, true),
+      _ => (Self::from_number(self as u8 - 1), false),
+    }
+  }
+
+  /// The month that is the given number of months later (or earlier, if `n` is negative),
+  /// wrapping around the end of the year.
+  ///
+  /// This is equivalent to `month + n`, but can be used in `const` contexts.
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::Month;
+  ///
+  /// assert_eq!(Month::October.wrapping_add(5), Month::March);
+  /// assert_eq!(Month::February.wrapping_add(-3), Month::November);
+  /// ```
+  pub const fn wrapping_add(self, n: i32) -> Month {
+    Self::from_number(((self as i32 - 1 + n % 12 + 12) % 12 + 1) as u8)
+  }
+
+  /// The number of this month, with January as 1 and December as 12.
+  #[inline]
+  pub const fn number(self) -> u8 {
+    self as u8
+  }
+
+  /// The number of days in this month in the given year.
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::Month;
+  ///
+  /// assert_eq!(Month::February.days_in(2023), 28);
+  /// assert_eq!(Month::February.days_in(2024), 29);
+  /// assert_eq!(Month::April.days_in(2024), 30);
+  /// ```
+  pub const fn days_in(self, year: i16) -> u8 {
+    utils::days_in_month(year, self as u8)
+  }
+
+  /// The month with the given number, which must be between 1 and 12.
+  pub(crate) const fn from_number(month: u8) -> Month {
+    match month {
+      1 => Self::January,
+      2 => Self::February,
+      3 => Self::March,
+      4 => Self::April,
+      5 => Self::May,
+      6 => Self::June,
+      7 => Self::July,
+      8 => Self::August,
+      9 => Self::September,
+      10 => Self::October,
+      11 => Self::November,
+      12 => Self::December,
+      #[cfg(not(tarpaulin_include))]
+      _ => panic!("Fictitious month"),
+    }
+  }
+
+  /// The English name of this month.
+  pub const fn name(self) -> &'static str {
+    match self {
+      Self::January => "January",
+      Self::February => "February",
+      Self::March => "March",
+      Self::April => "April",
+      Self::May => "May",
+      Self::June => "June",
+      Self::July => "July",
+      Self::August => "August",
+      Self::September => "September",
+      Self::October => "October",
+      Self::November => "November",
+      Self::December => "December",
+    }
+  }
+
+  /// The three-letter English abbreviation of this month.
+  pub const fn abbreviation(self) -> &'static str {
+    match self {
+      Self::January => "Jan",
+      Self::February => "Feb",
+      Self::March => "Mar",
+      Self::April => "Apr",
+      Self::May => "May",
+      Self::June => "Jun",
+      Self::July => "Jul",
+      Self::August => "Aug",
+      Self::September => "Sep",
+      Self::October => "Oct",
+      Self::November => "Nov",
+      Self::December => "Dec",
+    }
+  }
+
+  /// Whether the given string is one of the names of this month, ignoring ASCII case.
+  fn is_named(self, s: &str) -> bool {
+    if s.eq_ignore_ascii_case(self.name()) || s.eq_ignore_ascii_case(self.abbreviation()) {
+      return true;
+    }
+    #[cfg(feature = "i18n")]
+    for locale in Locale::ALL {
+      let i = self as usize - 1;
+      if s.eq_ignore_ascii_case(locale.month_names()[i])
+        || s.eq_ignore_ascii_case(locale.month_abbvs()[i])
+      {
+        return true;
+      }
+    }
+    false
+  }
+}
+
+impl Date {
+  /// The month of the year, as a [`Month`].
+  ///
+  /// See also [`Date::month`], which returns the month number.
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::date;
+  /// use date::Month;
+  ///
+  /// assert_eq!(date! { 2024-08-15 }.month_of_year(), Month::August);
+  /// ```
+  pub const fn month_of_year(&self) -> Month {
+    Month::from_number(self.month())
+  }
+}
+
+impl Add<i32> for Month {
+  type Output = Self;
+
+  /// Return the month that is the given number of months later, wrapping around the end of the
+  /// year.
+  fn add(self, n: i32) -> Self::Output {
+    self.wrapping_add(n)
+  }
+}
+
+impl Sub<i32> for Month {
+  type Output = Self;
+
+  /// Return the month that is the given number of months earlier, wrapping around the start of
+  /// the year.
+  fn sub(self, n: i32) -> Self::Output {
+    self.wrapping_add(-(n % 12))
+  }
+}
+
+impl fmt::Display for Month {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str(self.name())
+  }
+}
+
+impl FromStr for Month {
+  type Err = InvalidMonthError;
+
+  /// Parse a month from its English name (`"March"`) or three-letter abbreviation (`"Mar"`),
+  /// ignoring ASCII case.
+  ///
+  /// With the `i18n` feature, the names and abbreviations in each [`Locale`] are also accepted.
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    Month::all().find(|m| m.is_named(s)).ok_or(InvalidMonthError)
+  }
+}
+
+impl TryFrom<u8> for Month {
+  type Error = InvalidMonthError;
+
+  /// Convert a number to a month, with January as 1 and December as 12.
+  fn try_from(value: u8) -> Result<Self, Self::Error> {
+    match value {
+      1..=12 => Ok(Month::from_number(value)),
+      _ => Err(InvalidMonthError),
+    }
+  }
+}
+
+impl From<Month> for u8 {
+  /// Convert a month to a number, with January as 1 and December as 12.
+  fn from(month: Month) -> Self {
+    month as u8
+  }
+}
+
+/// An error returned when a string or number does not represent a month.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct InvalidMonthError;
+
+impl fmt::Display for InvalidMonthError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str("Invalid month")
+  }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidMonthError {}
+
+#[cfg(test)]
+mod tests {
+  use assert2::check;
+
+  use super::*;
+
+  #[test]
+  fn test_all() {
+    let months: Vec<_> = Month::all().collect();
+    check!(months.len() == 12);
+    check!(months.windows(2).all(|m| m[0].succ() == (m[1], false)));
+    check!(months.windows(2).all(|m| m[1].pred() == (m[0], false)));
+    check!(Month::all().next_back() == Some(Month::December));
+  }
+
+  #[test]
+  fn test_succ_pred() {
+    check!(Month::December.succ() == (Month::January, true));
+    check!(Month::January.pred() == (Month::December, true));
+    for month in Month::all() {
+      check!(month.succ().0.pred().0 == month);
+    }
+  }
+
+  #[test]
+  fn test_wrapping() {
+    check!(Month::January + 1 == Month::February);
+    check!(Month::December + 1 == Month::January);
+    check!(Month::January - 1 == Month::December);
+    check!(Month::March + 24 == Month::March);
+    check!(Month::March - 25 == Month::February);
+    check!(Month::June + i32::MAX == Month::January);
+    check!(Month::June + i32::MIN == Month::October);
+    check!(Month::June - i32::MIN == Month::February);
+    for month in Month::all() {
+      check!(month + 1 == month.succ().0);
+      check!(month - 1 == month.pred().0);
+    }
+  }
+
+  #[test]
+  fn test_days_in() {
+    check!(Month::February.days_in(1900) == 28);
+    check!(Month::February.days_in(2000) == 29);
+    for year in [2023, 2024] {
+      let days: u16 = Month::all().map(|m| m.days_in(year) as u16).sum();
+      check!(days == utils::days_in_year(year));
+    }
+  }
+
+  #[test]
+  fn test_month_of_year() {
+    for date in date! { 2024-01-01 }.iter_through(date! { 2024-12-31 }) {
+      check!(date.month_of_year().number() == date.month());
+    }
+  }
+
+  #[test]
+  fn test_numeric() {
+    for n in 1..=12 {
+      check!(u8::from(Month::try_from(n).unwrap()) == n);
+    }
+    check!(Month::try_from(0) == Err(InvalidMonthError));
+    check!(Month::try_from(13) == Err(InvalidMonthError));
+  }
+
+  #[test]
+  fn test_from_str() {
+    check!("March".parse::<Month>() == Ok(Month::March));
+    check!("mar".parse::<Month>() == Ok(Month::March));
+    check!("SEPTEMBER".parse::<Month>() == Ok(Month::September));
+    for month in Month::all() {
+      check!(month.to_string().parse::<Month>() == Ok(month));
+      check!(month.abbreviation().parse::<Month>() == Ok(month));
+    }
+    check!("Marc".parse::<Month>() == Err(InvalidMonthError));
+    check!("".parse::<Month>() == Err(InvalidMonthError));
+    check!(InvalidMonthError.to_string() == "Invalid month");
+  }
+
+  #[test]
+  #[cfg(feature = "i18n")]
+  fn test_from_str_localized() {
+    check!("März".parse::<Month>() == Ok(Month::March));
+    check!("février".parse::<Month>() == Ok(Month::February));
+    check!("Diciembre".parse::<Month>() == Ok(Month::December));
+    check!("mrt".parse::<Month>() == Ok(Month::March));
+    check!("juil.".parse::<Month>() == Ok(Month::July));
+    for locale in Locale::ALL {
+      for month in Month::all() {
+        let i = month as usize - 1;
+        check!(locale.month_names()[i].parse::<Month>() == Ok(month));
+        check!(locale.month_abbvs()[i].parse::<Month>() == Ok(month));
+      }
+    }
+  }
+}
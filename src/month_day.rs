@@ -0,0 +1,242 @@
+use core::fmt;
+
+use crate::utils;
+use crate::Date;
+use crate::DateError;
+use crate::Feb29Policy;
+
+/// A month and day without a year, such as a birthday, anniversary, or fixed-date holiday.
+///
+/// Month-days are written as `--08-15` (the ISO 8601 form used by [`Display`](fmt::Display)).
+///
+/// ## Examples
+///
+/// ```
+/// use date::date;
+/// use date::Feb29Policy;
+/// use date::MonthDay;
+///
+/// let christmas = MonthDay::new(12, 25);
+/// assert_eq!(christmas.at_year(2024), Ok(date! { 2024-12-25 }));
+/// assert_eq!(
+///   christmas.next_after(date! { 2024-12-25 }, Feb29Policy::Feb28),
+///   Some(date! { 2025-12-25 }),
+/// );
+/// assert_eq!(christmas.to_string(), "--12-25");
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct MonthDay {
+  month: u8,
+  day: u8,
+}
+
+impl MonthDay {
+  /// Construct a new month-day from the provided month (1–12) and day.
+  ///
+  /// February 29 is permitted.
+  ///
+  /// ## Panic
+  ///
+  /// This function panics if the month is not between 1 and 12, or if the day does not occur in
+  /// that month in a leap year.
+  pub const fn new(month: u8, day: u8) -> Self {
+    match Self::checked_new(month, day) {
+      Some(month_day) => month_day,
+      None => panic!("Day out-of-bounds"),
+    }
+  }
+
+  /// Construct a new month-day, or return `None` if the day does not occur in that month in a
+  /// leap year.
+  ///
+  /// This is the non-panicking counterpart to [`MonthDay::new`].
+  pub const fn checked_new(month: u8, day: u8) -> Option<Self> {
+    match utils::is_valid_date(2000, month, day) {
+      true => Some(Self { month, day }),
+      false => None,
+    }
+  }
+
+  /// The month, between 1 and 12.
+  #[inline]
+  pub const fn month(&self) -> u8 {
+    self.month
+  }
+
+  /// The day of the month.
+  #[inline]
+  pub const fn day(&self) -> u8 {
+    self.day
+  }
+
+  /// Whether this is February 29.
+  #[inline]
+  pub const fn is_leap_day(&self) -> bool {
+    self.month == 2 && self.day == 29
+  }
+
+  /// The date on which this month and day falls in the given year.
+  ///
+  /// This is strict: February 29 in a year that is not a leap year returns an error. Use
+  /// [`MonthDay::at_year_with`] to choose a different behavior.
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::date;
+  /// use date::DateError;
+  /// use date::MonthDay;
+  ///
+  /// let leap_day = MonthDay::new(2, 29);
+  /// assert_eq!(leap_day.at_year(2024), Ok(date! { 2024-02-29 }));
+  /// assert_eq!(
+  ///   leap_day.at_year(2023),
+  ///   Err(DateError::DayOutOfRange { year: 2023, month: 2, day: 29 }),
+  /// );
+  /// ```
+  pub const fn at_year(&self, year: i16) -> Result<Date, DateError> {
+    Date::try_new(year, self.month, self.day)
+  }
+
+  /// The date on which this month and day falls in the given year, using the provided policy to
+  /// place February 29 in years that are not leap years.
+  ///
+  /// Returns `None` only for February 29 in a non-leap year under
+  /// [`Feb29Policy::LeapYearsOnly`].
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::date;
+  /// use date::Feb29Policy;
+  /// use date::MonthDay;
+  ///
+  /// let leap_day = MonthDay::new(2, 29);
+  /// assert_eq!(leap_day.at_year_with(2023, Feb29Policy::Mar1), Some(date! { 2023-03-01 }));
+  /// assert_eq!(leap_day.at_year_with(2023, Feb29Policy::LeapYearsOnly), None);
+  /// ```
+  pub const fn at_year_with(&self, year: i16, policy: Feb29Policy) -> Option<Date> {
+    policy.resolve(year, self.month, self.day)
+  }
+
+  /// The first date strictly after `after` that falls on this month and day, using the provided
+  /// policy to place February 29 in years that are not leap years.
+  ///
+  /// Returns `None` if there is no such date before [`Date::MAX`].
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::date;
+  /// use date::Feb29Policy;
+  /// use date::MonthDay;
+  ///
+  /// let leap_day = MonthDay::new(2, 29);
+  /// let today = date! { 2024-06-01 };
+  /// assert_eq!(leap_day.next_after(today, Feb29Policy::Feb28), Some(date! { 2025-02-28 }));
+  /// assert_eq!(leap_day.next_after(today, Feb29Policy::LeapYearsOnly), Some(date! { 2028-02-29 }));
+  /// ```
+  pub fn next_after(&self, after: Date, policy: Feb29Policy) -> Option<Date> {
+    (after.year()..=i16::MAX)
+      .filter_map(|y| policy.resolve(y, self.month, self.day))
+      .find(|d| *d > after)
+  }
+}
+
+impl Date {
+  /// The month and day of this date, without the year.
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::date;
+  /// use date::MonthDay;
+  ///
+  /// assert_eq!(date! { 2024-08-15 }.month_day(), MonthDay::new(8, 15));
+  /// ```
+  pub const fn month_day(&self) -> MonthDay {
+    let (_, month, day) = self.ymd();
+    MonthDay { month, day }
+  }
+}
+
+impl From<Date> for MonthDay {
+  fn from(date: Date) -> Self {
+    date.month_day()
+  }
+}
+
+impl fmt::Display for MonthDay {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "--{:02}-{:02}", self.month, self.day)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use assert2::check;
+
+  use super::*;
+
+  #[test]
+  fn test_checked_new() {
+    check!(MonthDay::checked_new(2, 29) == Some(MonthDay::new(2, 29)));
+    check!(MonthDay::checked_new(2, 30) == None);
+    check!(MonthDay::checked_new(4, 31) == None);
+    check!(MonthDay::checked_new(13, 1) == None);
+    check!(MonthDay::checked_new(1, 0) == None);
+  }
+
+  #[test]
+  #[should_panic]
+  fn test_invalid_month_day() {
+    MonthDay::new(6, 31);
+  }
+
+  #[test]
+  fn test_at_year() {
+    check!(MonthDay::new(8, 15).at_year(2024) == Ok(date! { 2024-08-15 }));
+    check!(MonthDay::new(2, 29).at_year(2000) == Ok(date! { 2000-02-29 }));
+    check!(MonthDay::new(2, 29).at_year(1900).is_err());
+    for date in date! { 2023-01-01 }.iter_through(date! { 2024-12-31 }) {
+      check!(date.month_day().at_year(date.year()) == Ok(date));
+    }
+  }
+
+  #[test]
+  fn test_at_year_with() {
+    let leap_day = MonthDay::new(2, 29);
+    check!(leap_day.at_year_with(2024, Feb29Policy::LeapYearsOnly) == Some(date! { 2024-02-29 }));
+    check!(leap_day.at_year_with(2025, Feb29Policy::Feb28) == Some(date! { 2025-02-28 }));
+    check!(leap_day.at_year_with(2025, Feb29Policy::Mar1) == Some(date! { 2025-03-01 }));
+    check!(leap_day.at_year_with(2025, Feb29Policy::LeapYearsOnly) == None);
+    check!(MonthDay::new(3, 1).at_year_with(2025, Feb29Policy::LeapYearsOnly).is_some());
+  }
+
+  #[test]
+  fn test_next_after() {
+    let month_day = MonthDay::new(6, 15);
+    let policy = Feb29Policy::Feb28;
+    check!(month_day.next_after(date! { 2024-06-14 }, policy) == Some(date! { 2024-06-15 }));
+    check!(month_day.next_after(date! { 2024-06-15 }, policy) == Some(date! { 2025-06-15 }));
+    check!(month_day.next_after(date! { 2024-12-31 }, policy) == Some(date! { 2025-06-15 }));
+    check!(month_day.next_after(date! { 32767-06-15 }, policy) == None);
+    let leap_day = MonthDay::new(2, 29);
+    let after = date! { 2096-02-29 };
+    check!(leap_day.next_after(after, Feb29Policy::Feb28) == Some(date! { 2097-02-28 }));
+    check!(leap_day.next_after(after, Feb29Policy::Mar1) == Some(date! { 2097-03-01 }));
+    check!(leap_day.next_after(after, Feb29Policy::LeapYearsOnly) == Some(date! { 2104-02-29 }));
+  }
+
+  #[test]
+  fn test_display() {
+    check!(MonthDay::new(8, 15).to_string() == "--08-15");
+    check!(MonthDay::new(12, 1).to_string() == "--12-01");
+  }
+
+  #[test]
+  fn test_ord() {
+    check!(MonthDay::new(1, 31) < MonthDay::new(2, 1));
+    check!(MonthDay::new(2, 28) < MonthDay::new(2, 29));
+  }
+}
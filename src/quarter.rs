@@ -0,0 +1,302 @@
+use core::fmt;
+use core::ops::Add;
+use core::ops::Sub;
+use core::str::FromStr;
+
+use crate::utils;
+use crate::Date;
+
+/// A quarter of a calendar year (Q1 through Q4).
+///
+/// Quarters are written as `2024-Q3` (the format used by [`Display`](fmt::Display)), and can also
+/// be parsed from the `Q3 2024` form that is common in financial reporting.
+///
+/// ## Examples
+///
+/// ```
+/// use date::Quarter;
+///
+/// let quarter = Quarter::new(2024, 3);
+/// assert_eq!(quarter.to_string(), "2024-Q3");
+/// assert_eq!("2024-Q3".parse::<Quarter>(), Ok(quarter));
+/// assert_eq!("Q3 2024".parse::<Quarter>(), Ok(quarter));
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Quarter {
+  year: i16,
+  number: u8,
+}
+
+impl Quarter {
+  /// Construct a new quarter from the provided year and quarter number (1–4).
+  ///
+  /// ## Panic
+  ///
+  /// This function panics if the quarter number is not between 1 and 4.
+  pub const fn new(year: i16, number: u8) -> Self {
+    assert!(number >= 1 && number <= 4, "Quarter out-of-bounds");
+    Self { year, number }
+  }
+
+  /// Construct a new quarter, or return `None` if the quarter number is not between 1 and 4.
+  ///
+  /// This is the non-panicking counterpart to [`Quarter::new`].
+  pub const fn checked_new(year: i16, number: u8) -> Option<Self> {
+    match number >= 1 && number <= 4 {
+      true => Some(Self { year, number }),
+      false => None,
+    }
+  }
+
+  /// The year.
+  #[inline]
+  pub const fn year(&self) -> i16 {
+    self.year
+  }
+
+  /// The quarter number, between 1 and 4.
+  #[inline]
+  pub const fn number(&self) -> u8 {
+    self.number
+  }
+
+  /// The first day of the quarter.
+  pub const fn start(&self) -> Date {
+    Date::new(self.year, self.number * 3 - 2, 1)
+  }
+
+  /// The last day of the quarter.
+  pub const fn end(&self) -> Date {
+    let month = self.number * 3;
+    Date::new(self.year, month, utils::days_in_month(self.year, month))
+  }
+
+  /// Whether the given date falls within the quarter.
+  pub const fn contains(&self, date: Date) -> bool {
+    date.0 >= self.start().0 && date.0 <= self.end().0
+  }
+
+  /// The following quarter.
+  ///
+  /// ## Panic
+  ///
+  /// This function panics if the result would be after the year 32,767.
+  pub const fn succ(self) -> Self {
+    self.add_quarters(1)
+  }
+
+  /// The preceding quarter.
+  ///
+  /// ## Panic
+  ///
+  /// This function panics if the result would be before the year -32,768.
+  pub const fn pred(self) -> Self {
+    self.add_quarters(-1)
+  }
+
+  /// The quarter that is the given number of quarters later (or earlier, if `n` is negative).
+  ///
+  /// This is equivalent to `quarter + n`, but can be used in `const` contexts.
+  ///
+  /// ## Panic
+  ///
+  /// This function panics if the result would be outside the years -32,768 through 32,767. Use
+  /// [`Quarter::checked_add_quarters`] to handle this case.
+  pub const fn add_quarters(self, n: i32) -> Self {
+    match self.checked_add_quarters(n) {
+      Some(quarter) => quarter,
+      None => panic!("Quarter out-of-bounds"),
+    }
+  }
+
+  /// The quarter that is the given number of quarters later (or earlier, if `n` is negative), or
+  /// `None` if the result would be outside the years -32,768 through 32,767.
+  pub const fn checked_add_quarters(self, n: i32) -> Option<Self> {
+    let total = self.year as i64 * 4 + self.number as i64 - 1 + n as i64;
+    let year = total.div_euclid(4);
+    match year >= i16::MIN as i64 && year <= i16::MAX as i64 {
+      true => Some(Self { year: year as i16, number: total.rem_euclid(4) as u8 + 1 }),
+      false => None,
+    }
+  }
+}
+
+impl Date {
+  /// The quarter containing this date.
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::date;
+  /// use date::Quarter;
+  ///
+  /// let quarter = date! { 2024-08-15 }.quarter();
+  /// assert_eq!(quarter, Quarter::new(2024, 3));
+  /// assert_eq!(quarter.start(), date! { 2024-07-01 });
+  /// assert_eq!(quarter.end(), date! { 2024-09-30 });
+  /// assert_eq!(quarter + 2, Quarter::new(2025, 1));
+  /// ```
+  pub const fn quarter(&self) -> Quarter {
+    let (year, month, _) = self.ymd();
+    Quarter { year, number: (month - 1) / 3 + 1 }
+  }
+}
+
+impl Add<i32> for Quarter {
+  type Output = Self;
+
+  /// Return the quarter that is the given number of quarters later.
+  fn add(self, n: i32) -> Self::Output {
+    self.add_quarters(n)
+  }
+}
+
+impl Sub<i32> for Quarter {
+  type Output = Self;
+
+  /// Return the quarter that is the given number of quarters earlier.
+  fn sub(self, n: i32) -> Self::Output {
+    self.add_quarters(-n)
+  }
+}
+
+impl fmt::Display for Quarter {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self.year < 0 {
+      true => write!(f, "-{:04}-Q{}", self.year.unsigned_abs(), self.number),
+      false => write!(f, "{:04}-Q{}", self.year, self.number),
+    }
+  }
+}
+
+impl FromStr for Quarter {
+  type Err = ParseQuarterError;
+
+  /// Parse a quarter, either in the `YYYY-Qn` form or the `Qn YYYY` form.
+  ///
+  /// The `Q` may be written in either case.
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let (year, quarter) = match s.get(..1) {
+      Some("Q" | "q") => s.split_once(' ').map(|(q, y)| (y, q)),
+      _ => s.rsplit_once('-'),
+    }
+    .ok_or(ParseQuarterError)?;
+    let number = match quarter {
+      "Q1" | "q1" => 1,
+      "Q2" | "q2" => 2,
+      "Q3" | "q3" => 3,
+      "Q4" | "q4" => 4,
+      _ => return Err(ParseQuarterError),
+    };
+    if year.trim_start_matches('-').len() < 4 || year.starts_with('+') {
+      return Err(ParseQuarterError);
+    }
+    let year: i16 = year.parse().map_err(|_| ParseQuarterError)?;
+    Ok(Self { year, number })
+  }
+}
+
+/// An error returned when parsing a [`Quarter`] from a string fails.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ParseQuarterError;
+
+impl fmt::Display for ParseQuarterError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str("Invalid quarter; expected `YYYY-Qn` or `Qn YYYY`")
+  }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseQuarterError {}
+
+#[cfg(test)]
+mod tests {
+  use assert2::check;
+
+  use super::*;
+
+  #[test]
+  fn test_display() {
+    check!(Quarter::new(2024, 3).to_string() == "2024-Q3");
+    check!(Quarter::new(812, 1).to_string() == "0812-Q1");
+    check!(Quarter::new(-44, 2).to_string() == "-0044-Q2");
+  }
+
+  #[test]
+  fn test_parse() {
+    check!("2024-Q3".parse::<Quarter>() == Ok(Quarter::new(2024, 3)));
+    check!("2024-q4".parse::<Quarter>() == Ok(Quarter::new(2024, 4)));
+    check!("Q1 2024".parse::<Quarter>() == Ok(Quarter::new(2024, 1)));
+    check!("q2 2024".parse::<Quarter>() == Ok(Quarter::new(2024, 2)));
+    check!("-0044-Q2".parse::<Quarter>() == Ok(Quarter::new(-44, 2)));
+    check!("Q2 -0044".parse::<Quarter>() == Ok(Quarter::new(-44, 2)));
+    check!("2024-Q5".parse::<Quarter>() == Err(ParseQuarterError));
+    check!("2024-Q0".parse::<Quarter>().is_err());
+    check!("24-Q1".parse::<Quarter>().is_err());
+    check!("+2024-Q1".parse::<Quarter>().is_err());
+    check!("Q1-2024".parse::<Quarter>().is_err());
+    check!("2024 Q1".parse::<Quarter>().is_err());
+    check!("2024-03".parse::<Quarter>().is_err());
+    check!("".parse::<Quarter>().is_err());
+  }
+
+  #[test]
+  fn test_round_trip() {
+    for year in [-32_768, -1, 0, 1999, 2024, 32_767] {
+      for number in 1..=4 {
+        let quarter = Quarter::new(year, number);
+        check!(quarter.to_string().parse::<Quarter>() == Ok(quarter));
+      }
+    }
+  }
+
+  #[test]
+  fn test_quarter() {
+    for date in date! { 2023-12-01 }.iter_through(date! { 2025-01-31 }) {
+      let quarter = date.quarter();
+      check!(quarter.contains(date));
+      check!(quarter.start() <= date && date <= quarter.end());
+      check!(quarter.start().day() == 1);
+      check!(Date(quarter.end().0 + 1) == quarter.succ().start());
+      check!(!quarter.succ().contains(date));
+      check!(!quarter.pred().contains(date));
+    }
+    check!(Quarter::new(2024, 1).end() == date! { 2024-03-31 });
+    check!(Quarter::new(2024, 2).end() == date! { 2024-06-30 });
+    check!(Quarter::new(2024, 4).start() == date! { 2024-10-01 });
+  }
+
+  #[test]
+  fn test_arithmetic() {
+    let quarter = Quarter::new(2024, 3);
+    check!(quarter.succ() == Quarter::new(2024, 4));
+    check!(quarter.succ().succ() == Quarter::new(2025, 1));
+    check!(quarter.pred() == Quarter::new(2024, 2));
+    check!(Quarter::new(2024, 1).pred() == Quarter::new(2023, 4));
+    check!(quarter + 6 == Quarter::new(2026, 1));
+    check!(quarter - 11 == Quarter::new(2021, 4));
+    check!(quarter + -3 == quarter - 3);
+    check!(Quarter::new(32767, 4).checked_add_quarters(1) == None);
+    check!(Quarter::new(-32768, 1).checked_add_quarters(-1) == None);
+    check!(Quarter::new(2024, 1).checked_add_quarters(i32::MAX) == None);
+  }
+
+  #[test]
+  #[should_panic]
+  fn test_succ_overflow() {
+    let _ = Quarter::new(32767, 4).succ();
+  }
+
+  #[test]
+  fn test_checked_new() {
+    check!(Quarter::checked_new(2024, 4) == Some(Quarter::new(2024, 4)));
+    check!(Quarter::checked_new(2024, 0) == None);
+    check!(Quarter::checked_new(2024, 5) == None);
+  }
+
+  #[test]
+  #[should_panic]
+  fn test_invalid_quarter() {
+    Quarter::new(2024, 5);
+  }
+}
@@ -0,0 +1,423 @@
+use core::fmt;
+use core::ops::Range;
+use core::ops::RangeInclusive;
+use core::str::FromStr;
+
+use crate::interval::CalendarDuration;
+use crate::iter::DateIterator;
+use crate::utils;
+use crate::Date;
+use crate::DateError;
+
+/// An inclusive span of dates, from `start` through `end`.
+///
+/// Both endpoints are part of the range, matching [`Date::iter_through`]. A range whose end is
+/// before its start is empty; conversions that produce an empty range return
+/// [`DateRange::EMPTY`].
+///
+/// ## Examples
+///
+/// ```
+/// use date::date;
+/// use date::DateRange;
+///
+/// let q1 = DateRange::new(date! { 2024-01-01 }, date! { 2024-03-31 });
+/// assert_eq!(q1.len_days(), 91);
+/// assert!(q1.contains(date! { 2024-02-29 }));
+/// assert!(!q1.contains(date! { 2024-04-01 }));
+///
+/// let spring = DateRange::new(date! { 2024-03-01 }, date! { 2024-05-31 });
+/// let march = DateRange::new(date! { 2024-03-01 }, date! { 2024-03-31 });
+/// assert_eq!(q1.intersect(spring), Some(march));
+/// ```
+///
+/// Ranges of dates (`start..end` and `start..=end`) convert into a `DateRange`, which makes them
+/// iterable:
+///
+/// ```
+/// use date::date;
+/// use date::DateRange;
+///
+/// let (start, end) = (date! { 2024-02-27 }, date! { 2024-03-01 });
+/// for date in DateRange::from(start..=end) {
+///   assert!(date >= start && date <= end);
+/// }
+/// assert_eq!(DateRange::from(start..=end).into_iter().count(), 4);
+/// assert_eq!(DateRange::from(start..end).into_iter().count(), 3);
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct DateRange {
+  start: Date,
+  end: Date,
+}
+
+impl DateRange {
+  /// The canonical empty range, which runs from [`Date::MAX`] back to [`Date::MIN`].
+  pub const EMPTY: Self = Self { start: Date::MAX, end: Date::MIN };
+
+  /// A range from `start` through `end`, inclusive.
+  #[inline]
+  pub const fn new(start: Date, end: Date) -> Self {
+    Self { start, end }
+  }
+
+  /// The first date in the range.
+  #[inline]
+  pub const fn start(&self) -> Date {
+    self.start
+  }
+
+  /// The last date in the range.
+  #[inline]
+  pub const fn end(&self) -> Date {
+    self.end
+  }
+
+  /// Whether the range contains no dates (its end is before its start).
+  #[inline]
+  pub const fn is_empty(&self) -> bool {
+    self.end.0 < self.start.0
+  }
+
+  /// The number of dates in the range.
+  pub const fn len_days(&self) -> u32 {
+    match self.is_empty() {
+      true => 0,
+      false => (self.end.0 - self.start.0) as u32 + 1,
+    }
+  }
+
+  /// Whether the given date falls within the range.
+  pub const fn contains(&self, date: Date) -> bool {
+    date.0 >= self.start.0 && date.0 <= self.end.0
+  }
+
+  /// An iterator over every date in the range.
+  pub const fn iter(&self) -> DateIterator {
+    self.start.iter_through(self.end)
+  }
+
+  /// The dates that fall within both ranges, or `None` if the ranges do not overlap.
+  pub const fn intersect(&self, other: DateRange) -> Option<DateRange> {
+    let start = if self.start.0 > other.start.0 { self.start } else { other.start };
+    let end = if self.end.0 < other.end.0 { self.end } else { other.end };
+    match start.0 <= end.0 {
+      true => Some(DateRange { start, end }),
+      false => None,
+    }
+  }
+
+  /// The dates that fall within either range, or `None` if the ranges neither overlap nor are
+  /// adjacent (in which case the union is not a single range).
+  ///
+  /// An empty range does not affect the union.
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::date;
+  /// use date::DateRange;
+  ///
+  /// let january = DateRange::new(date! { 2024-01-01 }, date! { 2024-01-31 });
+  /// let february = DateRange::new(date! { 2024-02-01 }, date! { 2024-02-29 });
+  /// let april = DateRange::new(date! { 2024-04-01 }, date! { 2024-04-30 });
+  /// assert_eq!(
+  ///   january.union(february),
+  ///   Some(DateRange::new(date! { 2024-01-01 }, date! { 2024-02-29 })),
+  /// );
+  /// assert_eq!(january.union(april), None);
+  /// ```
+  pub const fn union(&self, other: DateRange) -> Option<DateRange> {
+    if other.is_empty() {
+      return Some(*self);
+    }
+    if self.is_empty() {
+      return Some(other);
+    }
+    if self.start.0 > other.end.0 + 1 || other.start.0 > self.end.0 + 1 {
+      return None;
+    }
+    let start = if self.start.0 < other.start.0 { self.start } else { other.start };
+    let end = if self.end.0 > other.end.0 { self.end } else { other.end };
+    Some(DateRange { start, end })
+  }
+}
+
+impl fmt::Display for DateRange {
+  /// Write the range as an ISO 8601 time interval, such as `2024-01-01/2024-03-31`.
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{}/{}", self.start, self.end)
+  }
+}
+
+impl FromStr for DateRange {
+  type Err = DateError;
+
+  /// Parse an ISO 8601 time interval of dates.
+  ///
+  /// Three forms are accepted: `start/end` (such as `2024-01-01/2024-03-31`), `start/duration`
+  /// (such as `2024-01-01/P3M`), and `duration/end` (such as `P3M/2024-03-31`). Durations are
+  /// parsed as a [`CalendarDuration`], and describe the length of the range: the range
+  /// `2024-01-01/P3M` ends on the day before `2024-01-01` plus three months, which is
+  /// `2024-03-31`.
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::date;
+  /// use date::DateRange;
+  ///
+  /// let q1 = DateRange::new(date! { 2024-01-01 }, date! { 2024-03-31 });
+  /// assert_eq!("2024-01-01/2024-03-31".parse::<DateRange>(), Ok(q1));
+  /// assert_eq!("2024-01-01/P3M".parse::<DateRange>(), Ok(q1));
+  /// assert_eq!("P3M/2024-03-31".parse::<DateRange>(), Ok(q1));
+  /// assert_eq!(q1.to_string(), "2024-01-01/2024-03-31");
+  /// ```
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let (start, end) = s.split_once('/').ok_or(DateError::PARSE)?;
+    let range = match (Date::parse_iso8601(start), Date::parse_iso8601(end)) {
+      (Some(start), Some(end)) => Self::new(start, end),
+      (Some(start), None) => {
+        let duration: CalendarDuration = end.parse()?;
+        let (years, months, days) = components(duration);
+        let (y, m, d) = start.ymd();
+        let end = checked_offset((y as i64, m, d), years * 12 + months, days - 1);
+        Self::new(start, end.ok_or(DateError::PARSE)?)
+      },
+      (None, Some(end)) => {
+        let duration: CalendarDuration = start.parse()?;
+        let (years, months, days) = components(duration);
+        // The day after `end` is not representable if `end` is the last representable date.
+        let next = match end == Date::MAX {
+          true => (i16::MAX as i64 + 1, 1, 1),
+          false => {
+            let (y, m, d) = Date(end.0 + 1).ymd();
+            (y as i64, m, d)
+          },
+        };
+        let start = checked_offset(next, -years * 12 - months, -days);
+        Self::new(start.ok_or(DateError::PARSE)?, end)
+      },
+      (None, None) => return Err(DateError::PARSE),
+    };
+    match range.end < range.start {
+      true => Err(DateError::PARSE),
+      false => Ok(range),
+    }
+  }
+}
+
+/// The years, months, and days of the given duration.
+const fn components(duration: CalendarDuration) -> (i64, i64, i64) {
+  (duration.year_part() as i64, duration.month_part() as i64, duration.day_part() as i64)
+}
+
+/// The date the given number of months and then days from the given year, month, and day (which
+/// need not be representable), or `None` if the result is out of range.
+///
+/// The arithmetic is done in 64 bits, so that an interval may be measured from the day after
+/// [`Date::MAX`].
+const fn checked_offset(
+  (year, month, day): (i64, u8, u8), months: i64, days: i64,
+) -> Option<Date> {
+  let total = year * 12 + month as i64 - 1 + months;
+  let (year, month) = (total.div_euclid(12), total.rem_euclid(12) as u8 + 1);
+
+  // The Gregorian calendar repeats every 400 years, so the year can be reduced to one that fits
+  // in an `i16` and the difference made up in days.
+  let (cycles, year_of_cycle) = (year.div_euclid(400), year.rem_euclid(400) as i16);
+  let last = utils::days_in_month(year_of_cycle, month);
+  let day = if day > last { last } else { day };
+  let days = utils::days_from_civil(year_of_cycle, month, day) as i64 + cycles * 146_097 + days;
+  match days >= Date::MIN.0 as i64 && days <= Date::MAX.0 as i64 {
+    true => Some(Date(days as i32)),
+    false => None,
+  }
+}
+
+impl From<(Date, Date)> for DateRange {
+  fn from((start, end): (Date, Date)) -> Self {
+    Self::new(start, end)
+  }
+}
+
+impl From<Range<Date>> for DateRange {
+  /// Convert a half-open range of dates, which excludes its end, into a `DateRange`.
+  ///
+  /// An empty half-open range converts to [`DateRange::EMPTY`].
+  fn from(range: Range<Date>) -> Self {
+    match range.start < range.end {
+      true => Self::new(range.start, Date(range.end.0 - 1)),
+      false => Self::EMPTY,
+    }
+  }
+}
+
+impl From<RangeInclusive<Date>> for DateRange {
+  fn from(range: RangeInclusive<Date>) -> Self {
+    Self::new(*range.start(), *range.end())
+  }
+}
+
+impl From<DateRange> for (Date, Date) {
+  fn from(range: DateRange) -> Self {
+    (range.start, range.end)
+  }
+}
+
+impl IntoIterator for DateRange {
+  type IntoIter = DateIterator;
+  type Item = Date;
+
+  fn into_iter(self) -> Self::IntoIter {
+    self.iter()
+  }
+}
+
+impl IntoIterator for &DateRange {
+  type IntoIter = DateIterator;
+  type Item = Date;
+
+  fn into_iter(self) -> Self::IntoIter {
+    self.iter()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use assert2::check;
+
+  use super::*;
+
+  #[test]
+  fn test_len() {
+    let range = DateRange::new(date! { 2024-01-01 }, date! { 2024-12-31 });
+    check!(range.len_days() == 366);
+    check!(!range.is_empty());
+    check!(range.iter().count() == 366);
+    check!(range.into_iter().all(|d| range.contains(d)));
+    let single = DateRange::new(date! { 2024-01-01 }, date! { 2024-01-01 });
+    check!(single.len_days() == 1);
+    let empty = DateRange::new(date! { 2024-01-02 }, date! { 2024-01-01 });
+    check!(empty.is_empty());
+    check!(empty.len_days() == 0);
+    check!(empty.iter().next() == None);
+    check!(!empty.contains(date! { 2024-01-01 }));
+    check!(DateRange::new(Date::MIN, Date::MAX).len_days() == 23_936_532);
+  }
+
+  #[test]
+  fn test_intersect() {
+    let a = DateRange::new(date! { 2024-01-01 }, date! { 2024-01-31 });
+    let b = DateRange::new(date! { 2024-01-15 }, date! { 2024-02-15 });
+    let c = DateRange::new(date! { 2024-02-01 }, date! { 2024-02-29 });
+    check!(a.intersect(b) == Some(DateRange::new(date! { 2024-01-15 }, date! { 2024-01-31 })));
+    check!(b.intersect(a) == a.intersect(b));
+    check!(a.intersect(c) == None);
+    check!(a.intersect(a) == Some(a));
+    let edge = DateRange::new(date! { 2024-01-31 }, date! { 2024-02-01 });
+    check!(a.intersect(edge) == Some(DateRange::new(date! { 2024-01-31 }, date! { 2024-01-31 })));
+  }
+
+  #[test]
+  fn test_union() {
+    let a = DateRange::new(date! { 2024-01-01 }, date! { 2024-01-31 });
+    let b = DateRange::new(date! { 2024-01-15 }, date! { 2024-02-15 });
+    let c = DateRange::new(date! { 2024-02-01 }, date! { 2024-02-29 });
+    let d = DateRange::new(date! { 2024-02-02 }, date! { 2024-02-29 });
+    let empty = DateRange::new(date! { 2030-01-02 }, date! { 2030-01-01 });
+    check!(a.union(b) == Some(DateRange::new(date! { 2024-01-01 }, date! { 2024-02-15 })));
+    check!(a.union(c) == Some(DateRange::new(date! { 2024-01-01 }, date! { 2024-02-29 })));
+    check!(c.union(a) == a.union(c));
+    check!(a.union(d) == None);
+    check!(a.union(empty) == Some(a));
+    check!(empty.union(a) == Some(a));
+    let max = DateRange::new(Date::MAX, Date::MAX);
+    check!(max.union(a) == None);
+  }
+
+  #[test]
+  fn test_iso_interval() {
+    let cases = [
+      ("2024-01-01/2024-03-31", date! { 2024-01-01 }, date! { 2024-03-31 }),
+      ("2024-01-01/2024-01-01", date! { 2024-01-01 }, date! { 2024-01-01 }),
+      ("2024-01-01/P3M", date! { 2024-01-01 }, date! { 2024-03-31 }),
+      ("2024-01-31/P1M", date! { 2024-01-31 }, date! { 2024-02-28 }),
+      ("2024-01-01/P1D", date! { 2024-01-01 }, date! { 2024-01-01 }),
+      ("2024-01-01/P2W", date! { 2024-01-01 }, date! { 2024-01-14 }),
+      ("2024-01-01/P1Y", date! { 2024-01-01 }, date! { 2024-12-31 }),
+      ("P3M/2024-03-31", date! { 2024-01-01 }, date! { 2024-03-31 }),
+      ("P1M/2024-02-29", date! { 2024-02-01 }, date! { 2024-02-29 }),
+      ("P1M/2024-03-30", date! { 2024-02-29 }, date! { 2024-03-30 }),
+      ("P1D/2024-03-31", date! { 2024-03-31 }, date! { 2024-03-31 }),
+      ("P1D/32767-12-31", Date::MAX, Date::MAX),
+      ("P1M/32767-12-31", date! { 32767-12-01 }, Date::MAX),
+      ("P1Y/32767-12-31", date! { 32767-01-01 }, Date::MAX),
+      ("32767-12-31/P1D", Date::MAX, Date::MAX),
+      ("32767-12-01/P1M", date! { 32767-12-01 }, Date::MAX),
+      ("P1D/-32768-01-01", Date::MIN, Date::MIN),
+      ("-32768-01-01/P1D", Date::MIN, Date::MIN),
+      ("-32768-01-01/P1M", Date::MIN, date! { -32768-01-31 }),
+      ("P65536Y/32767-12-31", Date::MIN, Date::MAX),
+      ("-32768-01-01/P65535Y11M31D", Date::MIN, Date::MAX),
+    ];
+    for (s, start, end) in cases {
+      check!(s.parse::<DateRange>() == Ok(DateRange::new(start, end)), "{s}");
+    }
+    for s in [
+      "",
+      "2024-01-01",
+      "2024-01-01/",
+      "/2024-01-01",
+      "2024-03-31/2024-01-01",
+      "2024-01-01/P0D",
+      "2024-01-01/-P1D",
+      "P1M/P1M",
+      "2024-01-01/2024-02-30",
+      "2024-01-01/P1X",
+      "32767-12-31/P2D",
+      "P2D/-32768-01-01",
+      "P65536Y1D/32767-12-31",
+      "-32768-01-01/P65536Y1D",
+    ] {
+      check!(s.parse::<DateRange>() == Err(DateError::PARSE), "{s}");
+    }
+    let range = DateRange::new(date! { 2024-01-01 }, date! { 2024-03-31 });
+    check!(range.to_string() == "2024-01-01/2024-03-31");
+    check!(range.to_string().parse::<DateRange>() == Ok(range));
+  }
+
+  #[test]
+  fn test_for_loop() {
+    let (start, end) = (date! { 2023-12-30 }, date! { 2024-01-02 });
+    let mut dates = vec![];
+    for date in DateRange::from(start..=end) {
+      dates.push(date);
+    }
+    check!(dates == start.iter_through(end).collect::<Vec<_>>());
+    let range = DateRange::from(start..end);
+    dates.clear();
+    for date in &range {
+      dates.push(date);
+    }
+    check!(dates == [date! { 2023-12-30 }, date! { 2023-12-31 }, date! { 2024-01-01 }]);
+  }
+
+  #[test]
+  fn test_conversions() {
+    let (start, end) = (date! { 2024-01-01 }, date! { 2024-01-31 });
+    check!(DateRange::from((start, end)) == DateRange::new(start, end));
+    check!(DateRange::from(start..=end) == DateRange::new(start, end));
+    check!(DateRange::from(start..end) == DateRange::new(start, date! { 2024-01-30 }));
+    check!(DateRange::from(start..start) == DateRange::EMPTY);
+    check!(DateRange::from(end..start) == DateRange::EMPTY);
+    check!(DateRange::from(Date::MIN..Date::MIN) == DateRange::EMPTY);
+    check!(DateRange::from(Date::MIN..Date::MIN).to_string().parse::<DateRange>().is_err());
+    check!(DateRange::from(Date::MAX..Date::MAX) == DateRange::EMPTY);
+    let first = DateRange::new(Date::MIN, Date::MIN);
+    check!(DateRange::from(Date::MIN..date! { -32768-01-02 }) == first);
+    check!(DateRange::EMPTY.is_empty());
+    check!(DateRange::EMPTY.len_days() == 0);
+    check!(<(Date, Date)>::from(DateRange::new(start, end)) == (start, end));
+  }
+}
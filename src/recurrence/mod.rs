@@ -0,0 +1,623 @@
+//! Recurring dates.
+//!
+//! A [`Recurrence`] describes a series of dates (such as "every other Tuesday" or "the second
+//! Tuesday of every month"), following the semantics of recurrence rules in [RFC 5545][rfc].
+//!
+//! [rfc]: https://datatracker.ietf.org/doc/html/rfc5545#section-3.3.10
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::utils;
+use crate::Date;
+use crate::NthWeekdayRule;
+use crate::Weekday;
+
+#[cfg(feature = "rrule")]
+mod rrule;
+
+#[cfg(feature = "rrule")]
+pub use rrule::RRuleError;
+
+/// The frequency at which a recurrence repeats.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Frequency {
+  /// Repeat every day (or every `interval` days).
+  Daily,
+  /// Repeat every week (or every `interval` weeks).
+  Weekly,
+  /// Repeat every month (or every `interval` months).
+  Monthly,
+  /// Repeat every year (or every `interval` years).
+  Yearly,
+}
+
+impl Frequency {
+  /// The number of periods in one 400-year cycle of the Gregorian calendar, after which the
+  /// calendar (including the days of the week) repeats.
+  const fn periods_per_cycle(self) -> u32 {
+    match self {
+      Self::Daily => 146_097,
+      Self::Weekly => 146_097 / 7,
+      Self::Monthly => 400 * 12,
+      Self::Yearly => 400,
+    }
+  }
+}
+
+/// A weekday within a recurrence period, optionally restricted to its `n`th occurrence in that
+/// period (counting from the end of the period if `n` is negative).
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub(crate) struct ByDay {
+  pub(crate) nth: Option<i8>,
+  pub(crate) weekday: Weekday,
+}
+
+/// A rule describing a recurring series of dates.
+///
+/// ## Examples
+///
+/// ```
+/// use date::date;
+/// use date::recurrence::Frequency;
+/// use date::recurrence::Recurrence;
+///
+/// let monthly = Recurrence::new(date! { 2024-01-31 }, Frequency::Monthly);
+/// let dates: Vec<_> = monthly.iter().take(3).collect();
+/// assert_eq!(dates, vec![date! { 2024-01-31 }, date! { 2024-03-31 }, date! { 2024-05-31 }]);
+/// ```
+///
+/// Recurrences are built up from a start date and frequency:
+///
+/// ```
+/// use date::date;
+/// use date::recurrence::Frequency;
+/// use date::recurrence::Recurrence;
+/// use date::Weekday;
+///
+/// // The second Tuesday of every other month, six times.
+/// let rule = Recurrence::new(date! { 2024-01-01 }, Frequency::Monthly)
+///   .with_interval(2)
+///   .with_nth_weekday(2, Weekday::Tuesday)
+///   .with_count(6);
+/// assert_eq!(rule.iter().last(), Some(date! { 2024-11-12 }));
+///
+/// // The last day of every month through the end of the year.
+/// let rule = Recurrence::new(date! { 2024-01-01 }, Frequency::Monthly)
+///   .with_month_day(-1)
+///   .with_until(date! { 2024-12-31 });
+/// let dates: Vec<_> = rule.iter().collect();
+/// assert_eq!(dates.len(), 12);
+/// assert_eq!(dates[1], date! { 2024-02-29 });
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Recurrence {
+  pub(crate) start: Date,
+  pub(crate) frequency: Frequency,
+  pub(crate) interval: u16,
+  pub(crate) by_day: Vec<ByDay>,
+  pub(crate) by_month_day: Vec<i8>,
+  pub(crate) by_month: Vec<u8>,
+  pub(crate) by_set_pos: Vec<i16>,
+  pub(crate) week_start: Weekday,
+  pub(crate) count: Option<u32>,
+  pub(crate) until: Option<Date>,
+}
+
+impl Recurrence {
+  /// A new recurrence, beginning on the given date and repeating at the given frequency.
+  ///
+  /// Occurrences fall on the same day of the week, month, or year as the start date, as
+  /// appropriate. Monthly and yearly recurrences skip periods in which that day does not exist
+  /// (for example, a monthly recurrence starting on the 31st skips months with 30 days).
+  pub fn new(start: Date, frequency: Frequency) -> Self {
+    Self {
+      start,
+      frequency,
+      interval: 1,
+      by_day: Vec::new(),
+      by_month_day: Vec::new(),
+      by_month: Vec::new(),
+      by_set_pos: Vec::new(),
+      week_start: Weekday::Monday,
+      count: None,
+      until: None,
+    }
+  }
+
+  /// The first date of the recurrence.
+  pub const fn start(&self) -> Date {
+    self.start
+  }
+
+  /// The frequency at which the recurrence repeats.
+  pub const fn frequency(&self) -> Frequency {
+    self.frequency
+  }
+
+  /// The number of periods between occurrences (1 means every period, 2 every other period, and
+  /// so on).
+  pub const fn interval(&self) -> u16 {
+    self.interval
+  }
+
+  /// The maximum number of occurrences, if any.
+  pub const fn count(&self) -> Option<u32> {
+    self.count
+  }
+
+  /// The last date on which the recurrence may occur, if any.
+  pub const fn until(&self) -> Option<Date> {
+    self.until
+  }
+
+  /// Repeat every `interval` periods, rather than every period.
+  ///
+  /// ## Panic
+  ///
+  /// This function panics if the interval is zero.
+  pub fn with_interval(mut self, interval: u16) -> Self {
+    assert!(interval > 0, "Interval must be non-zero");
+    self.interval = interval;
+    self
+  }
+
+  /// Occur on the given day of the week.
+  ///
+  /// This can be called more than once to occur on several days of the week; a recurrence with
+  /// no weekdays or days of the month occurs on the same day of the week, month, or year as the
+  /// start date.
+  pub fn with_weekday(mut self, weekday: Weekday) -> Self {
+    self.by_day.push(ByDay { nth: None, weekday });
+    self
+  }
+
+  /// Occur on the `nth` occurrence of the given day of the week within each month (for monthly
+  /// recurrences) or year (for yearly recurrences), counting from the end of the period if `nth`
+  /// is negative; `-1` is the last occurrence.
+  ///
+  /// Daily and weekly recurrences ignore `nth`, and occur on every such weekday.
+  ///
+  /// ## Panic
+  ///
+  /// This function panics if `nth` is zero or its absolute value is greater than 53.
+  pub fn with_nth_weekday(mut self, nth: i8, weekday: Weekday) -> Self {
+    assert!(nth != 0 && nth.unsigned_abs() <= 53, "nth out-of-bounds");
+    self.by_day.push(ByDay { nth: Some(nth), weekday });
+    self
+  }
+
+  /// Occur on the given day of the month, counting from the end of the month if `day` is
+  /// negative; `-1` is the last day of the month.
+  ///
+  /// Months without the given day are skipped. When combined with weekdays, the recurrence occurs
+  /// only on dates that match both.
+  ///
+  /// ## Panic
+  ///
+  /// This function panics if `day` is zero or its absolute value is greater than 31.
+  pub fn with_month_day(mut self, day: i8) -> Self {
+    assert!(day != 0 && day.unsigned_abs() <= 31, "Day out-of-bounds");
+    self.by_month_day.push(day);
+    self
+  }
+
+  /// Occur only in the given month.
+  ///
+  /// This can be called more than once to occur in several months. Yearly recurrences occur in
+  /// each of the given months, rather than only in the month of the start date, and count the
+  /// `nth` weekday (see [`Recurrence::with_nth_weekday`]) within the month rather than the year.
+  ///
+  /// ## Panic
+  ///
+  /// This function panics if the month is not between 1 and 12.
+  pub fn with_month(mut self, month: u8) -> Self {
+    assert!((1..=12).contains(&month), "Month out-of-bounds");
+    self.by_month.push(month);
+    self
+  }
+
+  /// Occur only on the `nth` of the dates that the rest of the rule matches within each period,
+  /// counting from the end of the period if `nth` is negative.
+  ///
+  /// For example, a monthly recurrence on weekdays with a set position of `-1` occurs on the last
+  /// weekday of each month.
+  ///
+  /// ## Panic
+  ///
+  /// This function panics if `nth` is zero or its absolute value is greater than 366.
+  pub fn with_set_position(mut self, nth: i16) -> Self {
+    assert!(nth != 0 && nth.unsigned_abs() <= 366, "nth out-of-bounds");
+    self.by_set_pos.push(nth);
+    self
+  }
+
+  /// The day on which weeks begin, which determines the weeks that weekly recurrences with an
+  /// interval greater than 1 occur in. The default is Monday.
+  pub fn with_week_start(mut self, week_start: Weekday) -> Self {
+    self.week_start = week_start;
+    self
+  }
+
+  /// Stop after the given number of occurrences.
+  pub fn with_count(mut self, count: u32) -> Self {
+    self.count = Some(count);
+    self
+  }
+
+  /// Stop after the given date (inclusive).
+  pub fn with_until(mut self, until: Date) -> Self {
+    self.until = Some(until);
+    self
+  }
+
+  /// An iterator over the dates on which this recurrence occurs.
+  pub fn iter(&self) -> Occurrences<'_> {
+    Occurrences { rule: self, period: 0, buffer: Vec::new(), emitted: 0, empty: 0, done: false }
+  }
+
+  /// The first day of the given period (counting from the period containing the start date), and
+  /// the candidate dates in that period in chronological order. Returns `None` if the period is
+  /// beyond the representable range of dates.
+  fn candidates(&self, period: u32) -> Option<(Date, Vec<Date>)> {
+    let step = period as i64 * self.interval as i64;
+    let (start_year, start_month, start_day) = self.start.ymd();
+    let days = match self.frequency {
+      Frequency::Daily => {
+        let date = self.start.0 as i64 + step;
+        (date <= Date::MAX.0 as i64).then(|| vec![Date(date as i32)])?
+      },
+      Frequency::Weekly => {
+        let offset = (self.start.weekday() as i64 - self.week_start as i64).rem_euclid(7);
+        let week = self.start.0 as i64 - offset + 7 * step;
+        let end = (week + 7).min(Date::MAX.0 as i64 + 1);
+        (week <= Date::MAX.0 as i64).then(|| (week..end).map(|d| Date(d as i32)).collect())?
+      },
+      Frequency::Monthly => {
+        let month = start_year as i64 * 12 + start_month as i64 - 1 + step;
+        let (year, month) = (month.div_euclid(12), month.rem_euclid(12) as u8 + 1);
+        let year = i16::try_from(year).ok()?;
+        let first = Date::new(year, month, 1);
+        (0..utils::days_in_month(year, month) as i32).map(|d| Date(first.0 + d)).collect()
+      },
+      Frequency::Yearly => {
+        let year = i16::try_from(start_year as i64 + step).ok()?;
+        let first = Date::new(year, 1, 1);
+        (0..utils::days_in_year(year) as i32).map(|d| Date(first.0 + d)).collect()
+      },
+    };
+
+    // Filter the days in the period down to the ones that match the rule, then select the
+    // requested positions within the period, if any.
+    let first = *days.first()?;
+    let mut answer: Vec<Date> =
+      days.into_iter().filter(|d| self.matches(*d, start_month, start_day)).collect();
+    if !self.by_set_pos.is_empty() {
+      let len = answer.len() as i32;
+      answer = (answer.iter().enumerate())
+        .filter(|(i, _)| {
+          self.by_set_pos.iter().any(|pos| match *pos > 0 {
+            true => *pos as i32 - 1 == *i as i32,
+            false => len + *pos as i32 == *i as i32,
+          })
+        })
+        .map(|(_, d)| *d)
+        .collect();
+    }
+    answer.retain(|d| *d >= self.start);
+    Some((first, answer))
+  }
+
+  /// Whether the given date matches the rule, disregarding its position within the period.
+  fn matches(&self, date: Date, start_month: u8, start_day: u8) -> bool {
+    if !self.by_month.is_empty() && !self.by_month.contains(&date.month()) {
+      return false;
+    }
+    match self.by_day.is_empty() && self.by_month_day.is_empty() {
+      true => match self.frequency {
+        Frequency::Daily => true,
+        Frequency::Weekly => date.weekday() == self.start.weekday(),
+        Frequency::Monthly => date.day() == start_day,
+        Frequency::Yearly =>
+          (!self.by_month.is_empty() || date.month() == start_month) && date.day() == start_day,
+      },
+      false =>
+        (self.by_day.is_empty() || self.by_day.iter().any(|b| self.matches_by_day(date, b)))
+          && (self.by_month_day.is_empty()
+            || self.by_month_day.iter().any(|m| matches_month_day(date, *m))),
+    }
+  }
+
+  /// Whether the given date matches the given weekday (and ordinal, if any) within its period.
+  fn matches_by_day(&self, date: Date, by_day: &ByDay) -> bool {
+    if date.weekday() != by_day.weekday {
+      return false;
+    }
+    let Some(nth) = by_day.nth else { return true };
+    match self.frequency {
+      Frequency::Daily | Frequency::Weekly => true,
+      Frequency::Monthly =>
+        nth.unsigned_abs() <= 5
+          && NthWeekdayRule::new(nth, by_day.weekday, date.month()).matches(date),
+      Frequency::Yearly if !self.by_month.is_empty() =>
+        nth.unsigned_abs() <= 5
+          && NthWeekdayRule::new(nth, by_day.weekday, date.month()).matches(date),
+      Frequency::Yearly => {
+        let index = date.day_of_year() as i32;
+        match nth > 0 {
+          true => (index - 1) / 7 + 1 == nth as i32,
+          false => (utils::days_in_year(date.year()) as i32 - index) / 7 + 1 == -nth as i32,
+        }
+      },
+    }
+  }
+}
+
+/// Whether the given date is the given day of its month, counting from the end of the month if
+/// the day is negative.
+fn matches_month_day(date: Date, day: i8) -> bool {
+  match day > 0 {
+    true => date.day() as i8 == day,
+    false => (utils::days_in_month(date.year(), date.month()) - date.day()) as i8 + 1 == -day,
+  }
+}
+
+/// An iterator over the occurrences of a [`Recurrence`].
+pub struct Occurrences<'a> {
+  rule: &'a Recurrence,
+  period: u32,
+  buffer: Vec<Date>,
+  emitted: u32,
+  empty: u32,
+  done: bool,
+}
+
+impl<'a> Iterator for Occurrences<'a> {
+  type Item = Date;
+
+  fn next(&mut self) -> Option<Self::Item> {
+    if self.done || self.rule.count.is_some_and(|c| self.emitted >= c) {
+      return None;
+    }
+    while self.buffer.is_empty() {
+      // A period that begins after `until` cannot contain an occurrence, and since the calendar
+      // repeats, a rule with no occurrences in a full 400-year cycle of periods never occurs.
+      let next = self.rule.candidates(self.period).filter(|(first, _)| {
+        !self.rule.until.is_some_and(|until| *first > until)
+          && self.empty < self.rule.frequency.periods_per_cycle()
+      });
+      let Some((_, mut candidates)) = next else {
+        self.done = true;
+        return None;
+      };
+      self.empty = match candidates.is_empty() {
+        true => self.empty + 1,
+        false => 0,
+      };
+      candidates.reverse();
+      self.buffer = candidates;
+      self.period += 1;
+    }
+    let answer = self.buffer.pop()?;
+    if self.rule.until.is_some_and(|until| answer > until) {
+      self.done = true;
+      return None;
+    }
+    self.emitted += 1;
+    Some(answer)
+  }
+}
+
+impl<'a> IntoIterator for &'a Recurrence {
+  type IntoIter = Occurrences<'a>;
+  type Item = Date;
+
+  fn into_iter(self) -> Self::IntoIter {
+    self.iter()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use assert2::check;
+
+  use super::*;
+  use crate::interval::DateInterval;
+
+  #[test]
+  fn test_daily() {
+    let mut rule = Recurrence::new(date! { 2024-02-27 }, Frequency::Daily);
+    rule.interval = 2;
+    check!(
+      rule.iter().take(3).collect::<Vec<_>>()
+        == vec![date! { 2024-02-27 }, date! { 2024-02-29 }, date! { 2024-03-02 }]
+    );
+  }
+
+  #[test]
+  fn test_weekly() {
+    let mut rule = Recurrence::new(date! { 2024-04-03 }, Frequency::Weekly);
+    check!(rule.iter().nth(1) == Some(date! { 2024-04-10 }));
+    let by_day = |weekday| ByDay { nth: None, weekday };
+    rule.by_day = vec![by_day(Weekday::Monday), by_day(Weekday::Friday)];
+    rule.count = Some(4);
+    check!(
+      rule.iter().collect::<Vec<_>>()
+        == vec![date! { 2024-04-05 }, date! { 2024-04-08 }, date! { 2024-04-12 }, date! {
+          2024-04-15
+        }]
+    );
+  }
+
+  #[test]
+  fn test_monthly() {
+    let mut rule = Recurrence::new(date! { 2024-01-01 }, Frequency::Monthly);
+    rule.by_day = vec![ByDay { nth: Some(-1), weekday: Weekday::Friday }];
+    rule.until = Some(date! { 2024-03-29 });
+    check!(
+      rule.iter().collect::<Vec<_>>()
+        == vec![date! { 2024-01-26 }, date! { 2024-02-23 }, date! { 2024-03-29 }]
+    );
+  }
+
+  #[test]
+  fn test_builder() {
+    let rule = Recurrence::new(date! { 2024-01-01 }, Frequency::Monthly)
+      .with_nth_weekday(-1, Weekday::Friday)
+      .with_until(date! { 2024-03-29 });
+    check!(rule.until() == Some(date! { 2024-03-29 }));
+    check!(
+      rule.iter().collect::<Vec<_>>()
+        == vec![date! { 2024-01-26 }, date! { 2024-02-23 }, date! { 2024-03-29 }]
+    );
+
+    let rule = Recurrence::new(date! { 2024-04-01 }, Frequency::Weekly)
+      .with_interval(2)
+      .with_weekday(Weekday::Monday)
+      .with_weekday(Weekday::Thursday)
+      .with_count(3);
+    check!((rule.interval(), rule.count()) == (2, Some(3)));
+    check!(
+      rule.iter().collect::<Vec<_>>()
+        == vec![date! { 2024-04-01 }, date! { 2024-04-04 }, date! { 2024-04-15 }]
+    );
+
+    // Sundays with a week start of Sunday fall in different weeks than with Monday.
+    let rule = Recurrence::new(date! { 2024-04-01 }, Frequency::Weekly)
+      .with_interval(2)
+      .with_weekday(Weekday::Sunday)
+      .with_week_start(Weekday::Sunday);
+    check!(rule.iter().next() == Some(date! { 2024-04-14 }));
+  }
+
+  #[test]
+  fn test_month_day() {
+    let rule = Recurrence::new(date! { 2024-01-01 }, Frequency::Monthly).with_month_day(31);
+    check!(
+      rule.iter().take(3).collect::<Vec<_>>()
+        == vec![date! { 2024-01-31 }, date! { 2024-03-31 }, date! { 2024-05-31 }]
+    );
+    let rule = Recurrence::new(date! { 2024-01-15 }, Frequency::Monthly)
+      .with_month_day(1)
+      .with_month_day(-1);
+    check!(
+      rule.iter().take(3).collect::<Vec<_>>()
+        == vec![date! { 2024-01-31 }, date! { 2024-02-01 }, date! { 2024-02-29 }]
+    );
+
+    // Friday the 13th.
+    let rule = Recurrence::new(date! { 2024-01-01 }, Frequency::Monthly)
+      .with_weekday(Weekday::Friday)
+      .with_month_day(13);
+    check!(
+      rule.iter().take(3).collect::<Vec<_>>()
+        == vec![date! { 2024-09-13 }, date! { 2024-12-13 }, date! { 2025-06-13 }]
+    );
+
+    // Yearly recurrences with a day of the month occur in every month.
+    let rule = Recurrence::new(date! { 2024-06-01 }, Frequency::Yearly).with_month_day(-2);
+    check!(rule.iter().next() == Some(date! { 2024-06-29 }));
+    check!(rule.iter().nth(12) == Some(date! { 2025-06-29 }));
+  }
+
+  #[test]
+  fn test_month() {
+    // Thanksgiving in the United States.
+    let rule = Recurrence::new(date! { 2024-01-01 }, Frequency::Yearly)
+      .with_month(11)
+      .with_nth_weekday(4, Weekday::Thursday);
+    check!(
+      rule.iter().take(2).collect::<Vec<_>>() == vec![date! { 2024-11-28 }, date! { 2025-11-27 }]
+    );
+
+    // Yearly recurrences without a day occur on the start date's day of each month.
+    let rule =
+      Recurrence::new(date! { 2024-01-15 }, Frequency::Yearly).with_month(3).with_month(9);
+    check!(
+      rule.iter().take(3).collect::<Vec<_>>()
+        == vec![date! { 2024-03-15 }, date! { 2024-09-15 }, date! { 2025-03-15 }]
+    );
+
+    // Other frequencies are limited to the given months.
+    let rule = Recurrence::new(date! { 2024-01-15 }, Frequency::Monthly).with_month(4);
+    check!(
+      rule.iter().take(2).collect::<Vec<_>>() == vec![date! { 2024-04-15 }, date! { 2025-04-15 }]
+    );
+    let rule = Recurrence::new(date! { 2024-01-01 }, Frequency::Daily).with_month(2);
+    check!(rule.iter().next() == Some(date! { 2024-02-01 }));
+  }
+
+  #[test]
+  fn test_set_position() {
+    // The last weekday of each month.
+    let mut rule = Recurrence::new(date! { 2024-01-01 }, Frequency::Monthly).with_set_position(-1);
+    for weekday in Weekday::all(Weekday::Monday).take(5) {
+      rule = rule.with_weekday(weekday);
+    }
+    check!(
+      rule.iter().take(3).collect::<Vec<_>>()
+        == vec![date! { 2024-01-31 }, date! { 2024-02-29 }, date! { 2024-03-29 }]
+    );
+
+    // Positions are counted before dates earlier than the start date are removed.
+    let rule = Recurrence::new(date! { 2024-01-15 }, Frequency::Monthly)
+      .with_weekday(Weekday::Monday)
+      .with_set_position(1)
+      .with_set_position(2);
+    check!(
+      rule.iter().take(2).collect::<Vec<_>>() == vec![date! { 2024-02-05 }, date! { 2024-02-12 }]
+    );
+  }
+
+  #[test]
+  #[should_panic]
+  fn test_month_day_zero() {
+    Recurrence::new(date! { 2024-01-01 }, Frequency::Monthly).with_month_day(0);
+  }
+
+  #[test]
+  fn test_yearly() {
+    let rule = Recurrence::new(date! { 2024-02-29 }, Frequency::Yearly);
+    check!(
+      rule.iter().take(2).collect::<Vec<_>>() == vec![date! { 2024-02-29 }, date! { 2028-02-29 }]
+    );
+    let mut rule = Recurrence::new(date! { 2024-01-01 }, Frequency::Yearly);
+    rule.by_day = vec![ByDay { nth: Some(20), weekday: Weekday::Monday }];
+    check!(rule.iter().next() == Some(date! { 2024-05-13 }));
+  }
+
+  #[test]
+  fn test_end_of_range() {
+    let rule = Recurrence::new(Date::MAX - DateInterval::new(1), Frequency::Daily);
+    check!(rule.iter().count() == 2);
+    let rule = Recurrence::new(date! { 32767-01-15 }, Frequency::Monthly);
+    check!(rule.iter().count() == 12);
+  }
+
+  #[test]
+  fn test_no_occurrences() {
+    // February 30 never occurs; iteration stops at `until`, or after a 400-year cycle of periods.
+    let rule =
+      Recurrence::new(date! { 2024-01-01 }, Frequency::Daily).with_month(2).with_month_day(30);
+    check!(rule.clone().with_until(date! { 2024-12-31 }).iter().next() == None);
+    check!(rule.iter().next() == None);
+    let rule = Recurrence::new(date! { 2001-01-01 }, Frequency::Yearly)
+      .with_interval(4)
+      .with_month(2)
+      .with_month_day(29);
+    check!(rule.iter().next() == None);
+
+    // Empty periods before a match do not end the iteration early.
+    let rule =
+      Recurrence::new(date! { 2097-03-01 }, Frequency::Yearly).with_month(2).with_month_day(29);
+    check!(rule.iter().next() == Some(date! { 2104-02-29 }));
+    let rule = Recurrence::new(date! { 2000-01-01 }, Frequency::Yearly)
+      .with_interval(100)
+      .with_month(2)
+      .with_month_day(29);
+    check!(
+      rule.iter().take(3).collect::<Vec<_>>()
+        == vec![date! { 2000-02-29 }, date! { 2400-02-29 }, date! { 2800-02-29 }]
+    );
+  }
+}
@@ -0,0 +1,304 @@
+//! Parsing of RFC 5545 `RRULE` strings.
+
+use alloc::format;
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::RangeInclusive;
+use core::str::FromStr;
+
+use super::ByDay;
+use super::Frequency;
+use super::Recurrence;
+use crate::utils;
+use crate::Date;
+use crate::Weekday;
+
+impl Recurrence {
+  /// Parse an iCalendar recurrence rule (as defined in [RFC 5545][rfc]), beginning on the given
+  /// start date (the `DTSTART` of the event).
+  ///
+  /// The leading `RRULE:` is optional. The `FREQ`, `INTERVAL`, `COUNT`, `UNTIL`, `BYDAY`,
+  /// `BYMONTHDAY`, `BYMONTH`, `BYSETPOS`, and `WKST` parts are supported. Since this crate deals
+  /// only in dates, frequencies finer than `DAILY` are rejected, and any time component of `UNTIL`
+  /// is ignored.
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::date;
+  /// use date::recurrence::Recurrence;
+  ///
+  /// let rule = Recurrence::from_rrule("FREQ=MONTHLY;BYDAY=2TU;COUNT=12", date! { 2024-01-01 })?;
+  /// let dates: Vec<_> = rule.iter().collect();
+  /// assert_eq!(dates.len(), 12);
+  /// assert_eq!(dates[0], date! { 2024-01-09 });
+  /// assert_eq!(dates[11], date! { 2024-12-10 });
+  /// # Ok::<(), date::recurrence::RRuleError>(())
+  /// ```
+  ///
+  /// The last weekday of each month:
+  ///
+  /// ```
+  /// use date::date;
+  /// use date::recurrence::Recurrence;
+  ///
+  /// let rule = "FREQ=MONTHLY;BYDAY=MO,TU,WE,TH,FR;BYSETPOS=-1";
+  /// let rule = Recurrence::from_rrule(rule, date! { 2024-01-01 })?;
+  /// let mut dates = rule.iter();
+  /// assert_eq!(dates.next(), Some(date! { 2024-01-31 }));
+  /// assert_eq!(dates.next(), Some(date! { 2024-02-29 }));
+  /// assert_eq!(dates.next(), Some(date! { 2024-03-29 }));
+  /// # Ok::<(), date::recurrence::RRuleError>(())
+  /// ```
+  ///
+  /// [rfc]: https://datatracker.ietf.org/doc/html/rfc5545#section-3.3.10
+  pub fn from_rrule(rule: &str, start: Date) -> Result<Self, RRuleError> {
+    let rule = rule.strip_prefix("RRULE:").unwrap_or(rule);
+    let mut frequency = None;
+    let mut answer = Self::new(start, Frequency::Daily);
+    for part in rule.split(';').filter(|p| !p.is_empty()) {
+      let invalid = || RRuleError::InvalidPart(part.to_string());
+      let (key, value) = part.split_once('=').ok_or_else(invalid)?;
+      match key {
+        "FREQ" =>
+          frequency = Some(match value {
+            "DAILY" => Frequency::Daily,
+            "WEEKLY" => Frequency::Weekly,
+            "MONTHLY" => Frequency::Monthly,
+            "YEARLY" => Frequency::Yearly,
+            "SECONDLY" | "MINUTELY" | "HOURLY" =>
+              return Err(RRuleError::UnsupportedPart(part.to_string())),
+            _ => return Err(invalid()),
+          }),
+        "INTERVAL" =>
+          answer.interval = value.parse().ok().filter(|i| *i > 0).ok_or_else(invalid)?,
+        "COUNT" => answer.count = Some(value.parse().map_err(|_| invalid())?),
+        "UNTIL" => answer.until = Some(parse_date(value).ok_or_else(invalid)?),
+        "BYDAY" =>
+          answer.by_day =
+            value.split(',').map(parse_by_day).collect::<Option<_>>().ok_or_else(invalid)?,
+        "BYMONTHDAY" => {
+          answer.by_month_day = parse_list(value, -31..=31).ok_or_else(invalid)?;
+        },
+        "BYMONTH" => answer.by_month = parse_list(value, 1..=12).ok_or_else(invalid)?,
+        "BYSETPOS" => answer.by_set_pos = parse_list(value, -366..=366).ok_or_else(invalid)?,
+        "WKST" => answer.week_start = parse_weekday(value).ok_or_else(invalid)?,
+        _ => return Err(RRuleError::UnsupportedPart(part.to_string())),
+      }
+    }
+    answer.frequency = frequency.ok_or(RRuleError::MissingFrequency)?;
+
+    // Validate combinations of parts that RFC 5545 prohibits.
+    if answer.count.is_some() && answer.until.is_some() {
+      return Err(RRuleError::CountAndUntil);
+    }
+    if matches!(answer.frequency, Frequency::Daily | Frequency::Weekly)
+      && answer.by_day.iter().any(|d| d.nth.is_some())
+    {
+      return Err(RRuleError::InvalidPart(format!("BYDAY={}", rule_part(rule, "BYDAY"))));
+    }
+    if answer.frequency == Frequency::Weekly && !answer.by_month_day.is_empty() {
+      let part = rule_part(rule, "BYMONTHDAY");
+      return Err(RRuleError::InvalidPart(format!("BYMONTHDAY={}", part)));
+    }
+    if !answer.by_set_pos.is_empty()
+      && answer.by_day.is_empty()
+      && answer.by_month_day.is_empty()
+      && answer.by_month.is_empty()
+    {
+      let part = rule_part(rule, "BYSETPOS");
+      return Err(RRuleError::InvalidPart(format!("BYSETPOS={}", part)));
+    }
+    Ok(answer)
+  }
+}
+
+/// An error encountered while parsing an `RRULE` string.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RRuleError {
+  /// The rule has no `FREQ` part.
+  MissingFrequency,
+  /// The rule has both a `COUNT` and an `UNTIL` part.
+  CountAndUntil,
+  /// The given part of the rule could not be parsed.
+  InvalidPart(String),
+  /// The given part of the rule is not supported.
+  UnsupportedPart(String),
+}
+
+impl fmt::Display for RRuleError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::MissingFrequency => f.write_str("RRULE is missing FREQ"),
+      Self::CountAndUntil => f.write_str("RRULE may not contain both COUNT and UNTIL"),
+      Self::InvalidPart(part) => write!(f, "Invalid RRULE part: {}", part),
+      Self::UnsupportedPart(part) => write!(f, "Unsupported RRULE part: {}", part),
+    }
+  }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RRuleError {}
+
+/// The value of the given part of the rule.
+fn rule_part<'a>(rule: &'a str, key: &str) -> &'a str {
+  rule.split(';').find_map(|p| p.strip_prefix(key)?.strip_prefix('=')).unwrap_or_default()
+}
+
+/// Parse a `YYYYMMDD` date, ignoring any trailing time component.
+fn parse_date(value: &str) -> Option<Date> {
+  let date = value.get(..8).filter(|d| d.bytes().all(|b| b.is_ascii_digit()))?;
+  if value.len() > 8 && !value[8..].starts_with('T') {
+    return None;
+  }
+  let year: i16 = date[..4].parse().ok()?;
+  let month: u8 = date[4..6].parse().ok().filter(|m| (1..=12).contains(m))?;
+  let day: u8 = date[6..].parse().ok()?;
+  if day == 0 || day > utils::days_in_month(year, month) {
+    return None;
+  }
+  Some(Date::new(year, month, day))
+}
+
+/// Parse a `BYDAY` entry, such as `TU`, `2TU`, or `-1FR`.
+fn parse_by_day(value: &str) -> Option<ByDay> {
+  let split = value.len().checked_sub(2)?;
+  let weekday = parse_weekday(value.get(split..)?)?;
+  let nth = match &value[..split] {
+    "" => None,
+    n => Some(n.parse::<i8>().ok().filter(|n| *n != 0 && (-53..=53).contains(n))?),
+  };
+  Some(ByDay { nth, weekday })
+}
+
+/// Parse a comma-separated list of non-zero integers within the given range.
+fn parse_list<T>(value: &str, range: RangeInclusive<T>) -> Option<Vec<T>>
+where
+  T: FromStr + PartialOrd + Default, {
+  (value.split(','))
+    .map(|v| v.parse().ok().filter(|v| range.contains(v) && *v != T::default()))
+    .collect()
+}
+
+/// Parse a two-letter weekday abbreviation.
+fn parse_weekday(value: &str) -> Option<Weekday> {
+  Some(match value {
+    "SU" => Weekday::Sunday,
+    "MO" => Weekday::Monday,
+    "TU" => Weekday::Tuesday,
+    "WE" => Weekday::Wednesday,
+    "TH" => Weekday::Thursday,
+    "FR" => Weekday::Friday,
+    "SA" => Weekday::Saturday,
+    _ => return None,
+  })
+}
+
+#[cfg(test)]
+mod tests {
+  use assert2::check;
+
+  use super::*;
+
+  #[test]
+  fn test_parse() -> Result<(), RRuleError> {
+    let rule = Recurrence::from_rrule("RRULE:FREQ=WEEKLY;INTERVAL=2;BYDAY=TU,TH", date! {
+      2024-04-02
+    })?;
+    check!(rule.frequency() == Frequency::Weekly);
+    check!(rule.interval == 2);
+    check!(
+      rule.iter().take(4).collect::<Vec<_>>()
+        == vec![date! { 2024-04-02 }, date! { 2024-04-04 }, date! { 2024-04-16 }, date! {
+          2024-04-18
+        }]
+    );
+
+    let rule = Recurrence::from_rrule("FREQ=YEARLY;UNTIL=20260101T000000Z", date! { 2020-07-04 })?;
+    check!(rule.until == Some(date! { 2026-01-01 }));
+    check!(rule.iter().count() == 6);
+
+    let rule = Recurrence::from_rrule("FREQ=MONTHLY;BYDAY=-1FR;WKST=SU", date! { 2024-01-01 })?;
+    check!(rule.week_start == Weekday::Sunday);
+    check!(rule.iter().next() == Some(date! { 2024-01-26 }));
+    Ok(())
+  }
+
+  #[test]
+  fn test_parse_by_parts() -> Result<(), RRuleError> {
+    let start = date! { 2024-01-01 };
+    let rule = Recurrence::from_rrule("FREQ=MONTHLY;BYMONTHDAY=1,-1;COUNT=4", start)?;
+    check!(rule.by_month_day == vec![1, -1]);
+    check!(
+      rule.iter().collect::<Vec<_>>()
+        == vec![date! { 2024-01-01 }, date! { 2024-01-31 }, date! { 2024-02-01 }, date! {
+          2024-02-29
+        }]
+    );
+
+    // Thanksgiving in the United States.
+    let rule = Recurrence::from_rrule("FREQ=YEARLY;BYMONTH=11;BYDAY=4TH", start)?;
+    check!(
+      rule.iter().take(2).collect::<Vec<_>>() == vec![date! { 2024-11-28 }, date! { 2025-11-27 }]
+    );
+
+    // Election day in the United States: the first Tuesday after the first Monday of November.
+    let rule = "FREQ=YEARLY;INTERVAL=4;BYMONTH=11;BYDAY=TU;BYMONTHDAY=2,3,4,5,6,7,8";
+    let rule = Recurrence::from_rrule(rule, start)?;
+    check!(
+      rule.iter().take(2).collect::<Vec<_>>() == vec![date! { 2024-11-05 }, date! { 2028-11-07 }]
+    );
+
+    // The second-to-last weekday of each month.
+    let rule = "FREQ=MONTHLY;BYDAY=MO,TU,WE,TH,FR;BYSETPOS=-2;UNTIL=20240331";
+    check!(
+      Recurrence::from_rrule(rule, start)?.iter().collect::<Vec<_>>()
+        == vec![date! { 2024-01-30 }, date! { 2024-02-28 }, date! { 2024-03-28 }]
+    );
+    Ok(())
+  }
+
+  #[test]
+  fn test_parse_errors() {
+    let start = date! { 2024-01-01 };
+    check!(Recurrence::from_rrule("COUNT=3", start) == Err(RRuleError::MissingFrequency));
+    check!(
+      Recurrence::from_rrule("FREQ=DAILY;COUNT=3;UNTIL=20240201", start)
+        == Err(RRuleError::CountAndUntil)
+    );
+    check!(
+      Recurrence::from_rrule("FREQ=HOURLY", start)
+        == Err(RRuleError::UnsupportedPart("FREQ=HOURLY".into()))
+    );
+    check!(
+      Recurrence::from_rrule("FREQ=DAILY;BYHOUR=9", start)
+        == Err(RRuleError::UnsupportedPart("BYHOUR=9".into()))
+    );
+    check!(
+      Recurrence::from_rrule("FREQ=WEEKLY;BYDAY=2TU", start)
+        == Err(RRuleError::InvalidPart("BYDAY=2TU".into()))
+    );
+    for invalid in [
+      "FREQ=FORTNIGHTLY",
+      "FREQ=DAILY;INTERVAL=0",
+      "FREQ=DAILY;COUNT=x",
+      "FREQ=DAILY;UNTIL=20240230",
+      "FREQ=DAILY;UNTIL=2024-01-01",
+      "FREQ=MONTHLY;BYDAY=0MO",
+      "FREQ=MONTHLY;BYDAY=XX",
+      "FREQ=MONTHLY;BYMONTHDAY=0",
+      "FREQ=MONTHLY;BYMONTHDAY=32",
+      "FREQ=YEARLY;BYMONTH=13",
+      "FREQ=YEARLY;BYMONTH=",
+      "FREQ=MONTHLY;BYDAY=FR;BYSETPOS=367",
+      "FREQ=WEEKLY;BYMONTHDAY=1",
+      "FREQ=MONTHLY;BYSETPOS=1",
+      "FREQ",
+    ] {
+      check!(matches!(Recurrence::from_rrule(invalid, start), Err(RRuleError::InvalidPart(_))));
+    }
+    check!(RRuleError::MissingFrequency.to_string() == "RRULE is missing FREQ");
+  }
+}
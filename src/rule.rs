@@ -0,0 +1,236 @@
+use crate::utils;
+use crate::Date;
+use crate::Weekday;
+
+/// A rule identifying the `n`th occurrence of a weekday in a month, such as "the third Thursday
+/// of November" or "the last Monday of May".
+///
+/// Negative values of `n` count backwards from the end of the month: `-1` is the last occurrence,
+/// `-2` the second-to-last, and so on.
+///
+/// ## Examples
+///
+/// ```
+/// use date::date;
+/// use date::NthWeekdayRule;
+/// use date::Weekday;
+///
+/// let thanksgiving = NthWeekdayRule::new(4, Weekday::Thursday, 11);
+/// assert_eq!(thanksgiving.occurrence_in(2024), Some(date! { 2024-11-28 }));
+///
+/// let memorial_day = NthWeekdayRule::last(Weekday::Monday, 5);
+/// assert_eq!(memorial_day.next_after(date! { 2024-06-01 }), Some(date! { 2025-05-26 }));
+/// ```
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub struct NthWeekdayRule {
+  nth: i8,
+  weekday: Weekday,
+  month: u8,
+}
+
+impl NthWeekdayRule {
+  /// A rule for the `n`th occurrence of the given weekday in the given month.
+  ///
+  /// ## Panic
+  ///
+  /// This function panics if `nth` is zero or its magnitude exceeds 5, or if `month` is not
+  /// between 1 and 12.
+  pub const fn new(nth: i8, weekday: Weekday, month: u8) -> Self {
+    assert!(nth != 0 && nth >= -5 && nth <= 5, "Occurrence out-of-bounds");
+    assert!(month >= 1 && month <= 12, "Month out-of-bounds");
+    Self { nth, weekday, month }
+  }
+
+  /// A rule for the `n`th occurrence of the given weekday in the given month, or `None` if `nth`
+  /// or `month` is out of bounds.
+  ///
+  /// This is the non-panicking counterpart to [`NthWeekdayRule::new`].
+  pub const fn checked_new(nth: i8, weekday: Weekday, month: u8) -> Option<Self> {
+    match nth != 0 && nth >= -5 && nth <= 5 && month >= 1 && month <= 12 {
+      true => Some(Self { nth, weekday, month }),
+      false => None,
+    }
+  }
+
+  /// A rule for the last occurrence of the given weekday in the given month.
+  pub const fn last(weekday: Weekday, month: u8) -> Self {
+    Self::new(-1, weekday, month)
+  }
+
+  /// Which occurrence of the weekday this rule identifies (negative values count from the end of
+  /// the month).
+  pub const fn nth(&self) -> i8 {
+    self.nth
+  }
+
+  /// The weekday this rule identifies.
+  pub const fn weekday(&self) -> Weekday {
+    self.weekday
+  }
+
+  /// The month this rule applies to.
+  pub const fn month(&self) -> u8 {
+    self.month
+  }
+
+  /// The date on which this rule falls in the given year.
+  ///
+  /// This returns `None` if the month has fewer than `n` occurrences of the weekday (which is
+  /// only possible for the fifth occurrence).
+  pub const fn occurrence_in(&self, year: i16) -> Option<Date> {
+    let days = utils::days_in_month(year, self.month) as i32;
+    let weeks = self.nth.unsigned_abs() as i32 - 1;
+    let day = match self.nth > 0 {
+      true => {
+        let first = Date::new(year, self.month, 1).weekday();
+        1 + first.days_until(self.weekday) as i32 + 7 * weeks
+      },
+      false => {
+        let last = Date::new(year, self.month, days as u8).weekday();
+        days - self.weekday.days_until(last) as i32 - 7 * weeks
+      },
+    };
+    match day >= 1 && day <= days {
+      true => Some(Date::new(year, self.month, day as u8)),
+      false => None,
+    }
+  }
+
+  /// Whether the given date satisfies this rule.
+  pub const fn matches(&self, date: Date) -> bool {
+    match self.occurrence_in(date.year()) {
+      Some(d) => d.0 == date.0,
+      None => false,
+    }
+  }
+
+  /// The first date after the given date on which this rule falls.
+  ///
+  /// This returns `None` only if there is no such date before [`Date::MAX`].
+  pub fn next_after(&self, date: Date) -> Option<Date> {
+    (date.year()..=i16::MAX).filter_map(|y| self.occurrence_in(y)).find(|d| *d > date)
+  }
+}
+
+impl Date {
+  /// The `n`th occurrence of the given weekday in the given month (for example, the third
+  /// Thursday), or `None` if the month has fewer than `n` occurrences or if `n` or `month` is out
+  /// of bounds.
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::date;
+  /// use date::Date;
+  /// use date::Weekday;
+  ///
+  /// let date = Date::nth_weekday_of_month(2024, 11, Weekday::Thursday, 4);
+  /// assert_eq!(date, Some(date! { 2024-11-28 }));
+  /// assert_eq!(Date::nth_weekday_of_month(2024, 11, Weekday::Thursday, 5), None);
+  /// ```
+  pub const fn nth_weekday_of_month(
+    year: i16, month: u8, weekday: Weekday, n: u8,
+  ) -> Option<Date> {
+    if n > 5 {
+      return None;
+    }
+    match NthWeekdayRule::checked_new(n as i8, weekday, month) {
+      Some(rule) => rule.occurrence_in(year),
+      None => None,
+    }
+  }
+
+  /// The last occurrence of the given weekday in the given month, or `None` if `month` is out of
+  /// bounds.
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::date;
+  /// use date::Date;
+  /// use date::Weekday;
+  ///
+  /// let date = Date::last_weekday_of_month(2024, 5, Weekday::Monday);
+  /// assert_eq!(date, Some(date! { 2024-05-27 }));
+  /// ```
+  pub const fn last_weekday_of_month(year: i16, month: u8, weekday: Weekday) -> Option<Date> {
+    match NthWeekdayRule::checked_new(-1, weekday, month) {
+      Some(rule) => rule.occurrence_in(year),
+      None => None,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use assert2::check;
+
+  use super::*;
+
+  #[test]
+  fn test_occurrence_in() {
+    let rule = |nth, weekday, month| NthWeekdayRule::new(nth, weekday, month);
+    check!(rule(1, Weekday::Monday, 1).occurrence_in(2024) == Some(date! { 2024-01-01 }));
+    check!(rule(3, Weekday::Monday, 1).occurrence_in(2024) == Some(date! { 2024-01-15 }));
+    check!(rule(4, Weekday::Thursday, 11).occurrence_in(2024) == Some(date! { 2024-11-28 }));
+    check!(rule(5, Weekday::Thursday, 2).occurrence_in(2024) == Some(date! { 2024-02-29 }));
+    check!(rule(5, Weekday::Friday, 2).occurrence_in(2024) == None);
+    check!(rule(-1, Weekday::Monday, 5).occurrence_in(2024) == Some(date! { 2024-05-27 }));
+    check!(rule(-1, Weekday::Sunday, 3).occurrence_in(2024) == Some(date! { 2024-03-31 }));
+    check!(rule(-2, Weekday::Sunday, 3).occurrence_in(2024) == Some(date! { 2024-03-24 }));
+    check!(rule(-5, Weekday::Sunday, 3).occurrence_in(2024) == Some(date! { 2024-03-03 }));
+    check!(rule(-5, Weekday::Monday, 3).occurrence_in(2024) == None);
+  }
+
+  #[test]
+  fn test_matches() {
+    let rule = NthWeekdayRule::last(Weekday::Friday, 1);
+    check!(rule.matches(date! { 2024-01-26 }));
+    check!(!rule.matches(date! { 2024-01-19 }));
+    check!(!rule.matches(date! { 2024-02-23 }));
+  }
+
+  #[test]
+  fn test_next_after() {
+    let rule = NthWeekdayRule::new(4, Weekday::Thursday, 11);
+    check!(rule.next_after(date! { 2024-11-27 }) == Some(date! { 2024-11-28 }));
+    check!(rule.next_after(date! { 2024-11-28 }) == Some(date! { 2025-11-27 }));
+    let rule = NthWeekdayRule::new(5, Weekday::Thursday, 2);
+    check!(rule.next_after(date! { 2024-03-01 }) == Some(date! { 2052-02-29 }));
+    check!(rule.next_after(Date::MAX) == None);
+  }
+
+  #[test]
+  fn test_checked_new() {
+    check!(NthWeekdayRule::checked_new(-5, Weekday::Monday, 12).is_some());
+    check!(NthWeekdayRule::checked_new(0, Weekday::Monday, 1) == None);
+    check!(NthWeekdayRule::checked_new(6, Weekday::Monday, 1) == None);
+    check!(NthWeekdayRule::checked_new(1, Weekday::Monday, 13) == None);
+  }
+
+  #[test]
+  #[should_panic]
+  fn test_new_panic() {
+    NthWeekdayRule::new(0, Weekday::Monday, 1);
+  }
+
+  #[test]
+  fn test_nth_weekday_of_month() {
+    check!(Date::nth_weekday_of_month(2024, 4, Weekday::Monday, 1) == Some(date! { 2024-04-01 }));
+    check!(Date::nth_weekday_of_month(2024, 4, Weekday::Sunday, 3) == Some(date! { 2024-04-21 }));
+    check!(Date::nth_weekday_of_month(2024, 4, Weekday::Tuesday, 5) == Some(date! { 2024-04-30 }));
+    check!(Date::nth_weekday_of_month(2024, 4, Weekday::Friday, 5) == None);
+    check!(Date::nth_weekday_of_month(2024, 4, Weekday::Friday, 0) == None);
+    check!(Date::nth_weekday_of_month(2024, 4, Weekday::Friday, 6) == None);
+    check!(Date::nth_weekday_of_month(2024, 4, Weekday::Friday, 200) == None);
+    check!(Date::nth_weekday_of_month(2024, 13, Weekday::Friday, 1) == None);
+  }
+
+  #[test]
+  fn test_last_weekday_of_month() {
+    check!(Date::last_weekday_of_month(2024, 4, Weekday::Tuesday) == Some(date! { 2024-04-30 }));
+    check!(Date::last_weekday_of_month(2024, 4, Weekday::Friday) == Some(date! { 2024-04-26 }));
+    check!(Date::last_weekday_of_month(2024, 2, Weekday::Thursday) == Some(date! { 2024-02-29 }));
+    check!(Date::last_weekday_of_month(2024, 0, Weekday::Friday) == None);
+  }
+}
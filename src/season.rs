@@ -0,0 +1,156 @@
+use core::fmt;
+
+use crate::utils;
+use crate::Date;
+
+/// A hemisphere of the Earth, which determines which season a date falls in.
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub enum Hemisphere {
+  /// The northern hemisphere, where summer begins in June.
+  #[default]
+  Northern,
+  /// The southern hemisphere, where summer begins in December.
+  Southern,
+}
+
+/// A meteorological season.
+///
+/// Meteorological seasons each span three whole months: in the northern hemisphere, spring is
+/// March through May, summer is June through August, autumn is September through November, and
+/// winter is December through February. The southern hemisphere has the opposite seasons.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Season {
+  Spring,
+  Summer,
+  Autumn,
+  Winter,
+}
+
+impl fmt::Display for Season {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str(match self {
+      Self::Spring => "Spring",
+      Self::Summer => "Summer",
+      Self::Autumn => "Autumn",
+      Self::Winter => "Winter",
+    })
+  }
+}
+
+impl Date {
+  /// The meteorological season that this date falls in, in the given hemisphere.
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::date;
+  /// use date::Hemisphere;
+  /// use date::Season;
+  ///
+  /// assert_eq!(date! { 2024-07-04 }.season(Hemisphere::Northern), Season::Summer);
+  /// assert_eq!(date! { 2024-07-04 }.season(Hemisphere::Southern), Season::Winter);
+  /// assert_eq!(date! { 2024-12-01 }.season(Hemisphere::Northern), Season::Winter);
+  /// ```
+  pub const fn season(&self, hemisphere: Hemisphere) -> Season {
+    let northern = match self.month() {
+      3..=5 => Season::Spring,
+      6..=8 => Season::Summer,
+      9..=11 => Season::Autumn,
+      _ => Season::Winter,
+    };
+    match hemisphere {
+      Hemisphere::Northern => northern,
+      Hemisphere::Southern => match northern {
+        Season::Spring => Season::Autumn,
+        Season::Summer => Season::Winter,
+        Season::Autumn => Season::Spring,
+        Season::Winter => Season::Summer,
+      },
+    }
+  }
+
+  /// The first and last dates of the meteorological season that this date falls in.
+  ///
+  /// Season boundaries are the same in both hemispheres (only the names of the seasons differ).
+  /// Winter in the northern hemisphere (and summer in the southern hemisphere) spans two years,
+  /// from December through February. The bounds are clamped to [`Date::MIN`] and [`Date::MAX`].
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::date;
+  ///
+  /// let (start, end) = date! { 2024-07-04 }.season_bounds();
+  /// assert_eq!((start, end), (date! { 2024-06-01 }, date! { 2024-08-31 }));
+  ///
+  /// let (start, end) = date! { 2024-01-15 }.season_bounds();
+  /// assert_eq!((start, end), (date! { 2023-12-01 }, date! { 2024-02-29 }));
+  /// ```
+  pub const fn season_bounds(&self) -> (Date, Date) {
+    let (year, month, _) = self.ymd();
+    let start = match month {
+      1 | 2 => match year.checked_sub(1) {
+        Some(year) => Date::new(year, 12, 1),
+        None => Date::MIN,
+      },
+      _ => Date::new(year, month / 3 * 3, 1),
+    };
+    let end = match month {
+      12 => match year.checked_add(1) {
+        Some(year) => Date::new(year, 2, utils::days_in_month(year, 2)),
+        None => Date::MAX,
+      },
+      1 | 2 => Date::new(year, 2, utils::days_in_month(year, 2)),
+      _ => {
+        let month = month / 3 * 3 + 2;
+        Date::new(year, month, utils::days_in_month(year, month))
+      },
+    };
+    (start, end)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use assert2::check;
+
+  use super::*;
+
+  #[test]
+  fn test_season() {
+    for (month, northern, southern) in [
+      (1, Season::Winter, Season::Summer),
+      (2, Season::Winter, Season::Summer),
+      (3, Season::Spring, Season::Autumn),
+      (5, Season::Spring, Season::Autumn),
+      (6, Season::Summer, Season::Winter),
+      (8, Season::Summer, Season::Winter),
+      (9, Season::Autumn, Season::Spring),
+      (11, Season::Autumn, Season::Spring),
+      (12, Season::Winter, Season::Summer),
+    ] {
+      let date = Date::new(2024, month, 15);
+      check!(date.season(Hemisphere::Northern) == northern);
+      check!(date.season(Hemisphere::Southern) == southern);
+      check!(date.season(Hemisphere::default()) == northern);
+    }
+    check!(Season::Autumn.to_string() == "Autumn");
+  }
+
+  #[test]
+  fn test_season_bounds() {
+    for date in date! { 2023-11-01 }.iter_through(date! { 2025-03-31 }) {
+      let (start, end) = date.season_bounds();
+      check!(start <= date);
+      check!(date <= end);
+      check!(start.day() == 1);
+      check!(start.month() % 3 == 0);
+      check!(Date(end.0 + 1).day() == 1);
+      check!(start.season(Hemisphere::Northern) == date.season(Hemisphere::Northern));
+      check!(end.season(Hemisphere::Northern) == date.season(Hemisphere::Northern));
+    }
+    check!(date! { 2023-02-01 }.season_bounds().1 == date! { 2023-02-28 });
+    check!(Date::MIN.season_bounds() == (Date::MIN, date! { -32768-02-29 }));
+    check!(Date::MAX.season_bounds() == (date! { 32767-12-01 }, Date::MAX));
+  }
+}
@@ -1,5 +1,6 @@
-use std::fmt;
+use core::fmt;
 
+use serde::de::Unexpected;
 use serde::de::Visitor;
 use serde::Deserialize;
 use serde::Deserializer;
@@ -10,7 +11,7 @@ use crate::Date;
 
 impl Serialize for Date {
   fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-    serializer.collect_str(&self.format("%Y-%m-%d"))
+    serializer.serialize_str(self.to_iso_string().as_str())
   }
 }
 
@@ -25,7 +26,7 @@ impl<'de> Visitor<'de> for DateVisitor {
   }
 
   fn visit_str<E: serde::de::Error>(self, s: &str) -> Result<Self::Value, E> {
-    s.parse().map_err(E::custom)
+    Date::parse_iso8601(s).ok_or_else(|| E::invalid_value(Unexpected::Str(s), &self))
   }
 }
 
@@ -48,6 +49,7 @@ mod tests {
     check!(struct_.date == date! { 2012-04-21 });
     let json = serde_json::to_string(&struct_)?;
     check!(json == r#"{"date":"2012-04-21"}"#);
+    check!(serde_json::from_str::<TestStruct>(r#"{"date":"2012-02-30"}"#).is_err());
     Ok(())
   }
 
@@ -0,0 +1,167 @@
+use core::fmt;
+use core::ops::Add;
+use core::ops::Sub;
+use core::str::FromStr;
+
+use crate::interval::CalendarDuration;
+use crate::Date;
+
+/// A tenor: the length of time until a financial instrument or period ends, such as `3M` (three
+/// months) or `ON` (overnight).
+///
+/// A tenor can be added to or subtracted from a date. Tenors in months and years follow the same
+/// end-of-month rule as [`MonthInterval`](crate::interval::MonthInterval): the day is clamped to
+/// the last day of the resulting month. To roll the result onto a business day according to a
+/// [`RollConvention`](crate::holiday::RollConvention), use
+/// [`BusinessCalendar::add_tenor`](crate::holiday::BusinessCalendar::add_tenor) (which requires
+/// the `alloc` feature).
+///
+/// Tenors are parsed from (and formatted as) a number followed by a unit, which is `D` (days), `W`
+/// (weeks), `M` (months), or `Y` (years), in either case; or `ON` for overnight.
+///
+/// ## Examples
+///
+/// ```
+/// use date::date;
+/// use date::Tenor;
+///
+/// let tenor: Tenor = "3M".parse()?;
+/// assert_eq!(tenor, Tenor::Months(3));
+/// assert_eq!(date! { 2024-11-30 } + tenor, date! { 2025-02-28 });
+/// assert_eq!(date! { 2024-04-19 } + "1W".parse::<Tenor>()?, date! { 2024-04-26 });
+/// assert_eq!(Tenor::Years(2).to_string(), "2Y");
+/// # Ok::<(), date::ParseTenorError>(())
+/// ```
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Tenor {
+  /// Overnight: one day, or one business day when added using a business calendar.
+  Overnight,
+  /// The given number of days.
+  Days(u16),
+  /// The given number of weeks.
+  Weeks(u16),
+  /// The given number of months.
+  Months(u16),
+  /// The given number of years.
+  Years(u16),
+}
+
+impl From<Tenor> for CalendarDuration {
+  fn from(tenor: Tenor) -> Self {
+    match tenor {
+      Tenor::Overnight => Self::days(1),
+      Tenor::Days(days) => Self::days(days as i32),
+      Tenor::Weeks(weeks) => Self::days(weeks as i32 * 7),
+      Tenor::Months(months) => Self::months(months as i32),
+      Tenor::Years(years) => Self::years(years as i32),
+    }
+  }
+}
+
+impl Add<Tenor> for Date {
+  type Output = Self;
+
+  /// Return a new `Date` that is the given tenor later.
+  fn add(self, tenor: Tenor) -> Self {
+    self + CalendarDuration::from(tenor)
+  }
+}
+
+impl Sub<Tenor> for Date {
+  type Output = Self;
+
+  /// Return a new `Date` that is the given tenor earlier.
+  fn sub(self, tenor: Tenor) -> Self {
+    self - CalendarDuration::from(tenor)
+  }
+}
+
+impl fmt::Display for Tenor {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::Overnight => f.write_str("ON"),
+      Self::Days(days) => write!(f, "{days}D"),
+      Self::Weeks(weeks) => write!(f, "{weeks}W"),
+      Self::Months(months) => write!(f, "{months}M"),
+      Self::Years(years) => write!(f, "{years}Y"),
+    }
+  }
+}
+
+impl FromStr for Tenor {
+  type Err = ParseTenorError;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    if s.eq_ignore_ascii_case("ON") {
+      return Ok(Self::Overnight);
+    }
+    let unit = s.chars().last().ok_or(ParseTenorError)?;
+    let count = &s[..s.len() - unit.len_utf8()];
+    if count.is_empty() || !count.bytes().all(|b| b.is_ascii_digit()) {
+      return Err(ParseTenorError);
+    }
+    let count: u16 = count.parse().map_err(|_| ParseTenorError)?;
+    match unit.to_ascii_uppercase() {
+      'D' => Ok(Self::Days(count)),
+      'W' => Ok(Self::Weeks(count)),
+      'M' => Ok(Self::Months(count)),
+      'Y' => Ok(Self::Years(count)),
+      _ => Err(ParseTenorError),
+    }
+  }
+}
+
+/// An error returned when parsing a [`Tenor`] from a string fails.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ParseTenorError;
+
+impl fmt::Display for ParseTenorError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str("Invalid tenor")
+  }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseTenorError {}
+
+#[cfg(test)]
+mod tests {
+  use assert2::check;
+
+  use super::*;
+
+  #[test]
+  fn test_parse() {
+    check!("ON".parse() == Ok(Tenor::Overnight));
+    check!("on".parse() == Ok(Tenor::Overnight));
+    check!("1D".parse() == Ok(Tenor::Days(1)));
+    check!("2w".parse() == Ok(Tenor::Weeks(2)));
+    check!("18M".parse() == Ok(Tenor::Months(18)));
+    check!("30Y".parse() == Ok(Tenor::Years(30)));
+    check!("0D".parse() == Ok(Tenor::Days(0)));
+    for invalid in ["", "M", "3", "3X", "+3M", "-3M", "3 M", "3MM", "70000D", "1é", "O/N"] {
+      check!(invalid.parse::<Tenor>() == Err(ParseTenorError), "{invalid}");
+    }
+  }
+
+  #[test]
+  fn test_display() {
+    for tenor in [Tenor::Overnight, Tenor::Days(1), Tenor::Weeks(2), Tenor::Months(6)] {
+      check!(tenor.to_string().parse() == Ok(tenor));
+    }
+    check!(Tenor::Years(10).to_string() == "10Y");
+  }
+
+  #[test]
+  fn test_add() {
+    let date = date! { 2024-01-31 };
+    check!(date + Tenor::Overnight == date! { 2024-02-01 });
+    check!(date + Tenor::Days(30) == date! { 2024-03-01 });
+    check!(date + Tenor::Weeks(2) == date! { 2024-02-14 });
+    check!(date + Tenor::Months(1) == date! { 2024-02-29 });
+    check!(date + Tenor::Years(1) == date! { 2025-01-31 });
+    check!(date! { 2024-02-29 } + Tenor::Years(1) == date! { 2025-02-28 });
+    check!(date - Tenor::Months(2) == date! { 2023-11-30 });
+    check!(date - Tenor::Weeks(1) == date! { 2024-01-24 });
+  }
+}
@@ -0,0 +1,93 @@
+//! Utilities for testing code that depends on the current date.
+//!
+//! Functions such as [`Date::today`](crate::Date::today) and
+//! [`Date::today_utc`](crate::Date::today_utc) read the system clock. In tests, the clock can be
+//! frozen at a fixed time with [`freeze_time`], so that these functions return predictable
+//! results without changing every call site to accept the current time.
+//!
+//! The frozen time applies only to the current thread, so tests that run in parallel (each of
+//! which runs on its own thread) do not interfere with one another.
+
+use std::cell::Cell;
+use std::time::Duration;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+thread_local! {
+  static FROZEN_TIME: Cell<Option<SystemTime>> = const { Cell::new(None) };
+}
+
+/// Freeze the clock used by this crate, on the current thread, at the given Unix timestamp.
+///
+/// The clock remains frozen until the returned guard is dropped, at which point the previous
+/// state of the clock (frozen or not) is restored.
+///
+/// ## Examples
+///
+/// ```
+/// use date::date;
+/// use date::Date;
+///
+/// let _guard = date::test::freeze_time(1_334_966_400);
+/// assert_eq!(Date::today_utc(), date! { 2012-04-21 });
+/// ```
+pub fn freeze_time(unix_timestamp: i64) -> FrozenTime {
+  freeze_system_time(match unix_timestamp >= 0 {
+    true => UNIX_EPOCH + Duration::from_secs(unix_timestamp as u64),
+    false => UNIX_EPOCH - Duration::from_secs(unix_timestamp.unsigned_abs()),
+  })
+}
+
+/// Freeze the clock used by this crate, on the current thread, at the given time.
+///
+/// This is the same as [`freeze_time`], but accepts a [`SystemTime`].
+pub fn freeze_system_time(time: SystemTime) -> FrozenTime {
+  FrozenTime { previous: FROZEN_TIME.with(|cell| cell.replace(Some(time))) }
+}
+
+/// A guard that keeps the clock frozen until it is dropped; see [`freeze_time`].
+#[must_use = "the clock is unfrozen when the guard is dropped"]
+#[derive(Debug)]
+pub struct FrozenTime {
+  previous: Option<SystemTime>,
+}
+
+impl Drop for FrozenTime {
+  fn drop(&mut self) {
+    FROZEN_TIME.with(|cell| cell.set(self.previous));
+  }
+}
+
+/// The time at which the clock is frozen on the current thread, if any.
+pub(crate) fn frozen_time() -> Option<SystemTime> {
+  FROZEN_TIME.with(|cell| cell.get())
+}
+
+#[cfg(test)]
+mod tests {
+  use assert2::check;
+
+  use super::*;
+  use crate::Date;
+
+  #[test]
+  fn test_freeze_time() {
+    check!(frozen_time() == None);
+    {
+      let _guard = freeze_time(86_400);
+      check!(Date::today_utc() == date! { 1970-01-02 });
+      {
+        let _guard = freeze_time(-1);
+        check!(Date::today_utc() == date! { 1969-12-31 });
+      }
+      check!(Date::today_utc() == date! { 1970-01-02 });
+    }
+    check!(frozen_time() == None);
+  }
+
+  #[test]
+  fn test_other_threads() {
+    let _guard = freeze_time(0);
+    check!(std::thread::spawn(frozen_time).join().unwrap() == None);
+  }
+}
@@ -0,0 +1,111 @@
+use crate::Date;
+use crate::Weekday;
+
+/// A calendar period, used to find the boundaries of the period containing a date.
+///
+/// ## Examples
+///
+/// ```
+/// use date::date;
+/// use date::DateUnit;
+/// use date::Weekday;
+///
+/// let date = date! { 2024-08-15 };
+/// assert_eq!(date.start_of(DateUnit::Week(Weekday::Monday)), date! { 2024-08-12 });
+/// assert_eq!(date.start_of(DateUnit::Month), date! { 2024-08-01 });
+/// assert_eq!(date.end_of(DateUnit::Quarter), date! { 2024-09-30 });
+/// assert_eq!(date.end_of(DateUnit::Year), date! { 2024-12-31 });
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum DateUnit {
+  /// A week beginning on the given weekday.
+  Week(Weekday),
+  /// A calendar month.
+  Month,
+  /// A calendar quarter (January–March, April–June, July–September, or October–December).
+  Quarter,
+  /// A calendar year.
+  Year,
+}
+
+impl Date {
+  /// The first day of the period containing this date.
+  ///
+  /// Weeks that begin before [`Date::MIN`] are clamped to it.
+  pub const fn start_of(&self, unit: DateUnit) -> Date {
+    match unit {
+      DateUnit::Week(first_day) => {
+        let start = self.0 - first_day.days_until(self.weekday()) as i32;
+        Date(if start < Date::MIN.0 { Date::MIN.0 } else { start })
+      },
+      DateUnit::Month => self.first_of_month(),
+      DateUnit::Quarter => self.quarter().start(),
+      DateUnit::Year => self.first_of_year(),
+    }
+  }
+
+  /// The last day of the period containing this date.
+  ///
+  /// Weeks that end after [`Date::MAX`] are clamped to it.
+  pub const fn end_of(&self, unit: DateUnit) -> Date {
+    match unit {
+      DateUnit::Week(first_day) => {
+        let end = self.0 + 6 - first_day.days_until(self.weekday()) as i32;
+        Date(if end > Date::MAX.0 { Date::MAX.0 } else { end })
+      },
+      DateUnit::Month => self.last_of_month(),
+      DateUnit::Quarter => self.quarter().end(),
+      DateUnit::Year => self.last_of_year(),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use assert2::check;
+
+  use super::*;
+
+  #[test]
+  fn test_week() {
+    let date = date! { 2024-08-15 }; // Thursday
+    check!(date.start_of(DateUnit::Week(Weekday::Sunday)) == date! { 2024-08-11 });
+    check!(date.end_of(DateUnit::Week(Weekday::Sunday)) == date! { 2024-08-17 });
+    check!(date.start_of(DateUnit::Week(Weekday::Thursday)) == date);
+    check!(date.end_of(DateUnit::Week(Weekday::Friday)) == date);
+    check!(Date::MIN.start_of(DateUnit::Week(Weekday::Monday)) == Date::MIN);
+    check!(Date::MAX.end_of(DateUnit::Week(Weekday::Monday)) == Date::MAX);
+    for weekday in Weekday::all(Weekday::Sunday) {
+      let unit = DateUnit::Week(weekday);
+      for date in date! { 2024-08-01 }.iter_through(date! { 2024-08-31 }) {
+        check!(date.start_of(unit).weekday() == weekday);
+        check!(date.end_of(unit).0 - date.start_of(unit).0 == 6);
+        check!(date.start_of(unit) <= date && date <= date.end_of(unit));
+      }
+    }
+  }
+
+  #[test]
+  fn test_quarter() {
+    let cases = [
+      (date! { 2024-01-01 }, date! { 2024-01-01 }, date! { 2024-03-31 }),
+      (date! { 2024-03-31 }, date! { 2024-01-01 }, date! { 2024-03-31 }),
+      (date! { 2024-05-15 }, date! { 2024-04-01 }, date! { 2024-06-30 }),
+      (date! { 2024-07-04 }, date! { 2024-07-01 }, date! { 2024-09-30 }),
+      (date! { 2024-12-31 }, date! { 2024-10-01 }, date! { 2024-12-31 }),
+    ];
+    for (date, start, end) in cases {
+      check!(date.start_of(DateUnit::Quarter) == start);
+      check!(date.end_of(DateUnit::Quarter) == end);
+    }
+  }
+
+  #[test]
+  fn test_month_year() {
+    let date = date! { 2024-02-10 };
+    check!(date.start_of(DateUnit::Month) == date! { 2024-02-01 });
+    check!(date.end_of(DateUnit::Month) == date! { 2024-02-29 });
+    check!(date.start_of(DateUnit::Year) == date! { 2024-01-01 });
+    check!(date.end_of(DateUnit::Year) == date! { 2024-12-31 });
+  }
+}
@@ -1,11 +1,53 @@
 const DAYS_IN_MONTH: [u8; 12] = [31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
 const DAYS_IN_MONTH_LY: [u8; 12] = [31, 29, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
 
+/// The number of days between March 1, 40,000 BCE (year -40,000) and the Unix epoch.
+///
+/// Shifting by a whole number of 400-year cycles means every representable date maps to a
+/// non-negative day count, so the conversions below can use unsigned arithmetic and need no
+/// branches for negative years.
+const SHIFT: u32 = 146_097 * 100 + 719_468;
+
+/// The number of years corresponding to [`SHIFT`].
+const YEAR_SHIFT: i32 = 40_000;
+
 /// Return true if this is a leap year, false otherwise.
 pub(crate) const fn is_leap_year(year: i16) -> bool {
   year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
 }
 
+/// Returns the number of days in the year.
+pub(crate) const fn days_in_year(year: i16) -> u16 {
+  match is_leap_year(year) {
+    true => 366,
+    false => 365,
+  }
+}
+
+/// Parse the given range of ASCII digits as a number.
+///
+/// Returns `None` if the range is empty, contains anything other than digits, or is too long.
+pub(crate) const fn parse_digits(bytes: &[u8], start: usize, end: usize) -> Option<i32> {
+  if start >= end || end - start > 9 {
+    return None;
+  }
+  let mut value = 0;
+  let mut i = start;
+  while i < end {
+    match bytes[i] {
+      b'0'..=b'9' => value = value * 10 + (bytes[i] - b'0') as i32,
+      _ => return None,
+    }
+    i += 1;
+  }
+  Some(value)
+}
+
+/// Return true if the year, month, and day form a valid date, false otherwise.
+pub(crate) const fn is_valid_date(year: i16, month: u8, day: u8) -> bool {
+  month >= 1 && month <= 12 && day >= 1 && day <= days_in_month(year, month)
+}
+
 /// Returns the number of days in the month.
 pub(crate) const fn days_in_month(year: i16, month: u8) -> u8 {
   (match is_leap_year(year) {
@@ -13,3 +55,53 @@ pub(crate) const fn days_in_month(year: i16, month: u8) -> u8 {
     false => DAYS_IN_MONTH,
   })[month as usize - 1]
 }
+
+/// The number of 400-year cycles by which [`civil_from_days`] shifts its input, beyond [`SHIFT`].
+///
+/// Together with [`SHIFT`], this is enough for any `i32` day count to map to a non-negative day
+/// count, including those outside the range of representable dates (which may arise from
+/// [`Date::from_timestamp`](crate::Date::from_timestamp)).
+const WIDE_CYCLES: u64 = 14_700;
+
+/// The year, month, and day for the given number of days since the Unix epoch.
+///
+/// This uses the algorithm described by Neri and Schneider in "Euclidean affine functions and
+/// their application to calendar algorithms" (2022), on a calendar shifted to begin in March.
+///
+/// The intermediate arithmetic is done in 64 bits so that it cannot overflow for any input; for
+/// day counts outside the range of representable dates, the year wraps.
+pub(crate) const fn civil_from_days(days: i32) -> (i16, u8, u8) {
+  let n = (days as i64 + SHIFT as i64 + (WIDE_CYCLES * 146_097) as i64) as u64;
+
+  // Centuries, and the day within the century.
+  let n1 = 4 * n + 3;
+  let century = n1 / 146_097;
+  let n2 = (n1 % 146_097) as u32 | 3;
+
+  // Years within the century, and the day within the (March-based) year.
+  let p2 = 2_939_745 * n2 as u64;
+  let year_of_century = (p2 >> 32) as u32;
+  let day_of_year = (p2 as u32) / 2_939_745 / 4;
+
+  // Months and days; January and February belong to the following year.
+  let p3 = 2_141 * day_of_year + 197_913;
+  let month = p3 >> 16;
+  let day = (p3 & 0xffff) / 2_141 + 1;
+  let january_or_february = (day_of_year >= 306) as u32;
+  let year = (100 * century + (year_of_century + january_or_february) as u64) as i64
+    - YEAR_SHIFT as i64
+    - 400 * WIDE_CYCLES as i64;
+  (year as i16, (month - 12 * january_or_february) as u8, day as u8)
+}
+
+/// The number of days since the Unix epoch for the given year, month, and day.
+pub(crate) const fn days_from_civil(year: i16, month: u8, day: u8) -> i32 {
+  // January and February count as months 13 and 14 of the preceding year.
+  let january_or_february = (month <= 2) as u32;
+  let year = (year as i32 + YEAR_SHIFT) as u32 - january_or_february;
+  let month = month as u32 + 12 * january_or_february;
+  let century = year / 100;
+  let year_days = 1_461 * year / 4 - century + century / 4;
+  let month_days = (979 * month - 2_919) / 32;
+  (year_days + month_days + day as u32 - 1) as i32 - SHIFT as i32
+}
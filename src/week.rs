@@ -0,0 +1,306 @@
+use crate::utils;
+use crate::Date;
+use crate::Weekday;
+
+/// A convention for numbering the weeks of a year.
+///
+/// A policy specifies the day on which each week begins, and the minimum number of days of the
+/// new year that the first week must contain; week 1 is the first week that contains at least
+/// that many days. Policies also determine what happens to days that fall outside of the weeks
+/// of their own year:
+///
+/// - Policies made with [`WeekPolicy::week_based`] (such as [`WeekPolicy::ISO`]) assign such days
+///   to the last week of the previous year or the first week of the next year.
+/// - Policies made with [`WeekPolicy::calendar_year`] (such as [`WeekPolicy::US`]) number weeks
+///   within the calendar year: days before week 1 are in week 0, and the last week of the year may
+///   be a partial week.
+///
+/// ## Examples
+///
+/// ```
+/// use date::date;
+/// use date::WeekPolicy;
+///
+/// let date = date! { 2021-01-03 };
+/// assert_eq!(date.week_number(WeekPolicy::ISO), 53);
+/// assert_eq!(date.week_number(WeekPolicy::US), 2);
+/// assert_eq!(date.week_number(WeekPolicy::FIRST_SUNDAY), 1);
+/// assert_eq!(date.week_number(WeekPolicy::FIRST_MONDAY), 0);
+/// ```
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub struct WeekPolicy {
+  first_day: Weekday,
+  min_days: u8,
+  week_based: bool,
+}
+
+impl WeekPolicy {
+  /// Week 1 begins on the first Monday of the year, and leading days are in week 0.
+  ///
+  /// This is the same as the `%W` format token.
+  pub const FIRST_MONDAY: Self = Self::calendar_year(Weekday::Monday, 7);
+  /// Week 1 begins on the first Sunday of the year, and leading days are in week 0.
+  ///
+  /// This is the same as [`Date::week`] and the `%U` format token.
+  pub const FIRST_SUNDAY: Self = Self::calendar_year(Weekday::Sunday, 7);
+  /// ISO 8601 week numbers: weeks begin on Monday, and week 1 is the first week with at least
+  /// four days in the new year (equivalently, the week containing the first Thursday).
+  ///
+  /// This is the same as [`IsoWeekDate::week`](crate::IsoWeekDate::week), and the `%V` format
+  /// token.
+  pub const ISO: Self = Self::week_based(Weekday::Monday, 4);
+  /// United States week numbers: weeks begin on Sunday, and week 1 is the week containing January
+  /// 1, so that the last week of the year may be week 53 or 54.
+  pub const US: Self = Self::calendar_year(Weekday::Sunday, 1);
+
+  /// A policy in which every week belongs to a single year, so that days at the start or end of
+  /// the calendar year may be in the last week of the previous year or the first week of the next.
+  ///
+  /// `min_days` is clamped to between 1 and 7.
+  pub const fn week_based(first_day: Weekday, min_days: u8) -> Self {
+    Self { first_day, min_days: clamp_min_days(min_days), week_based: true }
+  }
+
+  /// A policy in which weeks are numbered within the calendar year, so that days before week 1
+  /// are in week 0.
+  ///
+  /// `min_days` is clamped to between 1 and 7.
+  pub const fn calendar_year(first_day: Weekday, min_days: u8) -> Self {
+    Self { first_day, min_days: clamp_min_days(min_days), week_based: false }
+  }
+
+  /// The day on which each week begins.
+  pub const fn first_day(&self) -> Weekday {
+    self.first_day
+  }
+
+  /// The minimum number of days of the new year that week 1 contains.
+  pub const fn min_days(&self) -> u8 {
+    self.min_days
+  }
+
+  /// The first day of week 1 of the year beginning on the given day (represented as days since
+  /// the Unix epoch, which may be outside the range of [`Date`]).
+  const fn week1_start(&self, jan1: i32) -> i32 {
+    let offset = Date(jan1).weekday().days_until(self.first_day) as i32;
+    let (start, days_in_year) = match offset {
+      0 => (jan1, 7),
+      _ => (jan1 + offset - 7, offset),
+    };
+    match days_in_year >= self.min_days as i32 {
+      true => start,
+      false => start + 7,
+    }
+  }
+}
+
+impl Date {
+  /// The week number of this date, according to the given policy.
+  ///
+  /// See [`WeekPolicy`] for the supported numbering conventions.
+  pub const fn week_number(&self, policy: WeekPolicy) -> u8 {
+    let year = self.year();
+    let jan1 = Date::new(year, 1, 1).0;
+    let week1 = policy.week1_start(jan1);
+    if policy.week_based {
+      if self.0 < week1 {
+        let days_in_prev_year = match year.checked_sub(1) {
+          Some(prev) => utils::days_in_year(prev) as i32,
+          None => 365,
+        };
+        return ((self.0 - policy.week1_start(jan1 - days_in_prev_year)) / 7 + 1) as u8;
+      }
+      if self.0 >= policy.week1_start(jan1 + utils::days_in_year(year) as i32) {
+        return 1;
+      }
+    }
+    ((self.0 - week1).div_euclid(7) + 1) as u8
+  }
+
+  /// The week of the month (between 1 and 6) in which this date falls, with weeks beginning on
+  /// Sunday.
+  ///
+  /// Week 1 is the week containing the first day of the month, as in the rows of a printed
+  /// calendar; it may begin in the previous month.
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::date;
+  ///
+  /// assert_eq!(date! { 2024-06-01 }.week_of_month(), 1); // Saturday
+  /// assert_eq!(date! { 2024-06-02 }.week_of_month(), 2); // Sunday
+  /// assert_eq!(date! { 2024-06-30 }.week_of_month(), 6);
+  /// ```
+  pub const fn week_of_month(&self) -> u8 {
+    self.week_of_month_starting(Weekday::Sunday)
+  }
+
+  /// The week of the month (between 1 and 6) in which this date falls, with weeks beginning on the
+  /// given day.
+  ///
+  /// See [`Date::week_of_month`] for details.
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::date;
+  /// use date::Weekday;
+  ///
+  /// let date = date! { 2024-06-02 }; // Sunday
+  /// assert_eq!(date.week_of_month_starting(Weekday::Sunday), 2);
+  /// assert_eq!(date.week_of_month_starting(Weekday::Monday), 1);
+  /// ```
+  pub const fn week_of_month_starting(&self, first_day: Weekday) -> u8 {
+    let offset = first_day.days_until(self.first_of_month().weekday());
+    (self.day() - 1 + offset) / 7 + 1
+  }
+
+  /// The number of ISO 8601 weeks in the given ISO year (either 52 or 53).
+  ///
+  /// This is the same as [`IsoWeekDate::weeks_in_year`](crate::IsoWeekDate::weeks_in_year).
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::Date;
+  ///
+  /// assert_eq!(Date::iso_weeks_in_year(2020), 53);
+  /// assert_eq!(Date::iso_weeks_in_year(2021), 52);
+  /// ```
+  pub const fn iso_weeks_in_year(year: i16) -> u8 {
+    Self::weeks_in_year(year, WeekPolicy::ISO)
+  }
+
+  /// The number of the last week of the given year, according to the given policy.
+  ///
+  /// For policies made with [`WeekPolicy::week_based`], this is the number of weeks in the year.
+  /// For policies made with [`WeekPolicy::calendar_year`], this is the week number of December 31;
+  /// such policies may also place days at the beginning of the year in week 0.
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::Date;
+  /// use date::WeekPolicy;
+  ///
+  /// assert_eq!(Date::weeks_in_year(2000, WeekPolicy::US), 54);
+  /// assert_eq!(Date::weeks_in_year(2024, WeekPolicy::US), 53);
+  /// assert_eq!(Date::weeks_in_year(2024, WeekPolicy::FIRST_SUNDAY), 52);
+  /// ```
+  pub const fn weeks_in_year(year: i16, policy: WeekPolicy) -> u8 {
+    let jan1 = Date::new(year, 1, 1).0;
+    match policy.week_based {
+      true => {
+        let next_jan1 = jan1 + utils::days_in_year(year) as i32;
+        ((policy.week1_start(next_jan1) - policy.week1_start(jan1)) / 7) as u8
+      },
+      false => Date::new(year, 12, 31).week_number(policy),
+    }
+  }
+}
+
+/// Clamp the minimum number of days in the first week to between 1 and 7.
+const fn clamp_min_days(min_days: u8) -> u8 {
+  match min_days {
+    0 => 1,
+    1..=7 => min_days,
+    _ => 7,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use assert2::check;
+
+  use super::*;
+  use crate::IsoWeekDate;
+
+  #[test]
+  fn test_iso() {
+    for date in date! { 1999-12-01 }.iter_through(date! { 2030-01-31 }) {
+      check!(date.week_number(WeekPolicy::ISO) == date.iso_week_date().week(), "{date}");
+    }
+    check!(Date::MIN.week_number(WeekPolicy::ISO) == Date::MIN.iso_week_date().week());
+  }
+
+  #[test]
+  fn test_first_sunday_and_monday() {
+    for date in date! { 1999-12-01 }.iter_through(date! { 2030-01-31 }) {
+      check!(date.week_number(WeekPolicy::FIRST_SUNDAY) as u16 == date.week());
+      let monday_based = (date.weekday() as u16 + 6) % 7;
+      let expected = (date.day_of_year() + 6 - monday_based) / 7;
+      check!(date.week_number(WeekPolicy::FIRST_MONDAY) as u16 == expected);
+    }
+  }
+
+  #[test]
+  fn test_us() {
+    for year in 1990..2030 {
+      check!(Date::new(year, 1, 1).week_number(WeekPolicy::US) == 1);
+    }
+    check!(date! { 2000-01-01 }.week_number(WeekPolicy::US) == 1);
+    check!(date! { 2000-01-02 }.week_number(WeekPolicy::US) == 2);
+    check!(date! { 2000-12-31 }.week_number(WeekPolicy::US) == 54);
+    check!(date! { 2024-12-31 }.week_number(WeekPolicy::US) == 53);
+  }
+
+  #[test]
+  fn test_week_based() {
+    // Weeks beginning on Sunday, where week 1 contains January 1.
+    let policy = WeekPolicy::week_based(Weekday::Sunday, 1);
+    check!(date! { 2024-12-28 }.week_number(policy) == 52);
+    check!(date! { 2024-12-29 }.week_number(policy) == 1);
+    check!(date! { 2025-01-04 }.week_number(policy) == 1);
+    check!(date! { 2025-01-05 }.week_number(policy) == 2);
+
+    // Weeks beginning on Saturday, where week 1 is the first full week.
+    let policy = WeekPolicy::week_based(Weekday::Saturday, 7);
+    check!(date! { 2025-01-03 }.week_number(policy) == 52);
+    check!(date! { 2025-01-04 }.week_number(policy) == 1);
+    check!(Date::MAX.week_number(policy) >= 52);
+  }
+
+  #[test]
+  fn test_weeks_in_year() {
+    for year in 1990..2040 {
+      check!(Date::iso_weeks_in_year(year) == IsoWeekDate::weeks_in_year(year));
+      for policy in [WeekPolicy::ISO, WeekPolicy::week_based(Weekday::Sunday, 1)] {
+        let last = Date::new(year + 1, 1, 1).0 - 1;
+        let max = (last - 6..=last).map(|d| Date(d).week_number(policy)).max().unwrap();
+        check!(Date::weeks_in_year(year, policy) == max, "{year}");
+      }
+    }
+    check!(Date::weeks_in_year(2000, WeekPolicy::US) == 54);
+    check!(Date::weeks_in_year(2023, WeekPolicy::FIRST_MONDAY) == 52);
+    check!(Date::weeks_in_year(2024, WeekPolicy::FIRST_MONDAY) == 53);
+    check!(Date::iso_weeks_in_year(i16::MIN) >= 52);
+    check!(Date::iso_weeks_in_year(i16::MAX) >= 52);
+  }
+
+  #[test]
+  fn test_week_of_month() {
+    for weekday in Weekday::all(Weekday::Sunday) {
+      for date in date! { 2024-01-01 }.iter_through(date! { 2024-12-31 }) {
+        let week = date.week_of_month_starting(weekday);
+        check!((1..=6).contains(&week));
+        let expected = match (date.day(), date.weekday() == weekday) {
+          (1, _) => 1,
+          (_, true) => Date(date.0 - 1).week_of_month_starting(weekday) + 1,
+          (_, false) => Date(date.0 - 1).week_of_month_starting(weekday),
+        };
+        check!(week == expected);
+      }
+    }
+    check!(date! { 2024-06-30 }.week_of_month() == 6);
+    check!(date! { 2026-02-28 }.week_of_month() == 4);
+  }
+
+  #[test]
+  fn test_clamp() {
+    check!(WeekPolicy::calendar_year(Weekday::Monday, 0).min_days() == 1);
+    check!(WeekPolicy::calendar_year(Weekday::Monday, 9).min_days() == 7);
+    check!(WeekPolicy::ISO.first_day() == Weekday::Monday);
+    check!(WeekPolicy::ISO.min_days() == 4);
+  }
+}
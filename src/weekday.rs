@@ -1,7 +1,13 @@
-use std::fmt::Display;
+use core::fmt;
+use core::fmt::Display;
+use core::str::FromStr;
+
+use crate::Date;
+#[cfg(feature = "i18n")]
+use crate::Locale;
 
 /// A representation of the day of the week.
-#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
 #[repr(u8)]
 pub enum Weekday {
   Sunday = 0,
@@ -14,21 +20,141 @@ pub enum Weekday {
 }
 
 impl Display for Weekday {
-  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-    macro_rules! display {
-      ($($e:ident),*) => {
-        f.write_str(match self {
-          $(Self::$e => stringify!($e)),*
-        })
-      };
-    }
-    display!(Sunday, Monday, Tuesday, Wednesday, Thursday, Friday, Saturday)
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    f.write_str(self.name())
   }
 }
 
 impl Weekday {
-  /// The three-letter abbreviation for this weekday.
-  pub(crate) fn abbv(&self) -> &'static str {
+  /// The weekday that is the given number of days after this one (or before, if `days` is
+  /// negative).
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::Weekday;
+  ///
+  /// assert_eq!(Weekday::Friday.add_days(3), Weekday::Monday);
+  /// assert_eq!(Weekday::Sunday.add_days(-1), Weekday::Saturday);
+  /// ```
+  pub const fn add_days(self, days: i32) -> Weekday {
+    match (self as i64 + days as i64).rem_euclid(7) {
+      0 => Self::Sunday,
+      1 => Self::Monday,
+      2 => Self::Tuesday,
+      3 => Self::Wednesday,
+      4 => Self::Thursday,
+      5 => Self::Friday,
+      6 => Self::Saturday,
+      #[cfg(not(tarpaulin_include))]
+      _ => panic!("Unreachable: n.rem_euclid(7) must be within 0 to 6"),
+    }
+  }
+
+  /// The number of days (between 0 and 6) from this weekday until the next occurrence of the
+  /// given weekday.
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::Weekday;
+  ///
+  /// assert_eq!(Weekday::Friday.days_until(Weekday::Monday), 3);
+  /// assert_eq!(Weekday::Monday.days_until(Weekday::Monday), 0);
+  /// ```
+  pub const fn days_until(self, other: Weekday) -> u8 {
+    (other as i8 - self as i8).rem_euclid(7) as u8
+  }
+
+  /// All seven weekdays, in order, beginning with the given weekday.
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::Weekday;
+  ///
+  /// let header: Vec<_> = Weekday::all(Weekday::Monday).map(|w| w.to_string()).collect();
+  /// assert_eq!(header.first().unwrap(), "Monday");
+  /// assert_eq!(header.last().unwrap(), "Sunday");
+  /// ```
+  pub fn all(start: Weekday) -> impl DoubleEndedIterator<Item = Weekday> + ExactSizeIterator {
+    (0..7).map(move |n| start.add_days(n))
+  }
+
+  /// The number of this weekday, from Sunday (`1`) to Saturday (`7`).
+  pub const fn number_from_sunday(self) -> u8 {
+    self as u8 + 1
+  }
+
+  /// The number of this weekday, from Monday (`1`) to Sunday (`7`).
+  ///
+  /// This is the weekday number used by ISO 8601.
+  pub const fn number_from_monday(self) -> u8 {
+    match self {
+      Self::Sunday => 7,
+      _ => self as u8,
+    }
+  }
+
+  /// The name of this weekday in the given locale.
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::Locale;
+  /// use date::Weekday;
+  ///
+  /// assert_eq!(Weekday::Wednesday.name_in(Locale::English), "Wednesday");
+  /// assert_eq!(Weekday::Wednesday.name_in(Locale::German), "Mittwoch");
+  /// assert_eq!(Weekday::Wednesday.name_in(Locale::French), "mercredi");
+  /// ```
+  #[cfg(feature = "i18n")]
+  pub const fn name_in(self, locale: Locale) -> &'static str {
+    locale.weekday_names()[self as usize]
+  }
+
+  /// The abbreviated name of this weekday in the given locale.
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::Locale;
+  /// use date::Weekday;
+  ///
+  /// assert_eq!(Weekday::Wednesday.abbv_in(Locale::English), "Wed");
+  /// assert_eq!(Weekday::Wednesday.abbv_in(Locale::Spanish), "mié");
+  /// ```
+  #[cfg(feature = "i18n")]
+  pub const fn abbv_in(self, locale: Locale) -> &'static str {
+    locale.weekday_abbvs()[self as usize]
+  }
+
+  /// The full English name of this weekday.
+  ///
+  /// This is the same as the [`Display`] output.
+  pub const fn name(&self) -> &'static str {
+    match self {
+      Self::Sunday => "Sunday",
+      Self::Monday => "Monday",
+      Self::Tuesday => "Tuesday",
+      Self::Wednesday => "Wednesday",
+      Self::Thursday => "Thursday",
+      Self::Friday => "Friday",
+      Self::Saturday => "Saturday",
+    }
+  }
+
+  /// The three-letter English abbreviation for this weekday.
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::Weekday;
+  ///
+  /// assert_eq!(Weekday::Wednesday.abbv(), "Wed");
+  /// assert_eq!(Weekday::Wednesday.abbv().parse(), Ok(Weekday::Wednesday));
+  /// ```
+  pub const fn abbv(&self) -> &'static str {
     match self {
       Self::Sunday => "Sun",
       Self::Monday => "Mon",
@@ -41,6 +167,140 @@ impl Weekday {
   }
 }
 
+impl Date {
+  /// The first date after this one that falls on the given weekday.
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::date;
+  /// use date::Weekday;
+  ///
+  /// let date = date! { 2024-04-19 }; // Friday
+  /// assert_eq!(date.next_weekday(Weekday::Monday), date! { 2024-04-22 });
+  /// assert_eq!(date.next_weekday(Weekday::Friday), date! { 2024-04-26 });
+  /// ```
+  pub const fn next_weekday(&self, weekday: Weekday) -> Date {
+    Date(self.0 + 1 + self.weekday().add_days(1).days_until(weekday) as i32)
+  }
+
+  /// The first date on or after this one that falls on the given weekday.
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::date;
+  /// use date::Weekday;
+  ///
+  /// let date = date! { 2024-04-19 }; // Friday
+  /// assert_eq!(date.next_weekday_or_same(Weekday::Monday), date! { 2024-04-22 });
+  /// assert_eq!(date.next_weekday_or_same(Weekday::Friday), date);
+  /// ```
+  pub const fn next_weekday_or_same(&self, weekday: Weekday) -> Date {
+    Date(self.0 + self.weekday().days_until(weekday) as i32)
+  }
+
+  /// The last date before this one that falls on the given weekday.
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::date;
+  /// use date::Weekday;
+  ///
+  /// let date = date! { 2024-04-19 }; // Friday
+  /// assert_eq!(date.prev_weekday(Weekday::Monday), date! { 2024-04-15 });
+  /// assert_eq!(date.prev_weekday(Weekday::Friday), date! { 2024-04-12 });
+  /// ```
+  pub const fn prev_weekday(&self, weekday: Weekday) -> Date {
+    Date(self.0 - 1 - weekday.days_until(self.weekday().add_days(-1)) as i32)
+  }
+
+  /// The last date on or before this one that falls on the given weekday.
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::date;
+  /// use date::Weekday;
+  ///
+  /// let date = date! { 2024-04-19 }; // Friday
+  /// assert_eq!(date.prev_weekday_or_same(Weekday::Monday), date! { 2024-04-15 });
+  /// assert_eq!(date.prev_weekday_or_same(Weekday::Friday), date);
+  /// ```
+  pub const fn prev_weekday_or_same(&self, weekday: Weekday) -> Date {
+    Date(self.0 - weekday.days_until(self.weekday()) as i32)
+  }
+
+  /// The date closest to this one that falls on the given weekday.
+  ///
+  /// This is this date itself if it falls on the given weekday; otherwise, it is at most three
+  /// days earlier or later. (Because a week has an odd number of days, there is never a tie.)
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::date;
+  /// use date::Weekday;
+  ///
+  /// let date = date! { 2024-04-19 }; // Friday
+  /// assert_eq!(date.nearest_weekday(Weekday::Monday), date! { 2024-04-22 });
+  /// assert_eq!(date.nearest_weekday(Weekday::Tuesday), date! { 2024-04-16 });
+  /// assert_eq!(date.nearest_weekday(Weekday::Friday), date);
+  /// ```
+  pub const fn nearest_weekday(&self, weekday: Weekday) -> Date {
+    let days = self.weekday().days_until(weekday) as i32;
+    match days <= 3 {
+      true => Date(self.0 + days),
+      false => Date(self.0 + days - 7),
+    }
+  }
+}
+
+impl FromStr for Weekday {
+  type Err = InvalidWeekdayError;
+
+  /// Parse a weekday from its full name (`"Tuesday"`) or its three-letter abbreviation
+  /// (`"Tue"`), ignoring ASCII case.
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    Weekday::all(Weekday::Sunday)
+      .find(|w| s.eq_ignore_ascii_case(w.abbv()) || s.eq_ignore_ascii_case(w.name()))
+      .ok_or(InvalidWeekdayError)
+  }
+}
+
+impl TryFrom<u8> for Weekday {
+  type Error = InvalidWeekdayError;
+
+  /// Convert a number to a weekday, with Sunday as 0 and Saturday as 6.
+  fn try_from(value: u8) -> Result<Self, Self::Error> {
+    match value {
+      0..=6 => Ok(Weekday::Sunday.add_days(value as i32)),
+      _ => Err(InvalidWeekdayError),
+    }
+  }
+}
+
+impl From<Weekday> for u8 {
+  /// Convert a weekday to a number, with Sunday as 0 and Saturday as 6.
+  fn from(weekday: Weekday) -> Self {
+    weekday as u8
+  }
+}
+
+/// An error returned when a string or number does not represent a weekday.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct InvalidWeekdayError;
+
+impl fmt::Display for InvalidWeekdayError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str("Invalid weekday")
+  }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidWeekdayError {}
+
 #[cfg(test)]
 mod tests {
   use assert2::check;
@@ -89,6 +349,22 @@ mod tests {
     check!(date! { 2024-11-28 }.weekday() == Weekday::Thursday);
   }
 
+  #[test]
+  fn test_arithmetic() {
+    const MONDAY: Weekday = Weekday::Saturday.add_days(2);
+    check!(MONDAY == Weekday::Monday);
+    check!(Weekday::Wednesday.add_days(0) == Weekday::Wednesday);
+    check!(Weekday::Wednesday.add_days(7 * 1000 + 1) == Weekday::Thursday);
+    check!(Weekday::Wednesday.add_days(i32::MIN) == Weekday::Monday);
+    check!(Weekday::Saturday.days_until(Weekday::Sunday) == 1);
+    check!(Weekday::Sunday.days_until(Weekday::Saturday) == 6);
+    for weekday in Weekday::all(Weekday::Sunday) {
+      for days in -14..14 {
+        check!(weekday.days_until(weekday.add_days(days)) as i32 == days.rem_euclid(7));
+      }
+    }
+  }
+
   #[test]
   fn test_display() {
     for (weekday, weekday_str, weekday_abbv_str) in [
@@ -104,4 +380,94 @@ mod tests {
       check!(weekday.abbv() == weekday_abbv_str);
     }
   }
+
+  #[test]
+  #[cfg(feature = "i18n")]
+  fn test_localized() {
+    for weekday in Weekday::all(Weekday::Sunday) {
+      check!(weekday.name_in(Locale::English) == weekday.to_string());
+      check!(weekday.abbv_in(Locale::English) == weekday.abbv());
+    }
+    check!(Weekday::Sunday.name_in(Locale::Dutch) == "zondag");
+    check!(Weekday::Saturday.name_in(Locale::Portuguese) == "sábado");
+    check!(Weekday::Thursday.abbv_in(Locale::Italian) == "gio");
+    check!(Weekday::Monday.abbv_in(Locale::German) == "Mo.");
+  }
+
+  #[test]
+  fn test_all() {
+    let weekdays: Vec<_> = Weekday::all(Weekday::Sunday).collect();
+    check!(weekdays.len() == 7);
+    check!(weekdays[0] == Weekday::Sunday);
+    check!(weekdays[6] == Weekday::Saturday);
+    check!(weekdays.windows(2).all(|w| w[0].add_days(1) == w[1]));
+    let weekdays: Vec<_> = Weekday::all(Weekday::Thursday).collect();
+    check!(weekdays[0] == Weekday::Thursday);
+    check!(weekdays[6] == Weekday::Wednesday);
+    check!(Weekday::all(Weekday::Monday).len() == 7);
+    check!(Weekday::all(Weekday::Monday).next_back() == Some(Weekday::Sunday));
+  }
+
+  #[test]
+  fn test_numbering() {
+    check!(Weekday::Sunday.number_from_sunday() == 1);
+    check!(Weekday::Saturday.number_from_sunday() == 7);
+    check!(Weekday::Monday.number_from_monday() == 1);
+    check!(Weekday::Sunday.number_from_monday() == 7);
+    for (n, weekday) in Weekday::all(Weekday::Sunday).enumerate() {
+      check!(weekday.number_from_sunday() as usize == n + 1);
+    }
+    for (n, weekday) in Weekday::all(Weekday::Monday).enumerate() {
+      check!(weekday.number_from_monday() as usize == n + 1);
+    }
+  }
+
+  #[test]
+  fn test_from_str() {
+    check!("Tuesday".parse::<Weekday>() == Ok(Weekday::Tuesday));
+    check!("Tue".parse::<Weekday>() == Ok(Weekday::Tuesday));
+    check!("tue".parse::<Weekday>() == Ok(Weekday::Tuesday));
+    check!("SATURDAY".parse::<Weekday>() == Ok(Weekday::Saturday));
+    for weekday in Weekday::all(Weekday::Sunday) {
+      check!(weekday.to_string().parse::<Weekday>() == Ok(weekday));
+      check!(weekday.abbv().parse::<Weekday>() == Ok(weekday));
+    }
+    check!("Tues".parse::<Weekday>() == Err(InvalidWeekdayError));
+    check!("".parse::<Weekday>() == Err(InvalidWeekdayError));
+    check!(InvalidWeekdayError.to_string() == "Invalid weekday");
+  }
+
+  #[test]
+  fn test_numeric() {
+    for n in 0..7 {
+      let weekday = Weekday::try_from(n).unwrap();
+      check!(u8::from(weekday) == n);
+    }
+    check!(Weekday::try_from(0) == Ok(Weekday::Sunday));
+    check!(Weekday::try_from(6) == Ok(Weekday::Saturday));
+    check!(Weekday::try_from(7) == Err(InvalidWeekdayError));
+  }
+
+  #[test]
+  fn test_next_prev_nearest() {
+    for date in date! { 2024-04-01 }.iter_through(date! { 2024-04-30 }) {
+      for weekday in Weekday::all(Weekday::Sunday) {
+        let next = date.next_weekday(weekday);
+        check!(next.weekday() == weekday);
+        check!(next > date && next.0 - date.0 <= 7);
+        let next = date.next_weekday_or_same(weekday);
+        check!(next.weekday() == weekday);
+        check!(next >= date && next.0 - date.0 < 7);
+        let prev = date.prev_weekday(weekday);
+        check!(prev.weekday() == weekday);
+        check!(prev < date && date.0 - prev.0 <= 7);
+        let prev = date.prev_weekday_or_same(weekday);
+        check!(prev.weekday() == weekday);
+        check!(prev <= date && date.0 - prev.0 < 7);
+        let nearest = date.nearest_weekday(weekday);
+        check!(nearest.weekday() == weekday);
+        check!((nearest.0 - date.0).abs() <= 3);
+      }
+    }
+  }
 }
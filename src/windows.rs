@@ -0,0 +1,162 @@
+use crate::Date;
+
+/// The number of 100-nanosecond intervals in a day.
+const TICKS_PER_DAY: i64 = 864_000_000_000;
+
+/// The number of days between January 1, 1601 (the Windows `FILETIME` epoch) and the Unix epoch.
+const FILETIME_EPOCH: i32 = -134_774;
+
+/// The number of days between January 1, 0001 (the .NET `DateTime` epoch) and the Unix epoch.
+const DOTNET_EPOCH: i32 = -719_162;
+
+impl Date {
+  /// The date containing the given Windows `FILETIME`, in UTC.
+  ///
+  /// A `FILETIME` is the number of 100-nanosecond intervals since January 1, 1601 (UTC). Windows
+  /// requires that the value not exceed `i64::MAX`.
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::date;
+  /// use date::Date;
+  ///
+  /// assert_eq!(Date::from_filetime(0), date! { 1601-01-01 });
+  /// assert_eq!(Date::from_filetime(129_794_400_000_000_000), date! { 2012-04-21 });
+  /// ```
+  ///
+  /// ## Panic
+  ///
+  /// This function panics if the value exceeds `i64::MAX`; use [`Date::checked_from_filetime`]
+  /// to validate untrusted input.
+  pub const fn from_filetime(filetime: u64) -> Self {
+    match Self::checked_from_filetime(filetime) {
+      Some(date) => date,
+      None => panic!("FILETIME out-of-bounds"),
+    }
+  }
+
+  /// The date containing the given Windows `FILETIME`, in UTC, or `None` if the value exceeds
+  /// `i64::MAX` (or would otherwise fall outside the range of [`Date::MIN`] to [`Date::MAX`]).
+  ///
+  /// This is the non-panicking counterpart to [`Date::from_filetime`].
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::date;
+  /// use date::Date;
+  ///
+  /// assert_eq!(Date::checked_from_filetime(0), Some(date! { 1601-01-01 }));
+  /// assert_eq!(Date::checked_from_filetime(u64::MAX), None);
+  /// ```
+  pub const fn checked_from_filetime(filetime: u64) -> Option<Self> {
+    match filetime <= i64::MAX as u64 {
+      true =>
+        Self::checked_from_unix_days(FILETIME_EPOCH + (filetime / TICKS_PER_DAY as u64) as i32),
+      false => None,
+    }
+  }
+
+  /// The Windows `FILETIME` for midnight (UTC) on this date, or `None` if the date is before
+  /// January 1, 1601.
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::date;
+  ///
+  /// assert_eq!(date! { 2012-04-21 }.to_filetime(), Some(129_794_400_000_000_000));
+  /// assert_eq!(date! { 1600-12-31 }.to_filetime(), None);
+  /// ```
+  pub const fn to_filetime(&self) -> Option<u64> {
+    match self.0 >= FILETIME_EPOCH {
+      true => Some((self.0 - FILETIME_EPOCH) as u64 * TICKS_PER_DAY as u64),
+      false => None,
+    }
+  }
+
+  /// The date containing the given .NET `DateTime.Ticks` value.
+  ///
+  /// Ticks are the number of 100-nanosecond intervals since January 1, 0001, in the `DateTime`'s
+  /// own time zone (which .NET does not record in the ticks value).
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::date;
+  /// use date::Date;
+  ///
+  /// assert_eq!(Date::from_dotnet_ticks(0), date! { 0001-01-01 });
+  /// assert_eq!(Date::from_dotnet_ticks(634_705_632_000_000_000), date! { 2012-04-21 });
+  /// ```
+  pub const fn from_dotnet_ticks(ticks: i64) -> Self {
+    Self(DOTNET_EPOCH + ticks.div_euclid(TICKS_PER_DAY) as i32)
+  }
+
+  /// The .NET `DateTime.Ticks` value for midnight on this date, or `None` if the date is outside
+  /// the range of `DateTime` (years 1 through 9999).
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::date;
+  ///
+  /// assert_eq!(date! { 2012-04-21 }.to_dotnet_ticks(), Some(634_705_632_000_000_000));
+  /// assert_eq!(date! { 0000-12-31 }.to_dotnet_ticks(), None);
+  /// assert_eq!(date! { 10000-01-01 }.to_dotnet_ticks(), None);
+  /// ```
+  pub const fn to_dotnet_ticks(&self) -> Option<i64> {
+    match self.year() >= 1 && self.year() <= 9999 {
+      true => Some((self.0 - DOTNET_EPOCH) as i64 * TICKS_PER_DAY),
+      false => None,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use assert2::check;
+
+  use super::*;
+
+  #[test]
+  fn test_filetime() {
+    check!(Date::from_filetime(0) == date! { 1601-01-01 });
+    check!(Date::from_filetime(TICKS_PER_DAY as u64 - 1) == date! { 1601-01-01 });
+    check!(Date::from_filetime(TICKS_PER_DAY as u64) == date! { 1601-01-02 });
+    check!(Date::from_filetime(116_444_736_000_000_000) == date! { 1970-01-01 });
+    check!(date! { 1970-01-01 }.to_filetime() == Some(116_444_736_000_000_000));
+    check!(date! { 1601-01-01 }.to_filetime() == Some(0));
+    check!(date! { 1600-12-31 }.to_filetime() == None);
+    check!(Date::MAX.to_filetime().is_some());
+    for date in date! { 1999-12-25 }.iter_through(date! { 2000-01-05 }) {
+      check!(Date::from_filetime(date.to_filetime().unwrap()) == date);
+      check!(Date::from_filetime(date.to_filetime().unwrap() + 1) == date);
+    }
+    check!(Date::checked_from_filetime(i64::MAX as u64) == Some(date! { 30828-09-14 }));
+    check!(Date::checked_from_filetime(i64::MAX as u64 + 1) == None);
+    check!(Date::checked_from_filetime(u64::MAX) == None);
+  }
+
+  #[test]
+  #[should_panic]
+  fn test_filetime_overflow() {
+    Date::from_filetime(u64::MAX);
+  }
+
+  #[test]
+  fn test_dotnet_ticks() {
+    check!(Date::from_dotnet_ticks(0) == date! { 0001-01-01 });
+    check!(Date::from_dotnet_ticks(-1) == date! { 0000-12-31 });
+    check!(Date::from_dotnet_ticks(621_355_968_000_000_000) == date! { 1970-01-01 });
+    check!(Date::from_dotnet_ticks(3_155_378_975_999_999_999) == date! { 9999-12-31 });
+    check!(date! { 1970-01-01 }.to_dotnet_ticks() == Some(621_355_968_000_000_000));
+    check!(date! { 0001-01-01 }.to_dotnet_ticks() == Some(0));
+    check!(date! { 9999-12-31 }.to_dotnet_ticks() == Some(3_155_378_112_000_000_000));
+    check!(date! { 10000-01-01 }.to_dotnet_ticks() == None);
+    for date in date! { 1999-12-25 }.iter_through(date! { 2000-01-05 }) {
+      check!(Date::from_dotnet_ticks(date.to_dotnet_ticks().unwrap()) == date);
+    }
+  }
+}
@@ -0,0 +1,327 @@
+use crate::interval::DateInterval;
+use crate::iter::WorkdayIterator;
+use crate::Date;
+use crate::Weekday;
+
+/// The days of the week that are working days.
+///
+/// Different regions define the working week differently: most of the world works Monday through
+/// Friday, but many countries in the Middle East work Sunday through Thursday, and some have
+/// historically worked Saturday through Wednesday. The days that are not working days are the
+/// weekend.
+///
+/// ## Examples
+///
+/// ```
+/// use date::date;
+/// use date::Workweek;
+///
+/// let date = date! { 2024-04-19 }; // Friday
+/// assert!(Workweek::MONDAY_TO_FRIDAY.is_workday(date));
+/// assert!(!Workweek::SUNDAY_TO_THURSDAY.is_workday(date));
+/// assert_eq!(Workweek::SUNDAY_TO_THURSDAY.add_workdays(date, 1), date! { 2024-04-21 });
+/// ```
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Workweek {
+  days: u8,
+}
+
+impl Workweek {
+  /// A workweek of Monday through Friday, with a Saturday–Sunday weekend.
+  pub const MONDAY_TO_FRIDAY: Self = Self::range(Weekday::Monday, Weekday::Friday);
+  /// A workweek of Saturday through Wednesday, with a Thursday–Friday weekend.
+  pub const SATURDAY_TO_WEDNESDAY: Self = Self::range(Weekday::Saturday, Weekday::Wednesday);
+  /// A workweek of Sunday through Thursday, with a Friday–Saturday weekend.
+  pub const SUNDAY_TO_THURSDAY: Self = Self::range(Weekday::Sunday, Weekday::Thursday);
+
+  /// A workweek consisting of the given working days.
+  ///
+  /// ## Panic
+  ///
+  /// This function panics if no working days are provided.
+  pub const fn new(workdays: &[Weekday]) -> Self {
+    match Self::checked_new(workdays) {
+      Some(workweek) => workweek,
+      None => panic!("A workweek must have at least one working day"),
+    }
+  }
+
+  /// A workweek consisting of the given working days, or `None` if no working days are provided.
+  ///
+  /// This is the non-panicking counterpart to [`Workweek::new`].
+  pub const fn checked_new(workdays: &[Weekday]) -> Option<Self> {
+    let mut days = 0;
+    let mut i = 0;
+    while i < workdays.len() {
+      days |= 1 << workdays[i] as u8;
+      i += 1;
+    }
+    match days != 0 {
+      true => Some(Self { days }),
+      false => None,
+    }
+  }
+
+  /// A workweek consisting of the days from `first` through `last` (inclusive), wrapping around
+  /// the end of the week if necessary.
+  pub const fn range(first: Weekday, last: Weekday) -> Self {
+    let mut days = 0;
+    let mut day = first as u8;
+    loop {
+      days |= 1 << day;
+      if day == last as u8 {
+        break;
+      }
+      day = (day + 1) % 7;
+    }
+    Self { days }
+  }
+
+  /// Whether the given date falls on a working day.
+  #[inline]
+  pub const fn is_workday(&self, date: Date) -> bool {
+    self.contains(date.weekday())
+  }
+
+  /// Whether the given date falls on the weekend.
+  #[inline]
+  pub const fn is_weekend(&self, date: Date) -> bool {
+    !self.is_workday(date)
+  }
+
+  /// Whether the given weekday is a working day.
+  #[inline]
+  pub const fn contains(&self, weekday: Weekday) -> bool {
+    self.days & (1 << weekday as u8) != 0
+  }
+
+  /// The number of working days in the week.
+  pub const fn workdays_per_week(&self) -> u8 {
+    self.days.count_ones() as u8
+  }
+
+  /// The date that is `n` working days after the given date (or before, if `n` is negative).
+  ///
+  /// If the given date is not itself a working day, counting begins from the adjacent working day
+  /// in the opposite direction: so (with a Monday–Friday workweek) one working day after a
+  /// Saturday is the following Monday, and one working day before a Saturday is the preceding
+  /// Friday.
+  pub const fn add_workdays(&self, date: Date, n: i32) -> Date {
+    if n == 0 {
+      return date;
+    }
+    let step = DateInterval::new(n.signum());
+    let mut date = date;
+    while !self.is_workday(date) {
+      date = date.sub_days(step);
+    }
+    date = date.add_days(DateInterval::new(7 * (n / self.workdays_per_week() as i32)));
+    let mut remaining = (n % self.workdays_per_week() as i32).abs();
+    while remaining > 0 {
+      date = date.add_days(step);
+      if self.is_workday(date) {
+        remaining -= 1;
+      }
+    }
+    date
+  }
+
+  /// An iterator of the working days from `start` through `end` (inclusive).
+  pub fn iter_workdays(&self, start: Date, end: Date) -> WorkdayIterator {
+    WorkdayIterator::new(start.iter_through(end), *self)
+  }
+}
+
+impl Default for Workweek {
+  fn default() -> Self {
+    Self::MONDAY_TO_FRIDAY
+  }
+}
+
+/// The days of the week that make up the weekend.
+///
+/// This is the complement of a [`Workweek`], but may contain any set of days (including none, or
+/// all seven).
+///
+/// ## Examples
+///
+/// ```
+/// use date::date;
+/// use date::Weekday;
+/// use date::Weekend;
+///
+/// let date = date! { 2024-04-19 }; // Friday
+/// assert!(!Weekend::SATURDAY_SUNDAY.is_weekend(date));
+/// assert!(Weekend::FRIDAY_SATURDAY.is_weekend(date));
+/// assert!(Weekend::new(&[Weekday::Friday]).is_weekend(date));
+/// ```
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Weekend {
+  days: u8,
+}
+
+impl Weekend {
+  /// A Friday–Saturday weekend.
+  pub const FRIDAY_SATURDAY: Self = Self::new(&[Weekday::Friday, Weekday::Saturday]);
+  /// A Saturday–Sunday weekend.
+  pub const SATURDAY_SUNDAY: Self = Self::new(&[Weekday::Saturday, Weekday::Sunday]);
+  /// A Thursday–Friday weekend.
+  pub const THURSDAY_FRIDAY: Self = Self::new(&[Weekday::Thursday, Weekday::Friday]);
+
+  /// A weekend consisting of the given days.
+  pub const fn new(days: &[Weekday]) -> Self {
+    let mut bits = 0;
+    let mut i = 0;
+    while i < days.len() {
+      bits |= 1 << days[i] as u8;
+      i += 1;
+    }
+    Self { days: bits }
+  }
+
+  /// Whether the given date falls on the weekend.
+  #[inline]
+  pub const fn is_weekend(&self, date: Date) -> bool {
+    self.contains(date.weekday())
+  }
+
+  /// Whether the given weekday is part of the weekend.
+  #[inline]
+  pub const fn contains(&self, weekday: Weekday) -> bool {
+    self.days & (1 << weekday as u8) != 0
+  }
+
+  /// The workweek consisting of the days that are not part of this weekend, or `None` if every
+  /// day is part of the weekend.
+  pub const fn workweek(&self) -> Option<Workweek> {
+    match self.days != 0b111_1111 {
+      true => Some(Workweek { days: !self.days & 0b111_1111 }),
+      false => None,
+    }
+  }
+}
+
+impl Default for Weekend {
+  fn default() -> Self {
+    Self::SATURDAY_SUNDAY
+  }
+}
+
+impl Workweek {
+  /// The weekend: the days that are not working days.
+  pub const fn weekend(&self) -> Weekend {
+    Weekend { days: !self.days & 0b111_1111 }
+  }
+}
+
+impl Date {
+  /// Whether this date falls on a Saturday or Sunday.
+  ///
+  /// To use a different definition of the weekend, see [`Weekend::is_weekend`].
+  #[inline]
+  pub const fn is_weekend(&self) -> bool {
+    Weekend::SATURDAY_SUNDAY.is_weekend(*self)
+  }
+
+  /// Whether this date falls on a Monday through Friday.
+  ///
+  /// To use a different definition of the weekend, see [`Workweek::is_workday`].
+  #[inline]
+  pub const fn is_weekday(&self) -> bool {
+    !self.is_weekend()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use assert2::check;
+
+  use super::*;
+
+  #[test]
+  fn test_workweek() {
+    check!(
+      Workweek::MONDAY_TO_FRIDAY
+        == Workweek::new(&[
+          Weekday::Monday,
+          Weekday::Tuesday,
+          Weekday::Wednesday,
+          Weekday::Thursday,
+          Weekday::Friday
+        ])
+    );
+    check!(Workweek::default() == Workweek::MONDAY_TO_FRIDAY);
+    check!(Workweek::SATURDAY_TO_WEDNESDAY.workdays_per_week() == 5);
+    check!(Workweek::SATURDAY_TO_WEDNESDAY.contains(Weekday::Saturday));
+    check!(!Workweek::SATURDAY_TO_WEDNESDAY.contains(Weekday::Thursday));
+    check!(Workweek::range(Weekday::Monday, Weekday::Monday).workdays_per_week() == 1);
+    check!(Workweek::range(Weekday::Tuesday, Weekday::Monday).workdays_per_week() == 7);
+  }
+
+  #[test]
+  fn test_is_weekend() {
+    check!(date! { 2024-04-20 }.is_weekend());
+    check!(date! { 2024-04-21 }.is_weekend());
+    check!(!date! { 2024-04-22 }.is_weekend());
+    check!(Workweek::SUNDAY_TO_THURSDAY.is_weekend(date! { 2024-04-19 }));
+    check!(!Workweek::SUNDAY_TO_THURSDAY.is_weekend(date! { 2024-04-21 }));
+  }
+
+  #[test]
+  fn test_weekend() {
+    check!(Weekend::default() == Weekend::SATURDAY_SUNDAY);
+    check!(Weekend::SATURDAY_SUNDAY.workweek() == Some(Workweek::MONDAY_TO_FRIDAY));
+    check!(Weekend::FRIDAY_SATURDAY.workweek() == Some(Workweek::SUNDAY_TO_THURSDAY));
+    check!(Weekend::THURSDAY_FRIDAY.workweek() == Some(Workweek::SATURDAY_TO_WEDNESDAY));
+    check!(Workweek::SUNDAY_TO_THURSDAY.weekend() == Weekend::FRIDAY_SATURDAY);
+    let every_day = Workweek::range(Weekday::Sunday, Weekday::Saturday);
+    check!(Weekend::new(&[]).workweek() == Some(every_day));
+    check!(every_day.weekend().workweek() == Some(every_day));
+    let every_day = Weekend::new(&[
+      Weekday::Sunday,
+      Weekday::Monday,
+      Weekday::Tuesday,
+      Weekday::Wednesday,
+      Weekday::Thursday,
+      Weekday::Friday,
+      Weekday::Saturday,
+    ]);
+    check!(every_day.workweek() == None);
+    check!(Workweek::range(Weekday::Monday, Weekday::Monday).weekend().workweek().is_some());
+    let weekend = Weekend::new(&[Weekday::Sunday]);
+    check!(weekend.is_weekend(date! { 2024-04-21 }));
+    check!(!weekend.is_weekend(date! { 2024-04-20 }));
+    for date in date! { 2024-04-01 }.iter_through(date! { 2024-04-30 }) {
+      check!(date.is_weekday() == !date.is_weekend());
+      check!(date.is_weekday() == Workweek::MONDAY_TO_FRIDAY.is_workday(date));
+    }
+  }
+
+  #[test]
+  fn test_add_workdays() {
+    let week = Workweek::MONDAY_TO_FRIDAY;
+    check!(week.add_workdays(date! { 2024-04-17 }, 0) == date! { 2024-04-17 });
+    check!(week.add_workdays(date! { 2024-04-17 }, 2) == date! { 2024-04-19 });
+    check!(week.add_workdays(date! { 2024-04-17 }, 3) == date! { 2024-04-22 });
+    check!(week.add_workdays(date! { 2024-04-17 }, 10) == date! { 2024-05-01 });
+    check!(week.add_workdays(date! { 2024-04-17 }, -3) == date! { 2024-04-12 });
+    check!(week.add_workdays(date! { 2024-04-20 }, 1) == date! { 2024-04-22 });
+    check!(week.add_workdays(date! { 2024-04-20 }, 5) == date! { 2024-04-26 });
+    check!(week.add_workdays(date! { 2024-04-20 }, -1) == date! { 2024-04-19 });
+    let week = Workweek::SUNDAY_TO_THURSDAY;
+    check!(week.add_workdays(date! { 2024-04-18 }, 1) == date! { 2024-04-21 });
+    check!(week.add_workdays(date! { 2024-04-21 }, -1) == date! { 2024-04-18 });
+  }
+
+  #[test]
+  fn test_checked_new() {
+    check!(Workweek::checked_new(&[Weekday::Monday]).is_some());
+    check!(Workweek::checked_new(&[]) == None);
+  }
+
+  #[test]
+  #[should_panic]
+  fn test_empty_workweek() {
+    Workweek::new(&[]);
+  }
+}
@@ -0,0 +1,169 @@
+use core::fmt;
+
+use crate::iter::DateIterator;
+use crate::utils;
+use crate::Date;
+use crate::Quarter;
+use crate::YearMonth;
+
+/// A calendar year.
+///
+/// ## Examples
+///
+/// ```
+/// use date::date;
+/// use date::Year;
+///
+/// let year = Year(2024);
+/// assert!(year.is_leap());
+/// assert_eq!(year.num_days(), 366);
+/// assert_eq!(year.first_day(), date! { 2024-01-01 });
+/// assert_eq!(year.last_day(), date! { 2024-12-31 });
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Year(pub i16);
+
+impl Year {
+  /// Whether this is a leap year.
+  #[inline]
+  pub const fn is_leap(&self) -> bool {
+    utils::is_leap_year(self.0)
+  }
+
+  /// The number of days in the year (365, or 366 in leap years).
+  #[inline]
+  pub const fn num_days(&self) -> u16 {
+    utils::days_in_year(self.0)
+  }
+
+  /// January 1 of this year.
+  pub const fn first_day(&self) -> Date {
+    Date::new(self.0, 1, 1)
+  }
+
+  /// December 31 of this year.
+  pub const fn last_day(&self) -> Date {
+    Date::new(self.0, 12, 31)
+  }
+
+  /// Whether the given date falls within the year.
+  pub const fn contains(&self, date: Date) -> bool {
+    date.year() == self.0
+  }
+
+  /// An iterator over every day in the year.
+  pub const fn days(&self) -> DateIterator {
+    self.first_day().iter_through(self.last_day())
+  }
+
+  /// An iterator over the twelve months of the year.
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::Year;
+  /// use date::YearMonth;
+  ///
+  /// let mut months = Year(2024).months();
+  /// assert_eq!(months.next(), Some(YearMonth::new(2024, 1)));
+  /// assert_eq!(months.last(), Some(YearMonth::new(2024, 12)));
+  /// ```
+  pub fn months(&self) -> impl Iterator<Item = YearMonth> + Clone {
+    let year = self.0;
+    (1..=12).map(move |month| YearMonth::new(year, month))
+  }
+
+  /// An iterator over the four quarters of the year.
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::date;
+  /// use date::Year;
+  ///
+  /// let ends: Vec<_> = Year(2024).quarters().map(|q| q.end()).collect();
+  /// assert_eq!(ends, [
+  ///   date! { 2024-03-31 },
+  ///   date! { 2024-06-30 },
+  ///   date! { 2024-09-30 },
+  ///   date! { 2024-12-31 },
+  /// ]);
+  /// ```
+  pub fn quarters(&self) -> impl Iterator<Item = Quarter> + Clone {
+    let year = self.0;
+    (1..=4).map(move |number| Quarter::new(year, number))
+  }
+}
+
+impl From<i16> for Year {
+  fn from(year: i16) -> Self {
+    Self(year)
+  }
+}
+
+impl From<Year> for i16 {
+  fn from(year: Year) -> Self {
+    year.0
+  }
+}
+
+impl From<Date> for Year {
+  fn from(date: Date) -> Self {
+    Self(date.year())
+  }
+}
+
+impl fmt::Display for Year {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self.0 < 0 {
+      true => write!(f, "-{:04}", self.0.unsigned_abs()),
+      false => write!(f, "{:04}", self.0),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use assert2::check;
+
+  use super::*;
+
+  #[test]
+  fn test_leap() {
+    check!(Year(2024).is_leap());
+    check!(Year(2000).is_leap());
+    check!(!Year(1900).is_leap());
+    check!(!Year(2023).is_leap());
+    check!(Year(2023).num_days() == 365);
+    check!(Year(2000).num_days() == 366);
+  }
+
+  #[test]
+  fn test_days() {
+    for year in [-32_768, -1, 0, 1900, 2023, 2024, 32_767] {
+      let year = Year(year);
+      check!(year.days().count() == year.num_days() as usize);
+      check!(year.days().next() == Some(year.first_day()));
+      check!(year.days().next_back() == Some(year.last_day()));
+      check!(year.days().all(|d| year.contains(d)));
+      check!(!year.contains(Date(year.first_day().0 - 1)));
+    }
+  }
+
+  #[test]
+  fn test_months_quarters() {
+    let year = Year(2023);
+    check!(year.months().count() == 12);
+    check!(year.months().map(|m| m.num_days() as u16).sum::<u16>() == year.num_days());
+    check!(year.quarters().count() == 4);
+    check!(year.quarters().next().map(|q| q.start()) == Some(year.first_day()));
+    check!(year.quarters().last().map(|q| q.end()) == Some(year.last_day()));
+  }
+
+  #[test]
+  fn test_display() {
+    check!(Year(2024).to_string() == "2024");
+    check!(Year(812).to_string() == "0812");
+    check!(Year(-44).to_string() == "-0044");
+  }
+}
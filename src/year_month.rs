@@ -0,0 +1,253 @@
+use core::fmt;
+use core::ops::Add;
+use core::ops::Sub;
+
+use crate::iter::DateIterator;
+use crate::utils;
+use crate::Date;
+
+/// A calendar month within a particular year, without a day.
+///
+/// This is useful for data that is keyed by month rather than by day, such as billing periods or
+/// statements. Year-months are written as `2024-08` (the format used by
+/// [`Display`](fmt::Display)).
+///
+/// ## Examples
+///
+/// ```
+/// use date::date;
+/// use date::YearMonth;
+///
+/// let month = YearMonth::new(2024, 2);
+/// assert_eq!(month.first_day(), date! { 2024-02-01 });
+/// assert_eq!(month.last_day(), date! { 2024-02-29 });
+/// assert_eq!(month + 11, YearMonth::new(2025, 1));
+/// assert_eq!(month.to_string(), "2024-02");
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct YearMonth {
+  year: i16,
+  month: u8,
+}
+
+impl YearMonth {
+  /// Construct a new year-month from the provided year and month (1–12).
+  ///
+  /// ## Panic
+  ///
+  /// This function panics if the month is not between 1 and 12.
+  pub const fn new(year: i16, month: u8) -> Self {
+    assert!(month >= 1 && month <= 12, "Month out-of-bounds");
+    Self { year, month }
+  }
+
+  /// Construct a new year-month, or return `None` if the month is not between 1 and 12.
+  ///
+  /// This is the non-panicking counterpart to [`YearMonth::new`].
+  pub const fn checked_new(year: i16, month: u8) -> Option<Self> {
+    match month >= 1 && month <= 12 {
+      true => Some(Self { year, month }),
+      false => None,
+    }
+  }
+
+  /// The year.
+  #[inline]
+  pub const fn year(&self) -> i16 {
+    self.year
+  }
+
+  /// The month, between 1 and 12.
+  #[inline]
+  pub const fn month(&self) -> u8 {
+    self.month
+  }
+
+  /// The first day of the month.
+  pub const fn first_day(&self) -> Date {
+    Date::new(self.year, self.month, 1)
+  }
+
+  /// The last day of the month.
+  pub const fn last_day(&self) -> Date {
+    Date::new(self.year, self.month, utils::days_in_month(self.year, self.month))
+  }
+
+  /// The number of days in the month.
+  pub const fn num_days(&self) -> u8 {
+    utils::days_in_month(self.year, self.month)
+  }
+
+  /// An iterator over every day in the month.
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::date;
+  /// use date::YearMonth;
+  ///
+  /// let mut days = YearMonth::new(2023, 2).days();
+  /// assert_eq!(days.next(), Some(date! { 2023-02-01 }));
+  /// assert_eq!(days.last(), Some(date! { 2023-02-28 }));
+  /// ```
+  pub const fn days(&self) -> DateIterator {
+    self.first_day().iter_through(self.last_day())
+  }
+
+  /// Whether the given date falls within the month.
+  pub const fn contains(&self, date: Date) -> bool {
+    let (year, month, _) = date.ymd();
+    year == self.year && month == self.month
+  }
+
+  /// The following month.
+  ///
+  /// ## Panic
+  ///
+  /// This function panics if the result would be after the year 32,767.
+  pub const fn succ(self) -> Self {
+    self.add_months(1)
+  }
+
+  /// The preceding month.
+  ///
+  /// ## Panic
+  ///
+  /// This function panics if the result would be before the year -32,768.
+  pub const fn pred(self) -> Self {
+    self.add_months(-1)
+  }
+
+  /// The year-month that is the given number of months later (or earlier, if `n` is negative).
+  ///
+  /// This is equivalent to `year_month + n`, but can be used in `const` contexts.
+  ///
+  /// ## Panic
+  ///
+  /// This function panics if the result would be outside the years -32,768 through 32,767. Use
+  /// [`YearMonth::checked_add_months`] to handle this case.
+  pub const fn add_months(self, n: i32) -> Self {
+    match self.checked_add_months(n) {
+      Some(year_month) => year_month,
+      None => panic!("Month out-of-bounds"),
+    }
+  }
+
+  /// The year-month that is the given number of months later (or earlier, if `n` is negative), or
+  /// `None` if the result would be outside the years -32,768 through 32,767.
+  pub const fn checked_add_months(self, n: i32) -> Option<Self> {
+    let total = self.year as i64 * 12 + self.month as i64 - 1 + n as i64;
+    let year = total.div_euclid(12);
+    match year >= i16::MIN as i64 && year <= i16::MAX as i64 {
+      true => Some(Self { year: year as i16, month: total.rem_euclid(12) as u8 + 1 }),
+      false => None,
+    }
+  }
+}
+
+impl Date {
+  /// The year and month containing this date.
+  ///
+  /// ## Examples
+  ///
+  /// ```
+  /// use date::date;
+  /// use date::YearMonth;
+  ///
+  /// assert_eq!(date! { 2024-08-15 }.year_month(), YearMonth::new(2024, 8));
+  /// ```
+  pub const fn year_month(&self) -> YearMonth {
+    let (year, month, _) = self.ymd();
+    YearMonth { year, month }
+  }
+}
+
+impl From<Date> for YearMonth {
+  fn from(date: Date) -> Self {
+    date.year_month()
+  }
+}
+
+impl Add<i32> for YearMonth {
+  type Output = Self;
+
+  /// Return the year-month that is the given number of months later.
+  fn add(self, n: i32) -> Self::Output {
+    self.add_months(n)
+  }
+}
+
+impl Sub<i32> for YearMonth {
+  type Output = Self;
+
+  /// Return the year-month that is the given number of months earlier.
+  fn sub(self, n: i32) -> Self::Output {
+    self.add_months(-n)
+  }
+}
+
+impl fmt::Display for YearMonth {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self.year < 0 {
+      true => write!(f, "-{:04}-{:02}", self.year.unsigned_abs(), self.month),
+      false => write!(f, "{:04}-{:02}", self.year, self.month),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use assert2::check;
+
+  use super::*;
+
+  #[test]
+  fn test_bounds() {
+    for date in date! { 2023-12-01 }.iter_through(date! { 2025-01-31 }) {
+      let year_month = date.year_month();
+      check!(year_month.contains(date));
+      check!(year_month.first_day() <= date && date <= year_month.last_day());
+      check!(Date(year_month.last_day().0 + 1) == year_month.succ().first_day());
+      check!(!year_month.succ().contains(date));
+      check!(!year_month.pred().contains(date));
+    }
+    check!(YearMonth::new(2023, 2).last_day() == date! { 2023-02-28 });
+    check!(YearMonth::new(2024, 2).num_days() == 29);
+    check!(YearMonth::new(2024, 4).days().count() == 30);
+    check!(YearMonth::new(2024, 4).days().all(|d| d.month() == 4));
+  }
+
+  #[test]
+  fn test_arithmetic() {
+    let year_month = YearMonth::new(2024, 11);
+    check!(year_month.succ() == YearMonth::new(2024, 12));
+    check!(year_month.succ().succ() == YearMonth::new(2025, 1));
+    check!(YearMonth::new(2024, 1).pred() == YearMonth::new(2023, 12));
+    check!(year_month + 14 == YearMonth::new(2026, 1));
+    check!(year_month - 23 == YearMonth::new(2022, 12));
+    check!(year_month + -3 == year_month - 3);
+    check!(YearMonth::new(32767, 12).checked_add_months(1) == None);
+    check!(YearMonth::new(-32768, 1).checked_add_months(-1) == None);
+    check!(YearMonth::new(2024, 1).checked_add_months(i32::MIN) == None);
+  }
+
+  #[test]
+  fn test_display() {
+    check!(YearMonth::new(2024, 8).to_string() == "2024-08");
+    check!(YearMonth::new(812, 12).to_string() == "0812-12");
+    check!(YearMonth::new(-44, 3).to_string() == "-0044-03");
+  }
+
+  #[test]
+  fn test_checked_new() {
+    check!(YearMonth::checked_new(2024, 12) == Some(YearMonth::new(2024, 12)));
+    check!(YearMonth::checked_new(2024, 0) == None);
+    check!(YearMonth::checked_new(2024, 13) == None);
+  }
+
+  #[test]
+  #[should_panic]
+  fn test_invalid_month() {
+    YearMonth::new(2024, 13);
+  }
+}